@@ -0,0 +1,58 @@
+//! Auction data shared between World of Warcraft Classic and retail auction houses.
+
+use std::fmt::Formatter;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Remaining duration buckets reported for auctions.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TimeLeft {
+    /// Represents auctions with less than 30 minutes remaining.
+    Short,
+    /// Represents auctions with between 30 minutes and 2 hours remaining.
+    Medium,
+    /// Represents auctions with between 2 and 12 hours remaining.
+    Long,
+    /// Represents auctions with more than 12 hours remaining.
+    VeryLong,
+    /// Represents an unknown duration, detected when the provided duration is not able to be parsed.
+    Unknown,
+}
+
+impl From<&str> for TimeLeft {
+    fn from(value: &str) -> Self {
+        match value {
+            "SHORT" => TimeLeft::Short,
+            "MEDIUM" => TimeLeft::Medium,
+            "LONG" => TimeLeft::Long,
+            "VERY_LONG" => TimeLeft::VeryLong,
+            _ => TimeLeft::Unknown,
+        }
+    }
+}
+
+/// Visits time left strings without allocating, as auction houses may contain tens of thousands of auctions.
+struct TimeLeftVisitor;
+
+impl<'de> Visitor<'de> for TimeLeftVisitor {
+    type Value = TimeLeft;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a time left string")
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(value.into())
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeLeft {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TimeLeftVisitor)
+    }
+}
@@ -1,7 +1,12 @@
+//! Client options and builders for fluently configuring a BubbleHearth client instance.
+
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::cassette::CassetteMode;
 use crate::client::BubbleHearthClient;
 use crate::errors::{BubbleHearthError, BubbleHearthResult};
+use crate::interceptors::Interceptor;
 use crate::localization::Locale;
 use crate::regionality::AccountRegion;
 
@@ -30,7 +35,8 @@ use crate::regionality::AccountRegion;
 ///         // Invalid, a locale is required
 ///         locale: None,
 ///         timeout: None,
-///         http: None
+///         http: None,
+///         ..Default::default()
 ///     };
 ///
 ///     // Trying to construct the client from invalid options
@@ -39,7 +45,7 @@ use crate::regionality::AccountRegion;
 ///
 ///     assert!(client.is_err());
 /// }
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct BubbleHearthClientOptions {
     /// Client ID provided by Blizzard's developer portal.
     pub client_id: Option<String>,
@@ -53,6 +59,16 @@ pub struct BubbleHearthClientOptions {
     pub timeout: Option<Duration>,
     /// Configurable HTTP timeout, optional and will trump all other configured HTTP options.
     pub http: Option<reqwest::Client>,
+    /// Configurable API gateway base URL, optional and defaults to the regional Blizzard gateway.
+    pub base_url: Option<String>,
+    /// Configurable OAuth token endpoint, optional and defaults to the regional token endpoint.
+    pub token_url: Option<String>,
+    /// Configurable cassette mode for recording or replaying interactions, optional.
+    pub cassette: Option<CassetteMode>,
+    /// Configurable interceptors invoked for every request and response, in order of registration.
+    pub interceptors: Vec<Arc<dyn Interceptor>>,
+    /// Configurable number of in-flight requests for bulk fetches, optional.
+    pub bulk_concurrency: Option<usize>,
 }
 
 /// A configurable client instance builder for the BubbleHearth client.
@@ -70,19 +86,9 @@ impl BubbleHearthClientBuilder {
 
     /// Sets the required client ID on the currently configured options.
     pub fn with_client_id(self, client_id: String) -> Self {
-        let options = match self.options {
-            None => BubbleHearthClientOptions {
-                client_id: Some(client_id),
-                client_secret: None,
-                region: None,
-                locale: None,
-                timeout: None,
-                http: None,
-            },
-            Some(options) => BubbleHearthClientOptions {
-                client_id: Some(client_id),
-                ..options
-            },
+        let options = BubbleHearthClientOptions {
+            client_id: Some(client_id),
+            ..self.options.unwrap_or_default()
         };
 
         Self {
@@ -92,19 +98,9 @@ impl BubbleHearthClientBuilder {
 
     /// Sets the required client secret on the currently configured options.
     pub fn with_client_secret(self, client_secret: String) -> Self {
-        let options = match self.options {
-            None => BubbleHearthClientOptions {
-                client_secret: Some(client_secret),
-                client_id: None,
-                region: None,
-                locale: None,
-                timeout: None,
-                http: None,
-            },
-            Some(options) => BubbleHearthClientOptions {
-                client_secret: Some(client_secret),
-                ..options
-            },
+        let options = BubbleHearthClientOptions {
+            client_secret: Some(client_secret),
+            ..self.options.unwrap_or_default()
         };
 
         Self {
@@ -114,19 +110,9 @@ impl BubbleHearthClientBuilder {
 
     /// Sets the required region on the currently configured options.
     pub fn with_region(self, region: AccountRegion) -> Self {
-        let options = match self.options {
-            None => BubbleHearthClientOptions {
-                region: Some(region),
-                client_id: None,
-                client_secret: None,
-                locale: None,
-                timeout: None,
-                http: None,
-            },
-            Some(options) => BubbleHearthClientOptions {
-                region: Some(region),
-                ..options
-            },
+        let options = BubbleHearthClientOptions {
+            region: Some(region),
+            ..self.options.unwrap_or_default()
         };
 
         Self {
@@ -136,19 +122,9 @@ impl BubbleHearthClientBuilder {
 
     /// Sets the required locale on the currently configured options.
     pub fn with_locale(self, locale: Locale) -> Self {
-        let options = match self.options {
-            None => BubbleHearthClientOptions {
-                client_id: None,
-                client_secret: None,
-                region: None,
-                locale: Some(locale),
-                timeout: None,
-                http: None,
-            },
-            Some(options) => BubbleHearthClientOptions {
-                locale: Some(locale),
-                ..options
-            },
+        let options = BubbleHearthClientOptions {
+            locale: Some(locale),
+            ..self.options.unwrap_or_default()
         };
 
         Self {
@@ -156,21 +132,12 @@ impl BubbleHearthClientBuilder {
         }
     }
 
-    /// Sets the optional request timeout on the currently configured options.
+    /// Sets the optional request timeout on the currently configured options. Streamed
+    /// responses, i.e. auction snapshots, are only limited by the timeout while connecting.
     pub fn with_timeout(self, timeout: Duration) -> Self {
-        let options = match self.options {
-            None => BubbleHearthClientOptions {
-                client_id: None,
-                client_secret: None,
-                region: None,
-                locale: None,
-                timeout: Some(timeout),
-                http: None,
-            },
-            Some(options) => BubbleHearthClientOptions {
-                timeout: Some(timeout),
-                ..options
-            },
+        let options = BubbleHearthClientOptions {
+            timeout: Some(timeout),
+            ..self.options.unwrap_or_default()
         };
 
         Self {
@@ -178,21 +145,61 @@ impl BubbleHearthClientBuilder {
         }
     }
 
-    /// Sets the optional request timeout on the currently configured options.
+    /// Sets the optional preconfigured HTTP client on the currently configured options,
+    /// used for all requests including streamed responses.
     pub fn with_http(self, client: reqwest::Client) -> Self {
-        let options = match self.options {
-            None => BubbleHearthClientOptions {
-                client_id: None,
-                client_secret: None,
-                region: None,
-                locale: None,
-                timeout: None,
-                http: Some(client),
-            },
-            Some(options) => BubbleHearthClientOptions {
-                http: Some(client),
-                ..options
-            },
+        let options = BubbleHearthClientOptions {
+            http: Some(client),
+            ..self.options.unwrap_or_default()
+        };
+
+        Self {
+            options: Some(options),
+        }
+    }
+
+    /// Sets the optional API gateway base URL on the currently configured options,
+    /// useful for pointing the client at a proxy or fake server.
+    pub fn with_base_url(self, base_url: String) -> Self {
+        let options = BubbleHearthClientOptions {
+            base_url: Some(base_url),
+            ..self.options.unwrap_or_default()
+        };
+
+        Self {
+            options: Some(options),
+        }
+    }
+
+    /// Sets the optional OAuth token endpoint on the currently configured options.
+    pub fn with_token_url(self, token_url: String) -> Self {
+        let options = BubbleHearthClientOptions {
+            token_url: Some(token_url),
+            ..self.options.unwrap_or_default()
+        };
+
+        Self {
+            options: Some(options),
+        }
+    }
+
+    /// Sets the optional cassette mode for recording or replaying interactions on the currently configured options.
+    pub fn with_cassette(self, cassette: CassetteMode) -> Self {
+        let options = BubbleHearthClientOptions {
+            cassette: Some(cassette),
+            ..self.options.unwrap_or_default()
+        };
+
+        Self {
+            options: Some(options),
+        }
+    }
+
+    /// Sets the optional number of in-flight requests for bulk fetches on the currently configured options.
+    pub fn with_bulk_concurrency(self, bulk_concurrency: usize) -> Self {
+        let options = BubbleHearthClientOptions {
+            bulk_concurrency: Some(bulk_concurrency),
+            ..self.options.unwrap_or_default()
         };
 
         Self {
@@ -200,6 +207,16 @@ impl BubbleHearthClientBuilder {
         }
     }
 
+    /// Registers an interceptor on the currently configured options, invoked after any previously registered interceptors.
+    pub fn with_interceptor(self, interceptor: impl Interceptor + 'static) -> Self {
+        let mut options = self.options.unwrap_or_default();
+        options.interceptors.push(Arc::new(interceptor));
+
+        Self {
+            options: Some(options),
+        }
+    }
+
     /// Attempts to construct the client instances using,
     /// propagating any errors for missing required client options.
     pub fn build(self) -> BubbleHearthResult<BubbleHearthClient> {
@@ -0,0 +1,99 @@
+//! Bounded-concurrency helpers for fetching many resources at once without tripping rate limits.
+
+use std::future::Future;
+
+use futures::StreamExt;
+
+/// Default number of in-flight requests for bulk fetches, overridable if provided.
+pub const DEFAULT_BULK_CONCURRENCY: usize = 8;
+
+/// Runs the fetch for each item with at most `concurrency` fetches in flight at once,
+/// returning each item's output in the same order as the input. As each fetch produces
+/// its own result, a single failure (or missing resource) does not fail the entire batch.
+///
+/// ```rust
+/// use bubblehearth::bulk::fetch_with_concurrency;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let doubled = fetch_with_concurrency(vec![1, 2, 3], 2, |n| async move { n * 2 }).await;
+///     assert_eq!(doubled, vec![2, 4, 6]);
+/// }
+/// ```
+pub async fn fetch_with_concurrency<I, F, Fut>(
+    items: I,
+    concurrency: usize,
+    fetch: F,
+) -> Vec<Fut::Output>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future,
+{
+    futures::stream::iter(items)
+        .map(fetch)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod bulk_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use crate::bulk::fetch_with_concurrency;
+
+    #[tokio::test]
+    async fn returns_results_in_input_order() {
+        // Arrange
+        let delays = vec![30, 10, 20, 0];
+
+        // Act
+        let results = fetch_with_concurrency(delays.clone(), 4, |delay| async move {
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            delay
+        })
+        .await;
+
+        // Assert
+        assert_eq!(results, delays);
+    }
+
+    #[tokio::test]
+    async fn returns_results_without_exceeding_concurrency() {
+        // Arrange
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+
+        // Act
+        let results = fetch_with_concurrency(0..20, 3, |n| {
+            let in_flight = &in_flight;
+            let max_in_flight = &max_in_flight;
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                n
+            }
+        })
+        .await;
+
+        // Assert
+        assert_eq!(results, (0..20).collect::<Vec<_>>());
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[tokio::test]
+    async fn returns_results_when_concurrency_is_zero() {
+        // Arrange
+        let items = vec!["a", "b"];
+
+        // Act
+        let results = fetch_with_concurrency(items, 0, |item| async move { item }).await;
+
+        // Assert
+        assert_eq!(results, vec!["a", "b"]);
+    }
+}
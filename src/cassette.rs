@@ -0,0 +1,385 @@
+//! Record and replay of HTTP interactions through cassette files. Recording captures each
+//! request and response sent by the client, with the access token redacted, allowing the
+//! recorded responses to be replayed deterministically in tests and demos without credentials.
+//! Recorded interactions are kept in memory and written to the cassette file once, either when
+//! saved explicitly through the client or when the client is dropped.
+//!
+//! ```rust
+//! use std::path::PathBuf;
+//! use bubblehearth::builder::BubbleHearthClientBuilder;
+//! use bubblehearth::cassette::CassetteMode;
+//! use bubblehearth::localization::Locale;
+//! use bubblehearth::regionality::AccountRegion;
+//!
+//! // Record all interactions to the cassette file, or replay them by swapping out the mode
+//! let client = BubbleHearthClientBuilder::new()
+//!     .with_client_id("client_id".to_string())
+//!     .with_client_secret("client_secret".to_string())
+//!     .with_region(AccountRegion::US)
+//!     .with_locale(Locale::EnglishUS)
+//!     .with_cassette(CassetteMode::Record(PathBuf::from("cassettes/realms.json")))
+//!     .build();
+//!
+//! assert!(client.is_ok());
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use http::{HeaderMap, Method};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{BubbleHearthError, BubbleHearthResult};
+
+/// Placeholder recorded in place of the access token sent with each request.
+pub const REDACTED_AUTHORIZATION: &str = "Bearer [REDACTED]";
+
+/// Namespace header name, normalized to lowercase as recorded in cassettes.
+const NAMESPACE_HEADER: &str = "battlenet-namespace";
+
+/// Cassette modes for recording interactions to, or replaying interactions from, a cassette file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Sends requests as normal, recording each request and response to be saved to the cassette file.
+    Record(PathBuf),
+    /// Serves requests from the cassette file without sending them, erroring on unmatched requests.
+    Replay(PathBuf),
+}
+
+/// Encodings of recorded response bodies.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BodyEncoding {
+    /// Represents bodies recorded as-is, i.e. JSON documents.
+    #[default]
+    Text,
+    /// Represents binary bodies recorded as base64, i.e. downloaded media assets.
+    Base64,
+}
+
+/// A set of recorded HTTP interactions, serialized as JSON to the cassette file.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Recorded interactions, in the order they were sent.
+    pub interactions: Vec<Interaction>,
+}
+
+/// A single recorded request and its response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// Recorded outgoing request.
+    pub request: RecordedRequest,
+    /// Recorded response to the request.
+    pub response: RecordedResponse,
+}
+
+/// An outgoing request recorded to a cassette, with the authorization redacted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method of the request.
+    pub method: String,
+    /// Full URL of the request, including the query string.
+    pub url: String,
+    /// Headers sent along with the request.
+    pub headers: BTreeMap<String, String>,
+}
+
+/// A response recorded to a cassette.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// Status code of the response.
+    pub status: u16,
+    /// Headers returned with the response.
+    pub headers: BTreeMap<String, String>,
+    /// Raw body of the response, encoded as described by the body encoding.
+    pub body: String,
+    /// Encoding of the recorded body, defaulting to text for cassettes recorded without one.
+    #[serde(default)]
+    pub encoding: BodyEncoding,
+}
+
+impl Cassette {
+    /// Loads a cassette from the given file.
+    pub fn load(path: &Path) -> BubbleHearthResult<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?;
+        let cassette = serde_json::from_str::<Cassette>(&contents)
+            .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?;
+
+        Ok(cassette)
+    }
+
+    /// Saves the cassette to the given file, creating any missing parent directories.
+    pub fn save(&self, path: &Path) -> BubbleHearthResult<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?;
+        }
+
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?;
+
+        std::fs::write(path, contents).map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))
+    }
+
+    /// Finds the first recorded interaction matching the request's method, URL, and namespace.
+    pub fn find_interaction(&self, request: &RecordedRequest) -> Option<&Interaction> {
+        self.interactions.iter().find(|interaction| {
+            interaction.request.method == request.method
+                && interaction.request.url == request.url
+                && interaction.request.headers.get(NAMESPACE_HEADER)
+                    == request.headers.get(NAMESPACE_HEADER)
+        })
+    }
+}
+
+impl RecordedRequest {
+    /// Records an outgoing request, redacting the authorization sent along with it.
+    pub(crate) fn new(method: &Method, url: &str, headers: &HeaderMap) -> Self {
+        let mut recorded_headers = map_headers(headers);
+        recorded_headers.insert(
+            http::header::AUTHORIZATION.to_string(),
+            REDACTED_AUTHORIZATION.to_string(),
+        );
+
+        Self {
+            method: method.to_string(),
+            url: url.to_string(),
+            headers: recorded_headers,
+        }
+    }
+}
+
+/// Cassette state owned by the client, either recording or replaying interactions.
+#[derive(Debug)]
+pub(crate) struct CassettePlayer {
+    /// Configured cassette mode, including the cassette file.
+    mode: CassetteMode,
+    /// Interactions recorded so far, or loaded for replay.
+    cassette: Mutex<Cassette>,
+    /// Flags interactions recorded since the cassette was last saved.
+    unsaved: AtomicBool,
+}
+
+impl CassettePlayer {
+    /// Constructs a cassette player, loading the cassette file up front when replaying.
+    pub(crate) fn new(mode: CassetteMode) -> BubbleHearthResult<Self> {
+        let cassette = match &mode {
+            CassetteMode::Record(_) => Cassette::default(),
+            CassetteMode::Replay(path) => Cassette::load(path)?,
+        };
+
+        Ok(Self {
+            mode,
+            cassette: Mutex::new(cassette),
+            unsaved: AtomicBool::new(false),
+        })
+    }
+
+    /// Determines if the player is serving responses from the cassette.
+    pub(crate) fn is_replaying(&self) -> bool {
+        matches!(self.mode, CassetteMode::Replay(_))
+    }
+
+    /// Serves the recorded response for the request, erroring if no recorded interaction matches.
+    pub(crate) fn replay(
+        &self,
+        request: &RecordedRequest,
+    ) -> BubbleHearthResult<reqwest::Response> {
+        let cassette = self
+            .cassette
+            .lock()
+            .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?;
+
+        match cassette.find_interaction(request) {
+            Some(interaction) => build_response(&interaction.response),
+            None => Err(BubbleHearthError::CassetteInteractionNotFound(format!(
+                "{} {}",
+                request.method, request.url
+            ))),
+        }
+    }
+
+    /// Records the response to the in-memory cassette with the given body encoding, returning
+    /// an equivalent response for deserialization.
+    pub(crate) async fn record(
+        &self,
+        request: RecordedRequest,
+        response: reqwest::Response,
+        encoding: BodyEncoding,
+    ) -> BubbleHearthResult<reqwest::Response> {
+        let CassetteMode::Record(_) = &self.mode else {
+            return Ok(response);
+        };
+
+        let status = response.status().as_u16();
+        let headers = map_headers(response.headers());
+        let body = match encoding {
+            BodyEncoding::Text => response.text().await?,
+            BodyEncoding::Base64 => STANDARD.encode(response.bytes().await?),
+        };
+        let recorded_response = RecordedResponse {
+            status,
+            headers,
+            body,
+            encoding,
+        };
+        let replayed_response = build_response(&recorded_response)?;
+
+        let mut cassette = self
+            .cassette
+            .lock()
+            .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?;
+        cassette.interactions.push(Interaction {
+            request,
+            response: recorded_response,
+        });
+        self.unsaved.store(true, Ordering::SeqCst);
+
+        Ok(replayed_response)
+    }
+
+    /// Writes the interactions recorded so far to the cassette file, doing nothing when replaying.
+    pub(crate) fn save(&self) -> BubbleHearthResult<()> {
+        let CassetteMode::Record(path) = &self.mode else {
+            return Ok(());
+        };
+
+        let cassette = self
+            .cassette
+            .lock()
+            .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?;
+        cassette.save(path)?;
+        self.unsaved.store(false, Ordering::SeqCst);
+
+        Ok(())
+    }
+}
+
+impl Drop for CassettePlayer {
+    fn drop(&mut self) {
+        // Errors can't be surfaced once the client is dropped, saving explicitly reports them instead
+        if self.unsaved.load(Ordering::SeqCst) {
+            let _ = self.save();
+        }
+    }
+}
+
+/// Maps headers into their recorded form, skipping any values that are not valid strings.
+fn map_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Builds a response from its recorded form.
+fn build_response(recorded: &RecordedResponse) -> BubbleHearthResult<reqwest::Response> {
+    let mut builder = http::Response::builder().status(recorded.status);
+
+    for (name, value) in &recorded.headers {
+        builder = builder.header(name, value);
+    }
+
+    let body = match recorded.encoding {
+        BodyEncoding::Text => recorded.body.clone().into_bytes(),
+        BodyEncoding::Base64 => STANDARD
+            .decode(&recorded.body)
+            .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?,
+    };
+
+    let response = builder
+        .body(body)
+        .map_err(|e| BubbleHearthError::CassetteFailed(e.to_string()))?;
+
+    Ok(response.into())
+}
+
+#[cfg(test)]
+mod cassette_tests {
+    use std::collections::BTreeMap;
+
+    use http::{HeaderMap, Method};
+
+    use crate::cassette::{
+        BodyEncoding, Cassette, Interaction, RecordedRequest, RecordedResponse,
+        REDACTED_AUTHORIZATION,
+    };
+
+    fn get_interaction(url: &str, namespace: &str, body: &str) -> Interaction {
+        let mut headers = HeaderMap::new();
+        headers.append("Battlenet-Namespace", namespace.parse().unwrap());
+
+        Interaction {
+            request: RecordedRequest::new(&Method::GET, url, &headers),
+            response: RecordedResponse {
+                status: 200,
+                headers: BTreeMap::new(),
+                body: body.to_string(),
+                encoding: BodyEncoding::Text,
+            },
+        }
+    }
+
+    #[test]
+    fn returns_request_with_redacted_authorization() {
+        // Arrange
+        let headers = HeaderMap::new();
+
+        // Act
+        let request = RecordedRequest::new(&Method::GET, "https://localhost", &headers);
+
+        // Assert
+        assert_eq!(
+            request.headers.get("authorization"),
+            Some(&REDACTED_AUTHORIZATION.to_string())
+        );
+    }
+
+    #[test]
+    fn returns_interaction_matching_url_and_namespace() {
+        // Arrange
+        let cassette = Cassette {
+            interactions: vec![
+                get_interaction("https://localhost/realm", "dynamic-classic-us", "classic"),
+                get_interaction("https://localhost/realm", "dynamic-us", "retail"),
+            ],
+        };
+        let request = get_interaction("https://localhost/realm", "dynamic-us", "").request;
+
+        // Act
+        let interaction = cassette.find_interaction(&request);
+
+        // Assert
+        assert!(interaction.is_some());
+        assert_eq!(interaction.unwrap().response.body, "retail");
+    }
+
+    #[test]
+    fn returns_no_interaction_when_url_unmatched() {
+        // Arrange
+        let cassette = Cassette {
+            interactions: vec![get_interaction(
+                "https://localhost/realm",
+                "dynamic-classic-us",
+                "classic",
+            )],
+        };
+        let request = get_interaction("https://localhost/region", "dynamic-classic-us", "").request;
+
+        // Act
+        let interaction = cassette.find_interaction(&request);
+
+        // Assert
+        assert!(interaction.is_none());
+    }
+}
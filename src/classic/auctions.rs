@@ -0,0 +1,236 @@
+//! Auction house data and APIs for World of Warcraft Classic, listing the auctions of each
+//! faction's auction house per connected realm. Auction payloads can number in the tens of
+//! thousands of listings, so auction models are kept compact, and streaming variants are
+//! available to deserialize listings one at a time as the auction house is received.
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::auctions::TimeLeft;
+use crate::classic::connected_realms::ConnectedRealm;
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentKey, Links};
+use crate::errors::BubbleHearthResult;
+use crate::localization::StringOrStructLocale;
+use crate::streaming::DocumentStream;
+
+/// Name of the field containing the listings of auction house documents.
+const AUCTIONS_FIELD: &str = "auctions";
+
+/// Response structure from the auction house index endpoint, listing the auction houses of a connected realm.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct AuctionHousesIndex {
+    /// Top-level document link to follow for a selected auction house.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available auction houses and their metadata.
+    pub auctions: Vec<AuctionHouseReference>,
+}
+
+/// Auction house metadata returned from the index endpoint.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AuctionHouseReference {
+    /// Document key of the auction house's listings.
+    pub key: DocumentKey<AuctionHouse>,
+    /// Localized auction house name, i.e. Alliance Auction House.
+    pub name: StringOrStructLocale,
+    /// Numeric auction house ID.
+    pub id: u32,
+}
+
+/// All auctions currently listed on an auction house.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AuctionHouse {
+    /// Top-level document link to follow of the selected auction house.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Document key of the connected realm the auction house belongs to.
+    pub connected_realm: Option<DocumentKey<ConnectedRealm>>,
+    /// Auctions currently listed on the auction house.
+    #[serde(default)]
+    pub auctions: Vec<Auction>,
+    /// Localized auction house name, i.e. Alliance Auction House.
+    pub name: Option<StringOrStructLocale>,
+    /// Numeric auction house ID.
+    pub id: Option<u32>,
+}
+
+/// A single auction listed on an auction house.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Auction {
+    /// Numeric auction ID.
+    pub id: u64,
+    /// Item listed in the auction.
+    pub item: AuctionItem,
+    /// Current bid on the auction in copper, if bidding is allowed.
+    pub bid: Option<u64>,
+    /// Buyout price of the auction in copper, if a buyout was set.
+    pub buyout: Option<u64>,
+    /// Number of items listed in the auction.
+    pub quantity: u32,
+    /// Remaining duration of the auction.
+    pub time_left: TimeLeft,
+}
+
+/// Item listed in an auction, including the random enchantment of the item.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuctionItem {
+    /// Numeric item ID.
+    pub id: u32,
+    /// Random enchantment ID of the item, i.e. "of the Bear", if one was rolled.
+    pub rand: Option<i64>,
+    /// Seed of the item's random enchantment, if one was rolled.
+    pub seed: Option<i64>,
+}
+
+/// Faction-specific auction houses available on each connected realm.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AuctionHouseFaction {
+    /// Represents the Alliance auction house.
+    Alliance,
+    /// Represents the Horde auction house.
+    Horde,
+    /// Represents the neutral, cross-faction auction house.
+    Neutral,
+}
+
+impl AuctionHouseFaction {
+    /// Gets the numeric auction house ID of the faction's auction house.
+    pub fn get_auction_house_id(&self) -> u32 {
+        match self {
+            AuctionHouseFaction::Alliance => 2,
+            AuctionHouseFaction::Horde => 6,
+            AuctionHouseFaction::Neutral => 7,
+        }
+    }
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves the auction houses available on the connected realm.
+    pub async fn get_auction_houses(
+        &self,
+        connected_realm_id: u64,
+    ) -> BubbleHearthResult<Option<AuctionHousesIndex>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "connected-realm/{}/auctions/index",
+            connected_realm_id
+        ));
+
+        let auction_houses = self
+            .client
+            .send_request_and_optionally_deserialize::<AuctionHousesIndex>(url)
+            .await?;
+
+        Ok(auction_houses)
+    }
+
+    /// Retrieves all auctions listed on the connected realm's auction house based on the auction house ID.
+    pub async fn get_auctions_by_auction_house_id(
+        &self,
+        connected_realm_id: u64,
+        auction_house_id: u32,
+    ) -> BubbleHearthResult<Option<AuctionHouse>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "connected-realm/{}/auctions/{}",
+            connected_realm_id, auction_house_id
+        ));
+
+        let auction_house = self
+            .client
+            .send_request_and_optionally_deserialize::<AuctionHouse>(url)
+            .await?;
+
+        Ok(auction_house)
+    }
+
+    /// Retrieves all auctions listed on the connected realm's auction house for the faction.
+    pub async fn get_auctions(
+        &self,
+        connected_realm_id: u64,
+        faction: AuctionHouseFaction,
+    ) -> BubbleHearthResult<Option<AuctionHouse>> {
+        self.get_auctions_by_auction_house_id(connected_realm_id, faction.get_auction_house_id())
+            .await
+    }
+
+    /// Streams all auctions listed on the connected realm's auction house based on the auction house ID.
+    /// Auctions are deserialized one at a time as the auction house is received, and the time the
+    /// listings were last updated is available from the stream.
+    pub async fn stream_auctions_by_auction_house_id(
+        &self,
+        connected_realm_id: u64,
+        auction_house_id: u32,
+    ) -> BubbleHearthResult<Option<DocumentStream<Auction>>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "connected-realm/{}/auctions/{}",
+            connected_realm_id, auction_house_id
+        ));
+
+        let auctions = self
+            .client
+            .send_request_and_optionally_stream::<Auction>(url, AUCTIONS_FIELD)
+            .await?;
+
+        Ok(auctions)
+    }
+
+    /// Streams all auctions listed on the connected realm's auction house for the faction.
+    pub async fn stream_auctions(
+        &self,
+        connected_realm_id: u64,
+        faction: AuctionHouseFaction,
+    ) -> BubbleHearthResult<Option<DocumentStream<Auction>>> {
+        self.stream_auctions_by_auction_house_id(connected_realm_id, faction.get_auction_house_id())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod auction_tests {
+    use crate::classic::auctions::{Auction, TimeLeft};
+
+    #[test]
+    fn returns_auction_when_bid_and_random_enchantment_omitted() {
+        // Arrange
+        let json = r#"
+            {
+              "id": 1880389245,
+              "item": { "id": 2589 },
+              "buyout": 2000,
+              "quantity": 20,
+              "time_left": "VERY_LONG"
+            }
+        "#;
+
+        // Act
+        let auction = serde_json::from_str::<Auction>(json).unwrap();
+
+        // Assert
+        assert_eq!(auction.item.id, 2589);
+        assert_eq!(auction.item.rand, None);
+        assert_eq!(auction.bid, None);
+        assert_eq!(auction.buyout, Some(2000));
+        assert_eq!(auction.time_left, TimeLeft::VeryLong);
+    }
+
+    #[test]
+    fn returns_unknown_time_left_when_no_match_found() {
+        // Arrange
+        let json = r#"
+            {
+              "id": 1880389246,
+              "item": { "id": 15215, "rand": 1020, "seed": 1484103040 },
+              "bid": 41000,
+              "quantity": 1,
+              "time_left": "FOREVER"
+            }
+        "#;
+
+        // Act
+        let auction = serde_json::from_str::<Auction>(json).unwrap();
+
+        // Assert
+        assert_eq!(auction.item.rand, Some(1020));
+        assert_eq!(auction.time_left, TimeLeft::Unknown);
+    }
+}
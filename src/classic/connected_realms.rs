@@ -0,0 +1,196 @@
+//! Connected realm data and APIs for World of Warcraft Classic, grouping realms with their
+//! shared status, population, and queue.
+
+use serde::{Deserialize, Serialize};
+
+use crate::classic::auctions::AuctionHousesIndex;
+use crate::classic::realms::Realm;
+use crate::classic::WorldOfWarcraftClassicConnector;
+pub use crate::connected_realms::{
+    ConnectedRealmPopulation, ConnectedRealmStatus, RealmPopulation, RealmStatus,
+};
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentKey, Links};
+use crate::errors::BubbleHearthResult;
+use crate::search::{SearchField, SearchPaginator, SearchQuery, SearchResult};
+
+/// Response structure from the connected realms index endpoint, listing keys to all connected realms.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct ConnectedRealmsIndex {
+    /// Top-level document link to follow for a selected connected realm ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of document keys for all available connected realms.
+    pub connected_realms: Vec<DocumentKey<ConnectedRealm>>,
+}
+
+/// A group of realms sharing a status, population, and queue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectedRealm {
+    /// Top-level document link to follow of the selected connected realm ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric connected realm ID.
+    pub id: u64,
+    /// Flag for indicating if the connected realms currently have a login queue.
+    pub has_queue: Option<bool>,
+    /// Current status of the connected realms, i.e. up or down.
+    pub status: Option<ConnectedRealmStatus>,
+    /// Current population of the connected realms, i.e. low, high, full, etc.
+    pub population: Option<ConnectedRealmPopulation>,
+    /// Realms belonging to the connected realm.
+    #[serde(default)]
+    pub realms: Vec<Realm>,
+    /// Document key of the connected realm's auctions.
+    pub auctions: Option<DocumentKey<AuctionHousesIndex>>,
+}
+
+/// Searchable fields of connected realm documents. Localized fields, i.e. realm names and categories,
+/// should be filtered on with a locale.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ConnectedRealmSearchField {
+    /// Numeric connected realm ID.
+    Id,
+    /// Flag for indicating if the connected realms currently have a login queue.
+    HasQueue,
+    /// Connected realm status type, i.e. UP, DOWN.
+    StatusType,
+    /// Connected realm population type, i.e. LOW, FULL, etc.
+    PopulationType,
+    /// Numeric ID of a member realm.
+    RealmId,
+    /// Localized name of a member realm.
+    RealmName,
+    /// Slugified name of a member realm.
+    RealmSlug,
+    /// Localized category of a member realm.
+    RealmCategory,
+    /// Locale of a member realm.
+    RealmLocale,
+    /// Timezone name of a member realm.
+    RealmTimezone,
+    /// Base realm type of a member realm, i.e. NORMAL, RP, etc.
+    RealmType,
+}
+
+impl ConnectedRealm {
+    /// Determines if the connected realms are reported as available for login.
+    pub fn is_up(&self) -> bool {
+        self.status
+            .as_ref()
+            .is_some_and(|status| status.status_type == RealmStatus::Up)
+    }
+
+    /// Determines if the connected realms are reported as being at their population limit.
+    pub fn is_full(&self) -> bool {
+        self.population
+            .as_ref()
+            .is_some_and(|population| population.population_type == RealmPopulation::Full)
+    }
+}
+
+impl SearchField for ConnectedRealmSearchField {
+    fn get_field_name(&self) -> &str {
+        match self {
+            ConnectedRealmSearchField::Id => "id",
+            ConnectedRealmSearchField::HasQueue => "has_queue",
+            ConnectedRealmSearchField::StatusType => "status.type",
+            ConnectedRealmSearchField::PopulationType => "population.type",
+            ConnectedRealmSearchField::RealmId => "realms.id",
+            ConnectedRealmSearchField::RealmName => "realms.name",
+            ConnectedRealmSearchField::RealmSlug => "realms.slug",
+            ConnectedRealmSearchField::RealmCategory => "realms.category",
+            ConnectedRealmSearchField::RealmLocale => "realms.locale",
+            ConnectedRealmSearchField::RealmTimezone => "realms.timezone",
+            ConnectedRealmSearchField::RealmType => "realms.type.type",
+        }
+    }
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves keys to all available connected realms.
+    pub async fn get_connected_realms(&self) -> BubbleHearthResult<ConnectedRealmsIndex> {
+        let url = self.get_dynamic_data_url("connected-realm/index");
+
+        let connected_realms = self
+            .client
+            .send_request_and_deserialize::<ConnectedRealmsIndex>(url)
+            .await?;
+
+        Ok(connected_realms)
+    }
+
+    /// Retrieves a connected realm's status, population, and member realms based on the connected realm ID.
+    pub async fn get_connected_realm(
+        &self,
+        connected_realm_id: u64,
+    ) -> BubbleHearthResult<Option<ConnectedRealm>> {
+        let url = self.get_dynamic_data_url(&format!("connected-realm/{}", connected_realm_id));
+
+        let mut connected_realm = self
+            .client
+            .send_request_and_optionally_deserialize::<ConnectedRealm>(url)
+            .await?;
+
+        if let Some(connected_realm) = connected_realm.as_mut() {
+            self.tag_realms(&mut connected_realm.realms);
+        }
+
+        Ok(connected_realm)
+    }
+
+    /// Retrieves data for each of the connected realm IDs using the client's bulk concurrency,
+    /// returning each connected realm's result in the same order as the provided IDs.
+    pub async fn get_connected_realms_by_ids(
+        &self,
+        connected_realm_ids: &[u64],
+    ) -> Vec<BubbleHearthResult<Option<ConnectedRealm>>> {
+        self.client
+            .fetch_bulk(connected_realm_ids.iter().copied(), |connected_realm_id| {
+                self.get_connected_realm(connected_realm_id)
+            })
+            .await
+    }
+
+    /// Searches for connected realms matching the query, returning the requested page or the first page when omitted.
+    pub async fn search_connected_realms(
+        &self,
+        query: &SearchQuery<ConnectedRealmSearchField>,
+        page: Option<u32>,
+    ) -> BubbleHearthResult<SearchResult<ConnectedRealm>> {
+        let url = query.get_search_page_url(
+            &self.get_dynamic_search_url("connected-realm"),
+            page.unwrap_or(1),
+        );
+
+        let mut search_result = self
+            .client
+            .send_request_and_deserialize::<SearchResult<ConnectedRealm>>(url)
+            .await?;
+
+        for item in search_result.results.iter_mut() {
+            self.tag_realms(&mut item.data.realms);
+        }
+
+        Ok(search_result)
+    }
+
+    /// Constructs a paginator over all connected realm search pages matching the query.
+    pub fn search_connected_realms_paginator(
+        &self,
+        query: &SearchQuery<ConnectedRealmSearchField>,
+    ) -> SearchPaginator<'a, ConnectedRealm> {
+        let flavor = self.flavor;
+
+        SearchPaginator::for_query(
+            self.client,
+            &self.get_dynamic_search_url("connected-realm"),
+            query,
+        )
+        .with_item_mapper(move |connected_realm: &mut ConnectedRealm| {
+            for realm in connected_realm.realms.iter_mut() {
+                realm.flavor = Some(flavor);
+            }
+        })
+    }
+}
@@ -0,0 +1,242 @@
+//! Creature data and APIs for World of Warcraft Classic, including creature families, types,
+//! display media, and searching.
+
+use serde::{Deserialize, Serialize};
+
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::localization::StringOrStructLocale;
+use crate::media::Media;
+use crate::search::{SearchField, SearchPaginator, SearchQuery, SearchResult};
+
+/// Response structure from the creature families index endpoint, listing all creature families.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct CreatureFamiliesIndex {
+    /// Top-level document link to follow for a selected creature family ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available creature families.
+    pub creature_families: Vec<DocumentReference<CreatureFamily>>,
+}
+
+/// Creature family metadata, i.e. Wolf, Cat, Bear, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatureFamily {
+    /// Top-level document link to follow of the selected creature family ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric creature family ID.
+    pub id: u64,
+    /// Localized creature family name.
+    pub name: StringOrStructLocale,
+    /// Reference to the creature family's media.
+    pub media: Option<DocumentReference<Media>>,
+}
+
+/// Response structure from the creature types index endpoint, listing all creature types.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct CreatureTypesIndex {
+    /// Top-level document link to follow for a selected creature type ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available creature types.
+    pub creature_types: Vec<DocumentReference<CreatureType>>,
+}
+
+/// Creature type metadata, i.e. Beast, Dragonkin, Undead, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatureType {
+    /// Top-level document link to follow of the selected creature type ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric creature type ID.
+    pub id: u64,
+    /// Localized creature type name.
+    pub name: StringOrStructLocale,
+}
+
+/// Creature metadata, including its type, family, and displays.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Creature {
+    /// Top-level document link to follow of the selected creature ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric creature ID.
+    pub id: u64,
+    /// Localized creature name.
+    pub name: StringOrStructLocale,
+    /// Reference to the creature's type.
+    #[serde(rename = "type")]
+    pub creature_type: Option<DocumentReference<CreatureType>>,
+    /// Reference to the creature's family, only present for beasts and demons.
+    pub family: Option<DocumentReference<CreatureFamily>>,
+    /// References to the creature's display media.
+    #[serde(default)]
+    pub creature_displays: Vec<DocumentReference<Media>>,
+    /// Flag for indicating if the creature is tameable by hunters.
+    pub is_tameable: Option<bool>,
+}
+
+/// Searchable fields of creature documents. Localized fields, i.e. the name, type name,
+/// and family name, should be filtered on with a locale.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CreatureSearchField {
+    /// Numeric creature ID.
+    Id,
+    /// Localized creature name.
+    Name,
+    /// Numeric ID of the creature's type.
+    TypeId,
+    /// Localized name of the creature's type.
+    TypeName,
+    /// Numeric ID of the creature's family.
+    FamilyId,
+    /// Localized name of the creature's family.
+    FamilyName,
+    /// Numeric ID of one of the creature's displays.
+    CreatureDisplayId,
+    /// Flag for indicating if the creature is tameable by hunters.
+    IsTameable,
+}
+
+impl SearchField for CreatureSearchField {
+    fn get_field_name(&self) -> &str {
+        match self {
+            CreatureSearchField::Id => "id",
+            CreatureSearchField::Name => "name",
+            CreatureSearchField::TypeId => "type.id",
+            CreatureSearchField::TypeName => "type.name",
+            CreatureSearchField::FamilyId => "family.id",
+            CreatureSearchField::FamilyName => "family.name",
+            CreatureSearchField::CreatureDisplayId => "creature_displays.id",
+            CreatureSearchField::IsTameable => "is_tameable",
+        }
+    }
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves data about all available creature families.
+    pub async fn get_creature_families(&self) -> BubbleHearthResult<CreatureFamiliesIndex> {
+        let url = self.get_static_data_url("creature-family/index");
+
+        let creature_families = self
+            .client
+            .send_request_and_deserialize::<CreatureFamiliesIndex>(url)
+            .await?;
+
+        Ok(creature_families)
+    }
+
+    /// Retrieves a creature family's metadata based on the creature family ID.
+    pub async fn get_creature_family(
+        &self,
+        creature_family_id: u32,
+    ) -> BubbleHearthResult<Option<CreatureFamily>> {
+        let url = self.get_static_data_url(&format!("creature-family/{}", creature_family_id));
+
+        let creature_family = self
+            .client
+            .send_request_and_optionally_deserialize::<CreatureFamily>(url)
+            .await?;
+
+        Ok(creature_family)
+    }
+
+    /// Retrieves data about all available creature types.
+    pub async fn get_creature_types(&self) -> BubbleHearthResult<CreatureTypesIndex> {
+        let url = self.get_static_data_url("creature-type/index");
+
+        let creature_types = self
+            .client
+            .send_request_and_deserialize::<CreatureTypesIndex>(url)
+            .await?;
+
+        Ok(creature_types)
+    }
+
+    /// Retrieves a creature type's metadata based on the creature type ID.
+    pub async fn get_creature_type(
+        &self,
+        creature_type_id: u32,
+    ) -> BubbleHearthResult<Option<CreatureType>> {
+        let url = self.get_static_data_url(&format!("creature-type/{}", creature_type_id));
+
+        let creature_type = self
+            .client
+            .send_request_and_optionally_deserialize::<CreatureType>(url)
+            .await?;
+
+        Ok(creature_type)
+    }
+
+    /// Retrieves a creature's metadata based on the creature ID.
+    pub async fn get_creature(&self, creature_id: u32) -> BubbleHearthResult<Option<Creature>> {
+        let url = self.get_static_data_url(&format!("creature/{}", creature_id));
+
+        let creature = self
+            .client
+            .send_request_and_optionally_deserialize::<Creature>(url)
+            .await?;
+
+        Ok(creature)
+    }
+
+    /// Retrieves the media assets of a creature display based on the creature display ID.
+    pub async fn get_creature_display_media(
+        &self,
+        creature_display_id: u32,
+    ) -> BubbleHearthResult<Option<Media>> {
+        let url =
+            self.get_static_data_url(&format!("media/creature-display/{}", creature_display_id));
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<Media>(url)
+            .await?;
+
+        Ok(media)
+    }
+
+    /// Retrieves the media assets of a creature family based on the creature family ID.
+    pub async fn get_creature_family_media(
+        &self,
+        creature_family_id: u32,
+    ) -> BubbleHearthResult<Option<Media>> {
+        let url =
+            self.get_static_data_url(&format!("media/creature-family/{}", creature_family_id));
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<Media>(url)
+            .await?;
+
+        Ok(media)
+    }
+
+    /// Searches for creatures matching the query, returning the requested page or the first page when omitted.
+    pub async fn search_creatures(
+        &self,
+        query: &SearchQuery<CreatureSearchField>,
+        page: Option<u32>,
+    ) -> BubbleHearthResult<SearchResult<Creature>> {
+        let url =
+            query.get_search_page_url(&self.get_static_search_url("creature"), page.unwrap_or(1));
+
+        let search_result = self
+            .client
+            .send_request_and_deserialize::<SearchResult<Creature>>(url)
+            .await?;
+
+        Ok(search_result)
+    }
+
+    /// Constructs a paginator over all creature search pages matching the query.
+    pub fn search_creatures_paginator(
+        &self,
+        query: &SearchQuery<CreatureSearchField>,
+    ) -> SearchPaginator<'a, Creature> {
+        SearchPaginator::for_query(self.client, &self.get_static_search_url("creature"), query)
+    }
+}
@@ -0,0 +1,390 @@
+//! Offline guild crest rendering, composing a crest PNG from downloaded emblem and border
+//! layers tinted with the guild's colors. Layers only need to be downloaded once per
+//! emblem and border, after which crests render without any further requests.
+//!
+//! ```rust,no_run
+//! use bubblehearth::classic::crest::{render_guild_crest, GuildCrestSelection};
+//! use bubblehearth::localization::Locale;
+//! use bubblehearth::regionality::AccountRegion;
+//! use bubblehearth::testing::FakeBlizzardServer;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let server = FakeBlizzardServer::start().await;
+//!     let client = server.client(AccountRegion::US, Locale::EnglishUS);
+//!     let classic = client.classic();
+//!     let selection = GuildCrestSelection {
+//!         emblem_id: 126,
+//!         emblem_color_id: 14,
+//!         border_id: 0,
+//!         border_color_id: 15,
+//!         background_color_id: 45,
+//!     };
+//!
+//!     let components = classic.get_guild_crest_components().await.unwrap();
+//!     let layers = classic.get_guild_crest_layers(&selection).await.unwrap();
+//!     let png = render_guild_crest(&components, &selection, &layers).unwrap();
+//!
+//!     std::fs::write("crest.png", png).unwrap();
+//! }
+//! ```
+
+use std::io::Cursor;
+
+use image::imageops::overlay;
+use image::{DynamicImage, ImageOutputFormat, Rgba, RgbaImage};
+
+use crate::classic::guild_crest::{
+    GuildCrest, GuildCrestColor, GuildCrestComponentsIndex, GuildCrestRgba,
+};
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::errors::{BubbleHearthError, BubbleHearthResult};
+use crate::media::{Media, MediaAssetKey};
+
+/// Emblem, border, and color selection of a guild crest, as configured in game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct GuildCrestSelection {
+    /// Numeric emblem ID.
+    pub emblem_id: u32,
+    /// Numeric emblem color ID.
+    pub emblem_color_id: u32,
+    /// Numeric border ID.
+    pub border_id: u32,
+    /// Numeric border color ID.
+    pub border_color_id: u32,
+    /// Numeric background color ID.
+    pub background_color_id: u32,
+}
+
+impl From<&GuildCrest> for GuildCrestSelection {
+    /// Builds the selection of a guild's crest, as returned on guild profiles and character appearances.
+    fn from(crest: &GuildCrest) -> Self {
+        Self {
+            emblem_id: crest.emblem.id,
+            emblem_color_id: crest.emblem.color.id,
+            border_id: crest.border.id,
+            border_color_id: crest.border.color.id,
+            background_color_id: crest.background.color.id,
+        }
+    }
+}
+
+/// Downloaded, untinted emblem and border layer images of a guild crest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuildCrestLayers {
+    /// Raw image bytes of the emblem layer.
+    pub emblem: Vec<u8>,
+    /// Raw image bytes of the border layer.
+    pub border: Vec<u8>,
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Downloads the untinted emblem and border layer images of the selected crest.
+    pub async fn get_guild_crest_layers(
+        &self,
+        selection: &GuildCrestSelection,
+    ) -> BubbleHearthResult<GuildCrestLayers> {
+        let (emblem_media, border_media) = futures::try_join!(
+            self.get_guild_crest_emblem_media(selection.emblem_id),
+            self.get_guild_crest_border_media(selection.border_id)
+        )?;
+
+        let emblem_url = get_layer_url(emblem_media, "emblem", selection.emblem_id)?;
+        let border_url = get_layer_url(border_media, "border", selection.border_id)?;
+
+        let (emblem, border) = futures::try_join!(
+            self.client.download_asset(emblem_url),
+            self.client.download_asset(border_url)
+        )?;
+
+        Ok(GuildCrestLayers { emblem, border })
+    }
+}
+
+/// Renders the selected crest as a PNG, tinting the emblem and border layers with their colors.
+/// The background color fills the border's silhouette, with the border and emblem layered on top.
+pub fn render_guild_crest(
+    components: &GuildCrestComponentsIndex,
+    selection: &GuildCrestSelection,
+    layers: &GuildCrestLayers,
+) -> BubbleHearthResult<Vec<u8>> {
+    let colors = &components.colors;
+    let emblem_color = find_color(&colors.emblems, "emblem", selection.emblem_color_id)?;
+    let border_color = find_color(&colors.borders, "border", selection.border_color_id)?;
+    let background_color = find_color(
+        &colors.backgrounds,
+        "background",
+        selection.background_color_id,
+    )?;
+
+    let emblem = tint_layer(decode_layer(&layers.emblem)?, emblem_color);
+    let border = tint_layer(decode_layer(&layers.border)?, border_color);
+    let mut crest = RgbaImage::new(
+        emblem.width().max(border.width()),
+        emblem.height().max(border.height()),
+    );
+
+    fill_silhouette(&mut crest, &border, background_color);
+    overlay_centered(&mut crest, &border);
+    overlay_centered(&mut crest, &emblem);
+
+    let mut png = Vec::new();
+    DynamicImage::ImageRgba8(crest)
+        .write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png)
+        .map_err(|e| BubbleHearthError::GuildCrestRenderFailed(e.to_string()))?;
+
+    Ok(png)
+}
+
+/// Gets the image URL of a crest layer's media, erroring if the layer or its image is not found.
+fn get_layer_url(media: Option<Media>, layer: &str, id: u32) -> BubbleHearthResult<String> {
+    let media = media.unwrap_or_default();
+    let asset = media
+        .get_asset(MediaAssetKey::Image)
+        .or_else(|| media.assets.first());
+
+    match asset {
+        Some(asset) => Ok(asset.value.clone()),
+        None => Err(BubbleHearthError::GuildCrestRenderFailed(format!(
+            "No image was found for {} {}.",
+            layer, id
+        ))),
+    }
+}
+
+/// Finds the layer color by ID, erroring if the color is not available for the layer.
+fn find_color(
+    colors: &[GuildCrestColor],
+    layer: &str,
+    id: u32,
+) -> BubbleHearthResult<GuildCrestRgba> {
+    colors
+        .iter()
+        .find(|color| color.id == id)
+        .map(|color| color.rgba)
+        .ok_or_else(|| {
+            BubbleHearthError::GuildCrestRenderFailed(format!(
+                "No {} color was found for ID {}.",
+                layer, id
+            ))
+        })
+}
+
+/// Decodes a downloaded layer image into RGBA pixels.
+fn decode_layer(bytes: &[u8]) -> BubbleHearthResult<RgbaImage> {
+    image::load_from_memory(bytes)
+        .map(|layer| layer.to_rgba8())
+        .map_err(|e| BubbleHearthError::GuildCrestRenderFailed(e.to_string()))
+}
+
+/// Tints a layer by multiplying each pixel with the color, preserving the layer's shading.
+fn tint_layer(mut layer: RgbaImage, color: GuildCrestRgba) -> RgbaImage {
+    let tint = [color.r, color.g, color.b];
+
+    for pixel in layer.pixels_mut() {
+        for (channel, tint_channel) in pixel.0.iter_mut().zip(tint) {
+            *channel = multiply_channel(*channel, tint_channel);
+        }
+
+        pixel.0[3] = (f64::from(pixel.0[3]) * color.a.clamp(0.0, 1.0)).round() as u8;
+    }
+
+    layer
+}
+
+/// Multiplies two color channels, normalized to the channel range.
+fn multiply_channel(channel: u8, tint: u8) -> u8 {
+    ((u16::from(channel) * u16::from(tint) + 127) / 255) as u8
+}
+
+/// Fills the background color across each row of the border's silhouette, spanning from
+/// the leftmost to the rightmost visible border pixel.
+fn fill_silhouette(crest: &mut RgbaImage, border: &RgbaImage, color: GuildCrestRgba) {
+    let background = Rgba([
+        color.r,
+        color.g,
+        color.b,
+        (color.a.clamp(0.0, 1.0) * 255.0).round() as u8,
+    ]);
+    let x_offset = (crest.width() - border.width()) / 2;
+    let y_offset = (crest.height() - border.height()) / 2;
+
+    for y in 0..border.height() {
+        let visible: Vec<u32> = (0..border.width())
+            .filter(|x| border.get_pixel(*x, y).0[3] > 0)
+            .collect();
+
+        if let (Some(first), Some(last)) = (visible.first(), visible.last()) {
+            for x in *first..=*last {
+                crest.put_pixel(x + x_offset, y + y_offset, background);
+            }
+        }
+    }
+}
+
+/// Alpha blends the layer over the crest, centered on the crest.
+fn overlay_centered(crest: &mut RgbaImage, layer: &RgbaImage) {
+    let x = (crest.width() - layer.width()) / 2;
+    let y = (crest.height() - layer.height()) / 2;
+    overlay(crest, layer, i64::from(x), i64::from(y));
+}
+
+#[cfg(test)]
+mod crest_tests {
+    use std::io::Cursor;
+
+    use image::{DynamicImage, ImageOutputFormat, Rgba, RgbaImage};
+
+    use crate::classic::crest::{render_guild_crest, GuildCrestLayers, GuildCrestSelection};
+    use crate::classic::guild_crest::{
+        GuildCrest, GuildCrestBackground, GuildCrestColor, GuildCrestColors,
+        GuildCrestComponentsIndex, GuildCrestLayer, GuildCrestRgba,
+    };
+    use crate::errors::BubbleHearthError;
+
+    fn get_png(image: RgbaImage) -> Vec<u8> {
+        let mut png = Vec::new();
+        DynamicImage::ImageRgba8(image)
+            .write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png)
+            .unwrap();
+        png
+    }
+
+    fn get_color(id: u32, r: u8, g: u8, b: u8) -> GuildCrestColor {
+        GuildCrestColor {
+            id,
+            rgba: GuildCrestRgba { r, g, b, a: 1.0 },
+        }
+    }
+
+    fn get_components() -> GuildCrestComponentsIndex {
+        GuildCrestComponentsIndex {
+            colors: GuildCrestColors {
+                emblems: vec![get_color(1, 255, 0, 0)],
+                borders: vec![get_color(2, 0, 0, 255)],
+                backgrounds: vec![get_color(3, 0, 255, 0)],
+            },
+            ..Default::default()
+        }
+    }
+
+    fn get_selection() -> GuildCrestSelection {
+        GuildCrestSelection {
+            emblem_id: 1,
+            emblem_color_id: 1,
+            border_id: 1,
+            border_color_id: 2,
+            background_color_id: 3,
+        }
+    }
+
+    /// Builds 4x4 layers, with a white emblem pixel in the center and a white border ring.
+    fn get_layers() -> GuildCrestLayers {
+        let emblem = RgbaImage::from_fn(2, 2, |x, y| {
+            if x == 0 && y == 0 {
+                Rgba([255, 255, 255, 255])
+            } else {
+                Rgba([0, 0, 0, 0])
+            }
+        });
+        let border = RgbaImage::from_fn(4, 4, |x, y| {
+            if x == 0 || x == 3 {
+                Rgba([255, 255, 255, 255])
+            } else if y == 0 {
+                Rgba([128, 128, 128, 255])
+            } else {
+                Rgba([0, 0, 0, 0])
+            }
+        });
+
+        GuildCrestLayers {
+            emblem: get_png(emblem),
+            border: get_png(border),
+        }
+    }
+
+    #[test]
+    fn returns_crest_with_tinted_layers_over_background() {
+        // Arrange
+        let components = get_components();
+
+        // Act
+        let png = render_guild_crest(&components, &get_selection(), &get_layers()).unwrap();
+        let crest = image::load_from_memory(&png).unwrap().to_rgba8();
+
+        // Assert
+        assert_eq!(crest.dimensions(), (4, 4));
+        assert_eq!(crest.get_pixel(0, 2), &Rgba([0, 0, 255, 255]));
+        assert_eq!(crest.get_pixel(1, 0), &Rgba([0, 0, 128, 255]));
+        assert_eq!(crest.get_pixel(1, 1), &Rgba([255, 0, 0, 255]));
+        assert_eq!(crest.get_pixel(2, 2), &Rgba([0, 255, 0, 255]));
+    }
+
+    #[test]
+    fn returns_error_when_color_not_found() {
+        // Arrange
+        let selection = GuildCrestSelection {
+            background_color_id: 42,
+            ..get_selection()
+        };
+
+        // Act
+        let png = render_guild_crest(&get_components(), &selection, &get_layers());
+
+        // Assert
+        assert!(matches!(
+            png,
+            Err(BubbleHearthError::GuildCrestRenderFailed(_))
+        ));
+    }
+
+    #[test]
+    fn returns_error_when_layer_not_an_image() {
+        // Arrange
+        let layers = GuildCrestLayers {
+            emblem: b"not a png".to_vec(),
+            ..get_layers()
+        };
+
+        // Act
+        let png = render_guild_crest(&get_components(), &get_selection(), &layers);
+
+        // Assert
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn returns_selection_when_built_from_guild_crest() {
+        // Arrange
+        let crest = GuildCrest {
+            emblem: GuildCrestLayer {
+                id: 126,
+                media: None,
+                color: get_color(15, 255, 255, 255),
+            },
+            border: GuildCrestLayer {
+                id: 0,
+                media: None,
+                color: get_color(2, 103, 76, 20),
+            },
+            background: GuildCrestBackground {
+                color: get_color(45, 0, 59, 79),
+            },
+        };
+
+        // Act
+        let selection = GuildCrestSelection::from(&crest);
+
+        // Assert
+        assert_eq!(
+            selection,
+            GuildCrestSelection {
+                emblem_id: 126,
+                emblem_color_id: 15,
+                border_id: 0,
+                border_color_id: 2,
+                background_color_id: 45,
+            }
+        );
+    }
+}
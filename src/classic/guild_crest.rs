@@ -0,0 +1,147 @@
+//! Guild crest component data and APIs for World of Warcraft Classic, listing the emblems,
+//! borders, and colors guild crests are composed from, along with their media.
+
+use serde::{Deserialize, Serialize};
+
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::media::Media;
+
+/// Response structure from the guild crest components index endpoint, listing all emblems, borders, and colors.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildCrestComponentsIndex {
+    /// Top-level document link of the guild crest components.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// List of available crest emblems.
+    #[serde(default)]
+    pub emblems: Vec<GuildCrestComponent>,
+    /// List of available crest borders.
+    #[serde(default)]
+    pub borders: Vec<GuildCrestComponent>,
+    /// Available colors for each crest layer.
+    #[serde(default)]
+    pub colors: GuildCrestColors,
+}
+
+/// A crest emblem or border, along with a reference to its media.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildCrestComponent {
+    /// Numeric emblem or border ID.
+    pub id: u32,
+    /// Reference to the emblem or border media.
+    pub media: Option<DocumentReference<Media>>,
+}
+
+/// Available colors for each crest layer.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildCrestColors {
+    /// Available emblem colors.
+    #[serde(default)]
+    pub emblems: Vec<GuildCrestColor>,
+    /// Available border colors.
+    #[serde(default)]
+    pub borders: Vec<GuildCrestColor>,
+    /// Available background colors.
+    #[serde(default)]
+    pub backgrounds: Vec<GuildCrestColor>,
+}
+
+/// A crest layer color.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildCrestColor {
+    /// Numeric color ID, unique within the layer.
+    pub id: u32,
+    /// RGBA value of the color.
+    pub rgba: GuildCrestRgba,
+}
+
+/// RGBA value of a crest color.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildCrestRgba {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+    /// Alpha channel, between zero and one.
+    pub a: f64,
+}
+
+/// A guild's selected crest, as displayed on guild profiles and character appearances.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildCrest {
+    /// Selected crest emblem and its color.
+    pub emblem: GuildCrestLayer,
+    /// Selected crest border and its color.
+    pub border: GuildCrestLayer,
+    /// Selected crest background color.
+    pub background: GuildCrestBackground,
+}
+
+/// A selected crest emblem or border, along with its color.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildCrestLayer {
+    /// Numeric emblem or border ID.
+    pub id: u32,
+    /// Reference to the emblem or border media.
+    pub media: Option<DocumentReference<Media>>,
+    /// Selected color of the emblem or border.
+    pub color: GuildCrestColor,
+}
+
+/// A selected crest background color.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildCrestBackground {
+    /// Selected color of the background.
+    pub color: GuildCrestColor,
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves all available guild crest emblems, borders, and colors.
+    pub async fn get_guild_crest_components(
+        &self,
+    ) -> BubbleHearthResult<GuildCrestComponentsIndex> {
+        let url = self.get_static_data_url("guild-crest/index");
+
+        let components = self
+            .client
+            .send_request_and_deserialize::<GuildCrestComponentsIndex>(url)
+            .await?;
+
+        Ok(components)
+    }
+
+    /// Retrieves the media assets of a guild crest border based on the border ID.
+    pub async fn get_guild_crest_border_media(
+        &self,
+        border_id: u32,
+    ) -> BubbleHearthResult<Option<Media>> {
+        let url = self.get_static_data_url(&format!("media/guild-crest/border/{}", border_id));
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<Media>(url)
+            .await?;
+
+        Ok(media)
+    }
+
+    /// Retrieves the media assets of a guild crest emblem based on the emblem ID.
+    pub async fn get_guild_crest_emblem_media(
+        &self,
+        emblem_id: u32,
+    ) -> BubbleHearthResult<Option<Media>> {
+        let url = self.get_static_data_url(&format!("media/guild-crest/emblem/{}", emblem_id));
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<Media>(url)
+            .await?;
+
+        Ok(media)
+    }
+}
@@ -0,0 +1,754 @@
+//! Item data and APIs for World of Warcraft Classic, including item classes, subclasses,
+//! item tooltip previews, media, and searching.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::localization::StringOrStructLocale;
+use crate::media::Media;
+use crate::search::{SearchField, SearchPaginator, SearchQuery, SearchResult};
+
+/// Response structure from the item classes index endpoint, listing all item classes.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct ItemClassesIndex {
+    /// Top-level document link to follow for a selected item class ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available item classes.
+    pub item_classes: Vec<DocumentReference<ItemClass>>,
+}
+
+/// Item class metadata, i.e. Weapon, Armor, Consumable, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemClass {
+    /// Top-level document link to follow of the selected item class ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric item class ID.
+    pub class_id: u64,
+    /// Localized item class name.
+    pub name: StringOrStructLocale,
+    /// References to the subclasses of the item class.
+    #[serde(default)]
+    pub item_subclasses: Vec<DocumentReference<ItemSubclass>>,
+}
+
+/// Item subclass metadata, i.e. One-Handed Swords, Cloth, Potions, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemSubclass {
+    /// Top-level document link to follow of the selected item subclass ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric ID of the parent item class.
+    pub class_id: u64,
+    /// Numeric item subclass ID, unique within the parent item class.
+    pub subclass_id: u64,
+    /// Localized item subclass name.
+    pub display_name: StringOrStructLocale,
+    /// Localized descriptive item subclass name, if it differs from the display name.
+    pub verbose_name: Option<StringOrStructLocale>,
+    /// Flag for indicating if the subclass is hidden within item tooltips.
+    pub hide_subclass_in_tooltips: Option<bool>,
+}
+
+/// Item metadata, including its classification, pricing, and tooltip preview.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    /// Top-level document link to follow of the selected item ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric item ID.
+    pub id: u64,
+    /// Localized item name.
+    pub name: StringOrStructLocale,
+    /// Quality of the item, i.e. common, rare, epic, etc.
+    pub quality: Option<ItemQualityDetails>,
+    /// Item level.
+    pub level: Option<u32>,
+    /// Character level required to use the item.
+    pub required_level: Option<u32>,
+    /// Reference to the item's media.
+    pub media: Option<DocumentReference<Media>>,
+    /// Reference to the item's class.
+    pub item_class: Option<DocumentReference<ItemClass>>,
+    /// Reference to the item's subclass.
+    pub item_subclass: Option<DocumentReference<ItemSubclass>>,
+    /// Equipment slot of the item, i.e. head, chest, two-handed weapon, etc.
+    pub inventory_type: Option<InventoryTypeDetails>,
+    /// Vendor purchase price of the item in copper.
+    pub purchase_price: Option<u64>,
+    /// Vendor sell price of the item in copper.
+    pub sell_price: Option<u64>,
+    /// Maximum number of the item a character may carry, zero when unlimited.
+    pub max_count: Option<u32>,
+    /// Flag for indicating if the item can be equipped.
+    pub is_equippable: Option<bool>,
+    /// Flag for indicating if the item stacks within inventory slots.
+    pub is_stackable: Option<bool>,
+    /// Number of items received per vendor purchase.
+    pub purchase_quantity: Option<u32>,
+    /// Tooltip preview of the item, including binding, stats, and spells.
+    pub preview_item: Option<ItemPreview>,
+}
+
+/// Tooltip preview of an item, as displayed in game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemPreview {
+    /// Reference to the previewed item.
+    pub item: Option<DocumentReference<Item>>,
+    /// Localized item name.
+    pub name: Option<StringOrStructLocale>,
+    /// Quality of the item, i.e. common, rare, epic, etc.
+    pub quality: Option<ItemQualityDetails>,
+    /// Reference to the item's media.
+    pub media: Option<DocumentReference<Media>>,
+    /// Reference to the item's class.
+    pub item_class: Option<DocumentReference<ItemClass>>,
+    /// Reference to the item's subclass.
+    pub item_subclass: Option<DocumentReference<ItemSubclass>>,
+    /// Equipment slot of the item, i.e. head, chest, two-handed weapon, etc.
+    pub inventory_type: Option<InventoryTypeDetails>,
+    /// Binding of the item, i.e. binds when picked up, binds when equipped, etc.
+    pub binding: Option<ItemBindingDetails>,
+    /// Localized unique equipped restriction, if the item is unique.
+    pub unique_equipped: Option<StringOrStructLocale>,
+    /// Weapon damage and speed, if the item is a weapon.
+    pub weapon: Option<ItemWeapon>,
+    /// Armor provided by the item, if any.
+    pub armor: Option<ItemArmor>,
+    /// Stats provided by the item.
+    #[serde(default)]
+    pub stats: Vec<ItemStat>,
+    /// Spells triggered by equipping or using the item.
+    #[serde(default)]
+    pub spells: Vec<ItemSpell>,
+    /// Vendor sell price of the item, including its display strings.
+    pub sell_price: Option<ItemSellPrice>,
+    /// Requirements for using the item.
+    pub requirements: Option<ItemRequirements>,
+    /// Item level, including its display string.
+    pub level: Option<ItemDisplayValue<u32>>,
+    /// Maximum durability of the item, including its display string.
+    pub durability: Option<ItemDisplayValue<u32>>,
+    /// Localized flavor text of the item.
+    pub description: Option<StringOrStructLocale>,
+}
+
+/// A tooltip value along with its localized display string.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemDisplayValue<T> {
+    /// Raw value.
+    pub value: T,
+    /// Localized display string of the value, i.e. "Durability 125 / 125".
+    pub display_string: Option<StringOrStructLocale>,
+}
+
+/// Weapon damage and speed of an item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemWeapon {
+    /// Damage range of the weapon.
+    pub damage: Option<ItemWeaponDamage>,
+    /// Attack speed of the weapon in milliseconds.
+    pub attack_speed: Option<ItemDisplayValue<u32>>,
+    /// Damage per second of the weapon.
+    pub dps: Option<ItemDisplayValue<f64>>,
+}
+
+/// Damage range of a weapon.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemWeaponDamage {
+    /// Minimum damage dealt per hit.
+    pub min_value: u32,
+    /// Maximum damage dealt per hit.
+    pub max_value: u32,
+    /// Localized display string of the damage range.
+    pub display_string: Option<StringOrStructLocale>,
+    /// School of damage dealt, i.e. physical, fire, etc.
+    pub damage_class: Option<ItemDamageClass>,
+}
+
+/// School of damage dealt by a weapon.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemDamageClass {
+    /// Base damage school, i.e. PHYSICAL, FIRE, etc.
+    #[serde(rename = "type")]
+    pub damage_type: String,
+    /// Localized damage school name.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Armor provided by an item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemArmor {
+    /// Amount of armor provided.
+    pub value: u32,
+    /// Display metadata of the armor.
+    pub display: Option<ItemStatDisplay>,
+}
+
+/// A stat provided by an item, i.e. stamina, strength, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemStat {
+    /// Type of the stat.
+    #[serde(rename = "type")]
+    pub stat_type: ItemStatType,
+    /// Amount of the stat provided, negative for penalties.
+    pub value: i64,
+    /// Flag for indicating if the stat is a penalty.
+    pub is_negated: Option<bool>,
+    /// Display metadata of the stat.
+    pub display: Option<ItemStatDisplay>,
+}
+
+/// Type of a stat provided by an item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemStatType {
+    /// Base stat type, i.e. STAMINA, STRENGTH, FIRE_RESISTANCE, etc.
+    #[serde(rename = "type")]
+    pub stat_type: String,
+    /// Localized stat name.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Display metadata of a tooltip line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemStatDisplay {
+    /// Localized display string, i.e. "+20 Stamina".
+    pub display_string: Option<StringOrStructLocale>,
+    /// Color of the tooltip line.
+    pub color: Option<ItemDisplayColor>,
+}
+
+/// RGBA color of a tooltip line.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemDisplayColor {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+    /// Alpha channel, between zero and one.
+    pub a: f64,
+}
+
+/// A spell triggered by equipping or using an item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemSpell {
+    /// Reference to the triggered spell.
+    pub spell: Option<DocumentReference>,
+    /// Localized tooltip description of the spell.
+    pub description: Option<StringOrStructLocale>,
+}
+
+/// Vendor sell price of an item, including its display strings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemSellPrice {
+    /// Sell price in copper.
+    pub value: u64,
+    /// Localized display strings of the sell price.
+    pub display_strings: Option<ItemSellPriceDisplay>,
+}
+
+/// Localized display strings of an item's sell price, broken into coin denominations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemSellPriceDisplay {
+    /// Localized sell price header, i.e. "Sell Price:".
+    pub header: Option<StringOrStructLocale>,
+    /// Gold portion of the sell price.
+    pub gold: Option<StringOrStructLocale>,
+    /// Silver portion of the sell price.
+    pub silver: Option<StringOrStructLocale>,
+    /// Copper portion of the sell price.
+    pub copper: Option<StringOrStructLocale>,
+}
+
+/// Requirements for using an item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemRequirements {
+    /// Character level required to use the item.
+    pub level: Option<ItemDisplayValue<u32>>,
+}
+
+/// Quality data of an item, including the localized name of the quality.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemQualityDetails {
+    /// Typed item quality.
+    #[serde(rename = "type")]
+    pub quality_type: ItemQuality,
+    /// Localized name of the quality.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Inventory type data of an item, including the localized name of the inventory type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventoryTypeDetails {
+    /// Typed inventory type.
+    #[serde(rename = "type")]
+    pub inventory_type: InventoryType,
+    /// Localized name of the inventory type.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Binding data of an item, including the localized name of the binding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemBindingDetails {
+    /// Typed item binding.
+    #[serde(rename = "type")]
+    pub binding_type: ItemBinding,
+    /// Localized name of the binding, i.e. "Binds when picked up".
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Item qualities, determining the color of the item's name.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ItemQuality {
+    /// Represents gray, poor quality items.
+    Poor,
+    /// Represents white, common quality items.
+    Common,
+    /// Represents green, uncommon quality items.
+    Uncommon,
+    /// Represents blue, rare quality items.
+    Rare,
+    /// Represents purple, epic quality items.
+    Epic,
+    /// Represents orange, legendary quality items.
+    Legendary,
+    /// Represents artifact quality items.
+    Artifact,
+    /// Represents heirloom quality items.
+    Heirloom,
+    /// Represents an unknown quality, detected when the provided quality is not able to be parsed.
+    Unknown,
+}
+
+/// Inventory types, determining the equipment slot of an item.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InventoryType {
+    /// Represents items that cannot be equipped.
+    NonEquip,
+    /// Represents head slot items.
+    Head,
+    /// Represents neck slot items.
+    Neck,
+    /// Represents shoulder slot items.
+    Shoulder,
+    /// Represents shirt slot items.
+    Body,
+    /// Represents chest slot items.
+    Chest,
+    /// Represents robes, equipped in the chest slot.
+    Robe,
+    /// Represents waist slot items.
+    Waist,
+    /// Represents leg slot items.
+    Legs,
+    /// Represents feet slot items.
+    Feet,
+    /// Represents wrist slot items.
+    Wrist,
+    /// Represents hand slot items.
+    Hand,
+    /// Represents finger slot items.
+    Finger,
+    /// Represents trinket slot items.
+    Trinket,
+    /// Represents one-handed weapons, equippable in either hand.
+    Weapon,
+    /// Represents shields, equipped in the off hand.
+    Shield,
+    /// Represents bows, equipped in the ranged slot.
+    Ranged,
+    /// Represents back slot items.
+    Cloak,
+    /// Represents two-handed weapons.
+    #[serde(rename = "TWOHWEAPON")]
+    TwoHandedWeapon,
+    /// Represents bags.
+    Bag,
+    /// Represents tabard slot items.
+    Tabard,
+    /// Represents one-handed weapons equipped in the main hand.
+    #[serde(rename = "WEAPONMAINHAND")]
+    MainHandWeapon,
+    /// Represents one-handed weapons equipped in the off hand.
+    #[serde(rename = "WEAPONOFFHAND")]
+    OffHandWeapon,
+    /// Represents off hand items that are not weapons or shields.
+    Holdable,
+    /// Represents ammunition.
+    Ammo,
+    /// Represents thrown weapons, equipped in the ranged slot.
+    Thrown,
+    /// Represents guns, crossbows, and wands, equipped in the ranged slot.
+    #[serde(rename = "RANGEDRIGHT")]
+    RangedRight,
+    /// Represents quivers and ammo pouches.
+    Quiver,
+    /// Represents librams, idols, and totems, equipped in the relic slot.
+    Relic,
+    /// Represents an unknown inventory type, detected when the provided inventory type is not able to be parsed.
+    Unknown,
+}
+
+/// Item bindings, determining when an item becomes soulbound.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ItemBinding {
+    /// Represents items that bind when picked up.
+    OnAcquire,
+    /// Represents items that bind when equipped.
+    OnEquip,
+    /// Represents items that bind when used.
+    OnUse,
+    /// Represents quest items.
+    Quest,
+    /// Represents items that bind to the account.
+    ToAccount,
+    /// Represents an unknown binding, detected when the provided binding is not able to be parsed.
+    Unknown,
+}
+
+/// Searchable fields of item documents. Localized fields, i.e. the name, should be filtered on with a locale.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ItemSearchField {
+    /// Numeric item ID.
+    Id,
+    /// Localized item name.
+    Name,
+    /// Item level.
+    Level,
+    /// Character level required to use the item.
+    RequiredLevel,
+    /// Quality type of the item, i.e. EPIC.
+    QualityType,
+    /// Numeric ID of the item's class.
+    ItemClassId,
+    /// Numeric ID of the item's subclass.
+    ItemSubclassId,
+    /// Inventory type of the item, i.e. TWOHWEAPON.
+    InventoryType,
+    /// Vendor purchase price of the item in copper.
+    PurchasePrice,
+    /// Vendor sell price of the item in copper.
+    SellPrice,
+    /// Flag for indicating if the item can be equipped.
+    IsEquippable,
+    /// Flag for indicating if the item stacks within inventory slots.
+    IsStackable,
+    /// Numeric ID of the item's media.
+    MediaId,
+}
+
+impl From<String> for ItemQuality {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "POOR" => ItemQuality::Poor,
+            "COMMON" => ItemQuality::Common,
+            "UNCOMMON" => ItemQuality::Uncommon,
+            "RARE" => ItemQuality::Rare,
+            "EPIC" => ItemQuality::Epic,
+            "LEGENDARY" => ItemQuality::Legendary,
+            "ARTIFACT" => ItemQuality::Artifact,
+            "HEIRLOOM" => ItemQuality::Heirloom,
+            _ => ItemQuality::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemQuality {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom(
+                "Invalid data type for item quality.",
+            )),
+        }
+    }
+}
+
+impl From<String> for InventoryType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "NON_EQUIP" => InventoryType::NonEquip,
+            "HEAD" => InventoryType::Head,
+            "NECK" => InventoryType::Neck,
+            "SHOULDER" => InventoryType::Shoulder,
+            "BODY" => InventoryType::Body,
+            "CHEST" => InventoryType::Chest,
+            "ROBE" => InventoryType::Robe,
+            "WAIST" => InventoryType::Waist,
+            "LEGS" => InventoryType::Legs,
+            "FEET" => InventoryType::Feet,
+            "WRIST" => InventoryType::Wrist,
+            "HAND" => InventoryType::Hand,
+            "FINGER" => InventoryType::Finger,
+            "TRINKET" => InventoryType::Trinket,
+            "WEAPON" => InventoryType::Weapon,
+            "SHIELD" => InventoryType::Shield,
+            "RANGED" => InventoryType::Ranged,
+            "CLOAK" => InventoryType::Cloak,
+            "TWOHWEAPON" => InventoryType::TwoHandedWeapon,
+            "BAG" => InventoryType::Bag,
+            "TABARD" => InventoryType::Tabard,
+            "WEAPONMAINHAND" => InventoryType::MainHandWeapon,
+            "WEAPONOFFHAND" => InventoryType::OffHandWeapon,
+            "HOLDABLE" => InventoryType::Holdable,
+            "AMMO" => InventoryType::Ammo,
+            "THROWN" => InventoryType::Thrown,
+            "RANGEDRIGHT" => InventoryType::RangedRight,
+            "QUIVER" => InventoryType::Quiver,
+            "RELIC" => InventoryType::Relic,
+            _ => InventoryType::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for InventoryType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom(
+                "Invalid data type for inventory type.",
+            )),
+        }
+    }
+}
+
+impl From<String> for ItemBinding {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ON_ACQUIRE" => ItemBinding::OnAcquire,
+            "ON_EQUIP" => ItemBinding::OnEquip,
+            "ON_USE" => ItemBinding::OnUse,
+            "QUEST" => ItemBinding::Quest,
+            "TO_ACCOUNT" => ItemBinding::ToAccount,
+            _ => ItemBinding::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemBinding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom(
+                "Invalid data type for item binding.",
+            )),
+        }
+    }
+}
+
+impl SearchField for ItemSearchField {
+    fn get_field_name(&self) -> &str {
+        match self {
+            ItemSearchField::Id => "id",
+            ItemSearchField::Name => "name",
+            ItemSearchField::Level => "level",
+            ItemSearchField::RequiredLevel => "required_level",
+            ItemSearchField::QualityType => "quality.type",
+            ItemSearchField::ItemClassId => "item_class.id",
+            ItemSearchField::ItemSubclassId => "item_subclass.id",
+            ItemSearchField::InventoryType => "inventory_type.type",
+            ItemSearchField::PurchasePrice => "purchase_price",
+            ItemSearchField::SellPrice => "sell_price",
+            ItemSearchField::IsEquippable => "is_equippable",
+            ItemSearchField::IsStackable => "is_stackable",
+            ItemSearchField::MediaId => "media.id",
+        }
+    }
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves data about all available item classes.
+    pub async fn get_item_classes(&self) -> BubbleHearthResult<ItemClassesIndex> {
+        let url = self.get_static_data_url("item-class/index");
+
+        let item_classes = self
+            .client
+            .send_request_and_deserialize::<ItemClassesIndex>(url)
+            .await?;
+
+        Ok(item_classes)
+    }
+
+    /// Retrieves an item class's metadata and subclasses based on the item class ID.
+    pub async fn get_item_class(
+        &self,
+        item_class_id: u32,
+    ) -> BubbleHearthResult<Option<ItemClass>> {
+        let url = self.get_static_data_url(&format!("item-class/{}", item_class_id));
+
+        let item_class = self
+            .client
+            .send_request_and_optionally_deserialize::<ItemClass>(url)
+            .await?;
+
+        Ok(item_class)
+    }
+
+    /// Retrieves an item subclass's metadata based on the item class and subclass IDs.
+    pub async fn get_item_subclass(
+        &self,
+        item_class_id: u32,
+        item_subclass_id: u32,
+    ) -> BubbleHearthResult<Option<ItemSubclass>> {
+        let url = self.get_static_data_url(&format!(
+            "item-class/{}/item-subclass/{}",
+            item_class_id, item_subclass_id
+        ));
+
+        let item_subclass = self
+            .client
+            .send_request_and_optionally_deserialize::<ItemSubclass>(url)
+            .await?;
+
+        Ok(item_subclass)
+    }
+
+    /// Retrieves an item's metadata and tooltip preview based on the item ID.
+    pub async fn get_item(&self, item_id: u32) -> BubbleHearthResult<Option<Item>> {
+        let url = self.get_static_data_url(&format!("item/{}", item_id));
+
+        let item = self
+            .client
+            .send_request_and_optionally_deserialize::<Item>(url)
+            .await?;
+
+        Ok(item)
+    }
+
+    /// Retrieves metadata for each of the item IDs using the client's bulk concurrency,
+    /// returning each item's result in the same order as the provided IDs.
+    pub async fn get_items_by_ids(
+        &self,
+        item_ids: &[u32],
+    ) -> Vec<BubbleHearthResult<Option<Item>>> {
+        self.client
+            .fetch_bulk(item_ids.iter().copied(), |item_id| self.get_item(item_id))
+            .await
+    }
+
+    /// Retrieves the media assets of an item, i.e. its icon, based on the item ID.
+    pub async fn get_item_media(&self, item_id: u32) -> BubbleHearthResult<Option<Media>> {
+        let url = self.get_static_data_url(&format!("media/item/{}", item_id));
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<Media>(url)
+            .await?;
+
+        Ok(media)
+    }
+
+    /// Searches for items matching the query, returning the requested page or the first page when omitted.
+    pub async fn search_items(
+        &self,
+        query: &SearchQuery<ItemSearchField>,
+        page: Option<u32>,
+    ) -> BubbleHearthResult<SearchResult<Item>> {
+        let url = query.get_search_page_url(&self.get_static_search_url("item"), page.unwrap_or(1));
+
+        let search_result = self
+            .client
+            .send_request_and_deserialize::<SearchResult<Item>>(url)
+            .await?;
+
+        Ok(search_result)
+    }
+
+    /// Constructs a paginator over all item search pages matching the query.
+    pub fn search_items_paginator(
+        &self,
+        query: &SearchQuery<ItemSearchField>,
+    ) -> SearchPaginator<'a, Item> {
+        SearchPaginator::for_query(self.client, &self.get_static_search_url("item"), query)
+    }
+}
+
+#[cfg(test)]
+mod item_tests {
+    use serde::Deserialize;
+
+    use crate::classic::items::{InventoryType, ItemBinding, ItemQuality};
+
+    #[derive(Deserialize)]
+    struct TestStruct {
+        quality: ItemQuality,
+        inventory_type: InventoryType,
+        binding: ItemBinding,
+    }
+
+    #[test]
+    fn returns_item_enums_when_match_found() {
+        // Arrange
+        let json =
+            r#"{ "quality": "LEGENDARY", "inventory_type": "TWOHWEAPON", "binding": "ON_EQUIP" }"#;
+
+        // Act
+        let parsed = serde_json::from_str::<TestStruct>(json).unwrap();
+
+        // Assert
+        assert_eq!(parsed.quality, ItemQuality::Legendary);
+        assert_eq!(parsed.inventory_type, InventoryType::TwoHandedWeapon);
+        assert_eq!(parsed.binding, ItemBinding::OnEquip);
+    }
+
+    #[test]
+    fn returns_unknown_item_enums_when_no_match_found() {
+        // Arrange
+        let json = r#"{ "quality": "MYTHIC", "inventory_type": "TAIL", "binding": "ON_SIGHT" }"#;
+
+        // Act
+        let parsed = serde_json::from_str::<TestStruct>(json).unwrap();
+
+        // Assert
+        assert_eq!(parsed.quality, ItemQuality::Unknown);
+        assert_eq!(parsed.inventory_type, InventoryType::Unknown);
+        assert_eq!(parsed.binding, ItemBinding::Unknown);
+    }
+
+    #[test]
+    fn returns_same_inventory_types_when_round_tripped() {
+        // Arrange
+        let inventory_types = [
+            InventoryType::NonEquip,
+            InventoryType::TwoHandedWeapon,
+            InventoryType::MainHandWeapon,
+            InventoryType::OffHandWeapon,
+            InventoryType::RangedRight,
+        ];
+
+        // Act
+        let round_tripped: Vec<InventoryType> = inventory_types
+            .iter()
+            .map(|inventory_type| serde_json::to_string(inventory_type).unwrap())
+            .map(|json| serde_json::from_str(&json).unwrap())
+            .collect();
+
+        // Assert
+        assert_eq!(round_tripped, inventory_types);
+    }
+}
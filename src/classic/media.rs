@@ -0,0 +1,78 @@
+//! Media search APIs for World of Warcraft Classic, finding the asset sets of items, creatures,
+//! spells, etc. by tag.
+
+use std::fmt::{Display, Formatter};
+
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::errors::BubbleHearthResult;
+use crate::media::Media;
+use crate::search::{SearchField, SearchPaginator, SearchQuery, SearchResult};
+
+/// Searchable fields of media documents.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MediaSearchField {
+    /// Numeric media ID, matching the ID of the document the media belongs to.
+    Id,
+    /// Tag of the kind of document the media belongs to, i.e. item, creature, spell, etc.
+    Tags,
+}
+
+impl SearchField for MediaSearchField {
+    fn get_field_name(&self) -> &str {
+        match self {
+            MediaSearchField::Id => "id",
+            MediaSearchField::Tags => "tags",
+        }
+    }
+}
+
+/// Tags of the kinds of documents media belongs to, used for filtering media searches.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MediaTag {
+    /// Represents item media, i.e. icons.
+    Item,
+    /// Represents creature media, i.e. creature display renders.
+    Creature,
+    /// Represents spell media, i.e. icons.
+    Spell,
+}
+
+impl Display for MediaTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let tag = match self {
+            MediaTag::Item => "item",
+            MediaTag::Creature => "creature",
+            MediaTag::Spell => "spell",
+        };
+
+        write!(f, "{}", tag)
+    }
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Searches for media matching the query, returning the requested page or the first page when omitted.
+    pub async fn search_media(
+        &self,
+        query: &SearchQuery<MediaSearchField>,
+        page: Option<u32>,
+    ) -> BubbleHearthResult<SearchResult<Media>> {
+        let url =
+            query.get_search_page_url(&self.get_static_search_url("media"), page.unwrap_or(1));
+
+        let search_result = self
+            .client
+            .send_request_and_deserialize::<SearchResult<Media>>(url)
+            .await?;
+
+        Ok(search_result)
+    }
+
+    /// Constructs a paginator over all media search pages matching the query.
+    pub fn search_media_paginator(
+        &self,
+        query: &SearchQuery<MediaSearchField>,
+    ) -> SearchPaginator<'a, Media> {
+        SearchPaginator::for_query(self.client, &self.get_static_search_url("media"), query)
+    }
+}
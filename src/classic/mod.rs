@@ -1,22 +1,105 @@
 //! Connectors for Classic WoW Game Data APIs. To date, Blizzard does not offer Community APIs
 //! for Classic Wow, though it's on their radar.
+//!
+//! Classic is split into flavors, each served from its own family of namespaces. Progression
+//! realms are served from the `classic` namespaces, while Classic Era, Hardcore, and seasonal
+//! realms are served from the `classic1x` namespaces. Connectors target a single flavor:
+//!
+//! ```rust
+//! use bubblehearth::classic::ClassicFlavor;
+//! use bubblehearth::localization::Locale;
+//! use bubblehearth::regionality::AccountRegion;
+//! use bubblehearth::testing::FakeBlizzardServer;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let server = FakeBlizzardServer::start().await;
+//!     let client = server.client(AccountRegion::US, Locale::EnglishUS);
+//!
+//!     let progression = client.classic();
+//!     let era = client.classic_era();
+//!
+//!     assert_eq!(progression.get_flavor(), ClassicFlavor::Progression);
+//!     assert_eq!(era.get_flavor(), ClassicFlavor::Era);
+//!     assert_eq!(client.classic_with_flavor(ClassicFlavor::Era).get_flavor(), ClassicFlavor::Era);
+//! }
+//! ```
 
 use crate::client::BubbleHearthClient;
-use crate::connectors::ClientConnector;
+use crate::connectors::{ClientConnector, WorldOfWarcraftUrls};
 
+pub mod auctions;
+pub mod connected_realms;
+pub mod creatures;
+#[cfg(feature = "crest")]
+pub mod crest;
+pub mod guild_crest;
+pub mod items;
+pub mod media;
+pub mod playable_classes;
+pub mod playable_races;
+pub mod power_types;
+pub mod profile;
+pub mod pvp;
 pub mod realms;
 pub mod regions;
 
+/// Flavors of World of Warcraft Classic, each served from its own family of namespaces.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ClassicFlavor {
+    /// Represents progression realms advancing through each expansion, served from the `classic` namespaces.
+    #[default]
+    Progression,
+    /// Represents Classic Era realms, including Hardcore and seasonal realms, served from the `classic1x` namespaces.
+    Era,
+}
+
+impl ClassicFlavor {
+    /// Gets the namespace segment of the flavor, i.e. `classic` in `dynamic-classic-us`.
+    pub fn get_namespace_segment(&self) -> &str {
+        match self {
+            ClassicFlavor::Progression => "classic",
+            ClassicFlavor::Era => "classic1x",
+        }
+    }
+}
+
 /// A client for WoW Classic, utilizing the base client authentication.
 #[derive(Debug)]
 pub struct WorldOfWarcraftClassicConnector<'a> {
     /// Parent client containing the HTTP client and authorization context.
     client: &'a BubbleHearthClient,
+    /// Classic flavor targeted by all requests sent through the connector.
+    flavor: ClassicFlavor,
 }
 
 impl<'a> ClientConnector<'a> for WorldOfWarcraftClassicConnector<'a> {
     /// Constructs a new WoW Classic composed of the base client for querying and authorization.
     fn new_connector(client: &'a BubbleHearthClient) -> Self {
-        Self { client }
+        Self::new_with_flavor(client, ClassicFlavor::default())
+    }
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Constructs a new WoW Classic connector targeting the flavor's namespaces.
+    pub(crate) fn new_with_flavor(client: &'a BubbleHearthClient, flavor: ClassicFlavor) -> Self {
+        Self { client, flavor }
+    }
+
+    /// Gets the Classic flavor targeted by the connector.
+    pub fn get_flavor(&self) -> ClassicFlavor {
+        self.flavor
+    }
+}
+
+impl<'a> WorldOfWarcraftUrls for WorldOfWarcraftClassicConnector<'a> {
+    /// Gets the parent client of the connector.
+    fn get_client(&self) -> &BubbleHearthClient {
+        self.client
+    }
+
+    /// Classic namespaces carry the flavor's segment, i.e. `static-classic1x-us`.
+    fn get_namespace_segment(&self) -> Option<&str> {
+        Some(self.flavor.get_namespace_segment())
     }
 }
@@ -0,0 +1,95 @@
+//! Playable class data and APIs for World of Warcraft Classic, including class media.
+
+use serde::{Deserialize, Serialize};
+
+use crate::classic::power_types::PowerType;
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::localization::StringOrStructLocale;
+use crate::media::Media;
+
+/// Response structure from the playable classes index endpoint, listing all playable classes.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct PlayableClassesIndex {
+    /// Top-level document link to follow for a selected playable class ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available playable classes.
+    pub classes: Vec<DocumentReference<PlayableClass>>,
+}
+
+/// Playable class metadata, i.e. Warrior, Mage, Druid, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayableClass {
+    /// Top-level document link to follow of the selected playable class ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric playable class ID.
+    pub id: u64,
+    /// Localized playable class name.
+    pub name: StringOrStructLocale,
+    /// Localized gendered class names.
+    pub gender_name: Option<GenderName>,
+    /// Reference to the class's primary power type.
+    pub power_type: Option<DocumentReference<PowerType>>,
+    /// Reference to the class's media.
+    pub media: Option<DocumentReference<Media>>,
+}
+
+/// Localized gendered names of a class or race. Classes return the names keyed as `male`
+/// and `female`, while races return them keyed as `male_name` and `female_name`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenderName {
+    /// Localized male name.
+    #[serde(alias = "male_name")]
+    pub male: StringOrStructLocale,
+    /// Localized female name.
+    #[serde(alias = "female_name")]
+    pub female: StringOrStructLocale,
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves data about all available playable classes.
+    pub async fn get_playable_classes(&self) -> BubbleHearthResult<PlayableClassesIndex> {
+        let url = self.get_static_data_url("playable-class/index");
+
+        let playable_classes = self
+            .client
+            .send_request_and_deserialize::<PlayableClassesIndex>(url)
+            .await?;
+
+        Ok(playable_classes)
+    }
+
+    /// Retrieves a playable class's metadata based on the playable class ID.
+    pub async fn get_playable_class(
+        &self,
+        playable_class_id: u32,
+    ) -> BubbleHearthResult<Option<PlayableClass>> {
+        let url = self.get_static_data_url(&format!("playable-class/{}", playable_class_id));
+
+        let playable_class = self
+            .client
+            .send_request_and_optionally_deserialize::<PlayableClass>(url)
+            .await?;
+
+        Ok(playable_class)
+    }
+
+    /// Retrieves the media assets of a playable class, i.e. its icon, based on the playable class ID.
+    pub async fn get_playable_class_media(
+        &self,
+        playable_class_id: u32,
+    ) -> BubbleHearthResult<Option<Media>> {
+        let url = self.get_static_data_url(&format!("media/playable-class/{}", playable_class_id));
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<Media>(url)
+            .await?;
+
+        Ok(media)
+    }
+}
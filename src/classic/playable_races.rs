@@ -0,0 +1,73 @@
+//! Playable race data and APIs for World of Warcraft Classic.
+
+use serde::{Deserialize, Serialize};
+
+use crate::classic::playable_classes::{GenderName, PlayableClass};
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::factions::FactionDetails;
+use crate::localization::StringOrStructLocale;
+
+/// Response structure from the playable races index endpoint, listing all playable races.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct PlayableRacesIndex {
+    /// Top-level document link to follow for a selected playable race ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available playable races.
+    pub races: Vec<DocumentReference<PlayableRace>>,
+}
+
+/// Playable race metadata, i.e. Human, Orc, Night Elf, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayableRace {
+    /// Top-level document link to follow of the selected playable race ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric playable race ID.
+    pub id: u64,
+    /// Localized playable race name.
+    pub name: StringOrStructLocale,
+    /// Localized gendered race names.
+    pub gender_name: Option<GenderName>,
+    /// Faction the race belongs to.
+    pub faction: Option<FactionDetails>,
+    /// Flag for indicating if the race is selectable during character creation.
+    pub is_selectable: Option<bool>,
+    /// Flag for indicating if the race is an allied race.
+    pub is_allied_race: Option<bool>,
+    /// References to the classes available to the race.
+    #[serde(default)]
+    pub playable_classes: Vec<DocumentReference<PlayableClass>>,
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves data about all available playable races.
+    pub async fn get_playable_races(&self) -> BubbleHearthResult<PlayableRacesIndex> {
+        let url = self.get_static_data_url("playable-race/index");
+
+        let playable_races = self
+            .client
+            .send_request_and_deserialize::<PlayableRacesIndex>(url)
+            .await?;
+
+        Ok(playable_races)
+    }
+
+    /// Retrieves a playable race's metadata and available classes based on the playable race ID.
+    pub async fn get_playable_race(
+        &self,
+        playable_race_id: u32,
+    ) -> BubbleHearthResult<Option<PlayableRace>> {
+        let url = self.get_static_data_url(&format!("playable-race/{}", playable_race_id));
+
+        let playable_race = self
+            .client
+            .send_request_and_optionally_deserialize::<PlayableRace>(url)
+            .await?;
+
+        Ok(playable_race)
+    }
+}
@@ -0,0 +1,60 @@
+//! Power type data and APIs for World of Warcraft Classic, i.e. mana, rage, energy, etc.
+
+use serde::{Deserialize, Serialize};
+
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::localization::StringOrStructLocale;
+
+/// Response structure from the power types index endpoint, listing all power types.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct PowerTypesIndex {
+    /// Top-level document link to follow for a selected power type ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available power types.
+    pub power_types: Vec<DocumentReference<PowerType>>,
+}
+
+/// Power type metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PowerType {
+    /// Top-level document link to follow of the selected power type ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric power type ID.
+    pub id: u64,
+    /// Localized power type name.
+    pub name: StringOrStructLocale,
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves data about all available power types.
+    pub async fn get_power_types(&self) -> BubbleHearthResult<PowerTypesIndex> {
+        let url = self.get_static_data_url("power-type/index");
+
+        let power_types = self
+            .client
+            .send_request_and_deserialize::<PowerTypesIndex>(url)
+            .await?;
+
+        Ok(power_types)
+    }
+
+    /// Retrieves a power type's metadata based on the power type ID.
+    pub async fn get_power_type(
+        &self,
+        power_type_id: u32,
+    ) -> BubbleHearthResult<Option<PowerType>> {
+        let url = self.get_static_data_url(&format!("power-type/{}", power_type_id));
+
+        let power_type = self
+            .client
+            .send_request_and_optionally_deserialize::<PowerType>(url)
+            .await?;
+
+        Ok(power_type)
+    }
+}
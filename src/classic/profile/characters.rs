@@ -0,0 +1,738 @@
+//! Character profile data and APIs for World of Warcraft Classic, including a character's
+//! equipment, appearance, hunter pets, media, PvP summary, talents, statistics, and achievements.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::classic::creatures::Creature;
+use crate::classic::guild_crest::GuildCrest;
+use crate::classic::items::{
+    InventoryTypeDetails, Item, ItemArmor, ItemBindingDetails, ItemClass, ItemDisplayValue,
+    ItemQualityDetails, ItemRequirements, ItemSellPrice, ItemSpell, ItemStat, ItemSubclass,
+    ItemWeapon,
+};
+use crate::classic::playable_classes::PlayableClass;
+use crate::classic::playable_races::PlayableRace;
+use crate::classic::power_types::PowerType;
+use crate::classic::profile::guilds::GuildReference;
+use crate::classic::profile::AchievementProgress;
+use crate::classic::realms::RealmReference;
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{get_timestamp, DocumentKey, DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::factions::FactionDetails;
+use crate::localization::StringOrStructLocale;
+use crate::media::{Media, MediaAsset};
+
+/// Profile summary of a character, including keys to the character's other profile documents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterProfile {
+    /// Top-level document link of the character profile.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric character ID.
+    pub id: u64,
+    /// Character name.
+    pub name: String,
+    /// Gender of the character.
+    pub gender: Option<GenderDetails>,
+    /// Faction of the character.
+    pub faction: Option<FactionDetails>,
+    /// Reference to the character's race.
+    pub race: Option<DocumentReference<PlayableRace>>,
+    /// Reference to the character's class.
+    pub character_class: Option<DocumentReference<PlayableClass>>,
+    /// Reference to the character's realm.
+    pub realm: Option<RealmReference>,
+    /// Reference to the character's guild, if the character belongs to one.
+    pub guild: Option<GuildReference>,
+    /// Character level.
+    pub level: Option<u32>,
+    /// Experience earned towards the next level.
+    pub experience: Option<u64>,
+    /// Total achievement points earned by the character.
+    pub achievement_points: Option<u32>,
+    /// Unix timestamp, in milliseconds, of the character's last login.
+    pub last_login_timestamp: Option<u64>,
+    /// Average item level of all of the character's items.
+    pub average_item_level: Option<u32>,
+    /// Average item level of the character's equipped items.
+    pub equipped_item_level: Option<u32>,
+    /// Flag for indicating if the character is currently a ghost.
+    pub is_ghost: Option<bool>,
+    /// Flag for indicating if the character is playing self-found on a Hardcore realm.
+    pub is_self_found: Option<bool>,
+    /// Document key of the character's equipment.
+    pub equipment: Option<DocumentKey<CharacterEquipment>>,
+    /// Document key of the character's appearance.
+    pub appearance: Option<DocumentKey<CharacterAppearance>>,
+    /// Document key of the character's media.
+    pub media: Option<DocumentKey<CharacterMedia>>,
+    /// Document key of the character's PvP summary.
+    pub pvp_summary: Option<DocumentKey<CharacterPvpSummary>>,
+    /// Document key of the character's talents.
+    pub specializations: Option<DocumentKey<CharacterSpecializations>>,
+    /// Document key of the character's statistics.
+    pub statistics: Option<DocumentKey<CharacterStatistics>>,
+    /// Document key of the character's hunter pets, if the character is a hunter.
+    pub hunter_pets: Option<DocumentKey<CharacterHunterPets>>,
+    /// Document key of the character's achievements.
+    pub achievements: Option<DocumentKey<CharacterAchievements>>,
+}
+
+impl CharacterProfile {
+    /// Gets the time of the character's last login, if available.
+    pub fn get_last_login(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.last_login_timestamp?)
+    }
+}
+
+/// Reference to a character from another profile document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterReference {
+    /// Document key of the character profile.
+    pub key: Option<DocumentKey<CharacterProfile>>,
+    /// Character name.
+    pub name: String,
+    /// Numeric character ID.
+    pub id: u64,
+    /// Reference to the character's realm.
+    pub realm: Option<RealmReference>,
+}
+
+/// Gender data, including the localized name of the gender.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenderDetails {
+    /// Typed gender.
+    #[serde(rename = "type")]
+    pub gender_type: Gender,
+    /// Localized name of the gender.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Character genders.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Gender {
+    /// Represents male characters.
+    Male,
+    /// Represents female characters.
+    Female,
+    /// Represents an unknown gender, detected when the provided gender is not able to be parsed.
+    Unknown,
+}
+
+impl From<String> for Gender {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "MALE" => Gender::Male,
+            "FEMALE" => Gender::Female,
+            _ => Gender::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Gender {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom("Invalid data type for gender.")),
+        }
+    }
+}
+
+/// Items equipped by a character.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterEquipment {
+    /// Top-level document link of the character's equipment.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the character.
+    pub character: CharacterReference,
+    /// Items equipped in each of the character's equipment slots.
+    #[serde(default)]
+    pub equipped_items: Vec<EquippedItem>,
+}
+
+/// An item equipped by a character, including its tooltip as displayed in game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquippedItem {
+    /// Reference to the equipped item.
+    pub item: DocumentReference<Item>,
+    /// Equipment slot the item is equipped in.
+    pub slot: EquipmentSlotDetails,
+    /// Number of items equipped in the slot, i.e. arrows in the ammo slot.
+    pub quantity: Option<u32>,
+    /// Localized item name.
+    pub name: Option<StringOrStructLocale>,
+    /// Quality of the item, i.e. common, rare, epic, etc.
+    pub quality: Option<ItemQualityDetails>,
+    /// Reference to the item's media.
+    pub media: Option<DocumentReference<Media>>,
+    /// Reference to the item's class.
+    pub item_class: Option<DocumentReference<ItemClass>>,
+    /// Reference to the item's subclass.
+    pub item_subclass: Option<DocumentReference<ItemSubclass>>,
+    /// Equipment slot type of the item, i.e. head, chest, two-handed weapon, etc.
+    pub inventory_type: Option<InventoryTypeDetails>,
+    /// Binding of the item, i.e. binds when picked up, binds when equipped, etc.
+    pub binding: Option<ItemBindingDetails>,
+    /// Weapon damage and speed, if the item is a weapon.
+    pub weapon: Option<ItemWeapon>,
+    /// Armor provided by the item, if any.
+    pub armor: Option<ItemArmor>,
+    /// Stats provided by the item.
+    #[serde(default)]
+    pub stats: Vec<ItemStat>,
+    /// Spells triggered by equipping or using the item.
+    #[serde(default)]
+    pub spells: Vec<ItemSpell>,
+    /// Enchantments applied to the item.
+    #[serde(default)]
+    pub enchantments: Vec<ItemEnchantment>,
+    /// Vendor sell price of the item, including its display strings.
+    pub sell_price: Option<ItemSellPrice>,
+    /// Requirements for using the item.
+    pub requirements: Option<ItemRequirements>,
+    /// Item level, including its display string.
+    pub level: Option<ItemDisplayValue<u32>>,
+    /// Current durability of the item, including its display string.
+    pub durability: Option<ItemDisplayValue<u32>>,
+}
+
+/// Equipment slot data, including the localized name of the slot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquipmentSlotDetails {
+    /// Base equipment slot, i.e. HEAD, FINGER_1, MAIN_HAND, etc.
+    #[serde(rename = "type")]
+    pub slot_type: String,
+    /// Localized name of the slot.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// An enchantment applied to an equipped item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemEnchantment {
+    /// Localized tooltip line of the enchantment, i.e. "Enchanted: +15 Agility".
+    pub display_string: Option<StringOrStructLocale>,
+    /// Reference to the item the enchantment was applied from, if any.
+    pub source_item: Option<DocumentReference<Item>>,
+    /// Numeric enchantment ID.
+    pub enchantment_id: Option<u64>,
+    /// Slot of the item the enchantment was applied to.
+    pub enchantment_slot: Option<ItemEnchantmentSlot>,
+}
+
+/// Slot of an item an enchantment was applied to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemEnchantmentSlot {
+    /// Numeric enchantment slot ID.
+    pub id: u32,
+    /// Base enchantment slot, i.e. PERMANENT, TEMPORARY, etc.
+    #[serde(rename = "type")]
+    pub slot_type: String,
+}
+
+/// Appearance of a character, including its race, class, and guild crest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterAppearance {
+    /// Top-level document link of the character's appearance.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the character.
+    pub character: CharacterReference,
+    /// Reference to the character's race.
+    pub playable_race: Option<DocumentReference<PlayableRace>>,
+    /// Reference to the character's class.
+    pub playable_class: Option<DocumentReference<PlayableClass>>,
+    /// Gender of the character.
+    pub gender: Option<GenderDetails>,
+    /// Faction of the character.
+    pub faction: Option<FactionDetails>,
+    /// Crest of the character's guild, if the character belongs to one.
+    pub guild_crest: Option<GuildCrest>,
+    /// Customizations selected for the character, i.e. skin color, face, hair style, etc.
+    #[serde(default)]
+    pub customizations: Vec<CharacterCustomization>,
+}
+
+/// A customization selected for a character.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterCustomization {
+    /// Reference to the customization option, i.e. skin color.
+    pub option: DocumentReference,
+    /// Reference to the selected choice for the option.
+    pub choice: DocumentReference,
+}
+
+/// Hunter pets tamed by a character.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterHunterPets {
+    /// Top-level document link of the character's hunter pets.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the character.
+    pub character: CharacterReference,
+    /// Pets tamed by the character, whether stabled or active.
+    #[serde(default)]
+    pub hunter_pets: Vec<HunterPet>,
+}
+
+/// A hunter pet tamed by a character.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HunterPet {
+    /// Name given to the pet.
+    pub name: String,
+    /// Pet level.
+    pub level: u32,
+    /// Reference to the tamed creature.
+    pub creature: Option<DocumentReference<Creature>>,
+    /// Stable slot of the pet.
+    pub slot: Option<u32>,
+    /// Flag for indicating if the pet is the character's active pet.
+    #[serde(default)]
+    pub is_active: bool,
+    /// Flag for indicating if the pet is currently summoned.
+    #[serde(default)]
+    pub is_summoned: bool,
+    /// Reference to the pet's creature display media.
+    pub creature_display: Option<DocumentReference<Media>>,
+}
+
+/// Media assets of a character, i.e. avatar, inset, and full body renders.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterMedia {
+    /// Top-level document link of the character's media.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the character.
+    pub character: CharacterReference,
+    /// Render assets of the character.
+    #[serde(default)]
+    pub assets: Vec<MediaAsset>,
+}
+
+/// PvP summary of a character, including honorable kills and battleground statistics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterPvpSummary {
+    /// Top-level document link of the character's PvP summary.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the character.
+    pub character: CharacterReference,
+    /// Lifetime honorable kills of the character.
+    pub honorable_kills: Option<u64>,
+    /// Honor level of the character.
+    pub honor_level: Option<u32>,
+    /// Match statistics for each battleground the character has played.
+    #[serde(default)]
+    pub pvp_map_statistics: Vec<PvpMapStatistics>,
+}
+
+/// Match statistics of a character for a battleground.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PvpMapStatistics {
+    /// Reference to the battleground map.
+    pub world_map: DocumentReference,
+    /// Matches played, won, and lost on the map.
+    pub match_statistics: PvpMatchStatistics,
+}
+
+/// Matches played, won, and lost.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PvpMatchStatistics {
+    /// Number of matches played.
+    pub played: u32,
+    /// Number of matches won.
+    pub won: u32,
+    /// Number of matches lost.
+    pub lost: u32,
+}
+
+/// Talents of a character, grouped by talent specialization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterSpecializations {
+    /// Top-level document link of the character's talents.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the character.
+    pub character: CharacterReference,
+    /// Talent specializations of the character, i.e. primary and secondary specs with dual talents.
+    #[serde(default)]
+    pub specialization_groups: Vec<CharacterSpecializationGroup>,
+}
+
+impl CharacterSpecializations {
+    /// Gets the character's active talent specialization, if any.
+    pub fn get_active_group(&self) -> Option<&CharacterSpecializationGroup> {
+        self.specialization_groups
+            .iter()
+            .find(|group| group.is_active)
+    }
+}
+
+/// A talent specialization of a character, spread across each of the class's talent trees.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterSpecializationGroup {
+    /// Flag for indicating if the specialization is the character's active specialization.
+    #[serde(default)]
+    pub is_active: bool,
+    /// Talent trees of the specialization.
+    #[serde(default)]
+    pub specializations: Vec<CharacterTalentTree>,
+}
+
+/// Talents learned within a talent tree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterTalentTree {
+    /// Localized name of the talent tree, i.e. "Beast Mastery".
+    pub specialization_name: Option<StringOrStructLocale>,
+    /// Number of talent points spent within the tree.
+    pub spent_points: u32,
+    /// Talents learned within the tree.
+    #[serde(default)]
+    pub talents: Vec<CharacterTalent>,
+}
+
+/// A talent learned by a character.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterTalent {
+    /// Reference to the talent.
+    pub talent: DocumentReference,
+    /// Tooltip of the talent's spell at its learned rank.
+    pub spell_tooltip: Option<SpellTooltip>,
+    /// Number of ranks learned of the talent.
+    pub talent_rank: Option<u32>,
+}
+
+/// Tooltip of a spell, as displayed in game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpellTooltip {
+    /// Reference to the spell.
+    pub spell: Option<DocumentReference>,
+    /// Localized description of the spell.
+    pub description: Option<StringOrStructLocale>,
+    /// Localized cast time of the spell, i.e. "Instant".
+    pub cast_time: Option<StringOrStructLocale>,
+    /// Localized power cost of the spell, i.e. "45 Mana".
+    pub power_cost: Option<StringOrStructLocale>,
+    /// Localized range of the spell, i.e. "30 yd range".
+    pub range: Option<StringOrStructLocale>,
+    /// Localized cooldown of the spell, i.e. "5 min cooldown".
+    pub cooldown: Option<StringOrStructLocale>,
+}
+
+/// Statistics of a character, i.e. health, attributes, resistances, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterStatistics {
+    /// Top-level document link of the character's statistics.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the character.
+    pub character: CharacterReference,
+    /// Maximum health of the character.
+    pub health: Option<u64>,
+    /// Maximum power of the character, i.e. mana, rage, energy, etc.
+    pub power: Option<u64>,
+    /// Reference to the character's power type.
+    pub power_type: Option<DocumentReference<PowerType>>,
+    /// Strength of the character.
+    pub strength: Option<CharacterStat>,
+    /// Agility of the character.
+    pub agility: Option<CharacterStat>,
+    /// Intellect of the character.
+    pub intellect: Option<CharacterStat>,
+    /// Stamina of the character.
+    pub stamina: Option<CharacterStat>,
+    /// Spirit of the character.
+    pub spirit: Option<CharacterStat>,
+    /// Armor of the character.
+    pub armor: Option<CharacterStat>,
+    /// Defense skill of the character.
+    pub defense: Option<CharacterStat>,
+    /// Melee critical strike chance of the character.
+    pub melee_crit: Option<CharacterRating>,
+    /// Ranged critical strike chance of the character.
+    pub ranged_crit: Option<CharacterRating>,
+    /// Spell critical strike chance of the character.
+    pub spell_crit: Option<CharacterRating>,
+    /// Dodge chance of the character.
+    pub dodge: Option<CharacterRating>,
+    /// Parry chance of the character.
+    pub parry: Option<CharacterRating>,
+    /// Block chance of the character.
+    pub block: Option<CharacterRating>,
+    /// Attack power of the character.
+    pub attack_power: Option<u64>,
+    /// Minimum damage of the character's main hand weapon.
+    pub main_hand_damage_min: Option<f64>,
+    /// Maximum damage of the character's main hand weapon.
+    pub main_hand_damage_max: Option<f64>,
+    /// Attack speed, in seconds, of the character's main hand weapon.
+    pub main_hand_speed: Option<f64>,
+    /// Damage per second of the character's main hand weapon.
+    pub main_hand_dps: Option<f64>,
+    /// Minimum damage of the character's off hand weapon.
+    pub off_hand_damage_min: Option<f64>,
+    /// Maximum damage of the character's off hand weapon.
+    pub off_hand_damage_max: Option<f64>,
+    /// Attack speed, in seconds, of the character's off hand weapon.
+    pub off_hand_speed: Option<f64>,
+    /// Damage per second of the character's off hand weapon.
+    pub off_hand_dps: Option<f64>,
+    /// Bonus spell power of the character.
+    pub spell_power: Option<u64>,
+    /// Spell penetration of the character.
+    pub spell_penetration: Option<f64>,
+    /// Mana regeneration of the character while out of combat.
+    pub mana_regen: Option<f64>,
+    /// Mana regeneration of the character while in combat.
+    pub mana_regen_combat: Option<f64>,
+    /// Arcane resistance of the character.
+    pub arcane_resistance: Option<CharacterStat>,
+    /// Fire resistance of the character.
+    pub fire_resistance: Option<CharacterStat>,
+    /// Frost resistance of the character.
+    pub frost_resistance: Option<CharacterStat>,
+    /// Holy resistance of the character.
+    pub holy_resistance: Option<CharacterStat>,
+    /// Nature resistance of the character.
+    pub nature_resistance: Option<CharacterStat>,
+    /// Shadow resistance of the character.
+    pub shadow_resistance: Option<CharacterStat>,
+}
+
+/// A character statistic, before and after bonuses from gear, buffs, and talents.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CharacterStat {
+    /// Base value of the statistic.
+    pub base: i64,
+    /// Effective value of the statistic, including all bonuses.
+    pub effective: i64,
+}
+
+/// A character statistic derived from ratings, i.e. critical strike or dodge chance.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterRating {
+    /// Bonus provided by ratings from gear.
+    pub rating_bonus: Option<f64>,
+    /// Effective value of the statistic, as a percentage.
+    pub value: f64,
+}
+
+/// Achievements earned by a character, along with their progress.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterAchievements {
+    /// Top-level document link of the character's achievements.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Total number of achievements earned by the character.
+    pub total_quantity: Option<u32>,
+    /// Total achievement points earned by the character.
+    pub total_points: Option<u32>,
+    /// Achievements earned or in progress by the character.
+    #[serde(default)]
+    pub achievements: Vec<AchievementProgress>,
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Builds a character profile URL, with the character name lowercased as required by Blizzard.
+    fn get_character_url(&self, realm_slug: &str, character_name: &str, document: &str) -> String {
+        let path = format!(
+            "character/{}/{}{}",
+            realm_slug,
+            character_name.to_lowercase(),
+            document
+        );
+
+        self.get_profile_url(&path)
+    }
+
+    /// Retrieves a character's profile summary based on the realm slug and character name.
+    pub async fn get_character_profile(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterProfile>> {
+        let url = self.get_character_url(realm_slug, character_name, "");
+
+        let character = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterProfile>(url)
+            .await?;
+
+        Ok(character)
+    }
+
+    /// Retrieves the items equipped by a character based on the realm slug and character name.
+    pub async fn get_character_equipment(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterEquipment>> {
+        let url = self.get_character_url(realm_slug, character_name, "/equipment");
+
+        let equipment = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterEquipment>(url)
+            .await?;
+
+        Ok(equipment)
+    }
+
+    /// Retrieves a character's appearance based on the realm slug and character name.
+    pub async fn get_character_appearance(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterAppearance>> {
+        let url = self.get_character_url(realm_slug, character_name, "/appearance");
+
+        let appearance = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterAppearance>(url)
+            .await?;
+
+        Ok(appearance)
+    }
+
+    /// Retrieves the hunter pets tamed by a character based on the realm slug and character name.
+    /// Characters that are not hunters are returned as `None`.
+    pub async fn get_character_hunter_pets(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterHunterPets>> {
+        let url = self.get_character_url(realm_slug, character_name, "/hunter-pets");
+
+        let hunter_pets = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterHunterPets>(url)
+            .await?;
+
+        Ok(hunter_pets)
+    }
+
+    /// Retrieves a character's render assets based on the realm slug and character name.
+    pub async fn get_character_media(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterMedia>> {
+        let url = self.get_character_url(realm_slug, character_name, "/character-media");
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterMedia>(url)
+            .await?;
+
+        Ok(media)
+    }
+
+    /// Retrieves a character's PvP summary based on the realm slug and character name.
+    pub async fn get_character_pvp_summary(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterPvpSummary>> {
+        let url = self.get_character_url(realm_slug, character_name, "/pvp-summary");
+
+        let pvp_summary = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterPvpSummary>(url)
+            .await?;
+
+        Ok(pvp_summary)
+    }
+
+    /// Retrieves a character's talents based on the realm slug and character name.
+    pub async fn get_character_specializations(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterSpecializations>> {
+        let url = self.get_character_url(realm_slug, character_name, "/specializations");
+
+        let specializations = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterSpecializations>(url)
+            .await?;
+
+        Ok(specializations)
+    }
+
+    /// Retrieves a character's statistics based on the realm slug and character name.
+    pub async fn get_character_statistics(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterStatistics>> {
+        let url = self.get_character_url(realm_slug, character_name, "/statistics");
+
+        let statistics = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterStatistics>(url)
+            .await?;
+
+        Ok(statistics)
+    }
+
+    /// Retrieves a character's achievements based on the realm slug and character name.
+    pub async fn get_character_achievements(
+        &self,
+        realm_slug: &str,
+        character_name: &str,
+    ) -> BubbleHearthResult<Option<CharacterAchievements>> {
+        let url = self.get_character_url(realm_slug, character_name, "/achievements");
+
+        let achievements = self
+            .client
+            .send_request_and_optionally_deserialize::<CharacterAchievements>(url)
+            .await?;
+
+        Ok(achievements)
+    }
+}
+
+#[cfg(test)]
+mod character_tests {
+    use serde::Deserialize;
+
+    use crate::classic::profile::characters::Gender;
+
+    #[derive(Deserialize)]
+    struct TestStruct {
+        gender: Gender,
+    }
+
+    #[test]
+    fn returns_unknown_when_gender_unrecognized() {
+        // Arrange
+        let json = r#"{ "gender": "NOT_A_GENDER" }"#;
+
+        // Act
+        let result = serde_json::from_str::<TestStruct>(json).unwrap();
+
+        // Assert
+        assert_eq!(result.gender, Gender::Unknown);
+    }
+
+    #[test]
+    fn returns_error_when_gender_not_a_string() {
+        // Arrange
+        let json = r#"{ "gender": 1 }"#;
+
+        // Act
+        let result = serde_json::from_str::<TestStruct>(json);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Guild profile data and APIs for World of Warcraft Classic, including a guild's roster and achievements.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::classic::guild_crest::GuildCrest;
+use crate::classic::playable_classes::PlayableClass;
+use crate::classic::playable_races::PlayableRace;
+use crate::classic::profile::characters::CharacterProfile;
+use crate::classic::profile::{slugify, AchievementProgress};
+use crate::classic::realms::RealmReference;
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{get_timestamp, DocumentKey, DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::factions::FactionDetails;
+
+/// Profile summary of a guild, including keys to the guild's roster and achievements.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Guild {
+    /// Top-level document link of the guild profile.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric guild ID.
+    pub id: u64,
+    /// Guild name.
+    pub name: String,
+    /// Faction of the guild.
+    pub faction: Option<FactionDetails>,
+    /// Total achievement points earned by the guild.
+    pub achievement_points: Option<u32>,
+    /// Number of characters in the guild.
+    pub member_count: Option<u32>,
+    /// Reference to the guild's realm.
+    pub realm: Option<RealmReference>,
+    /// Crest selected by the guild.
+    pub crest: Option<GuildCrest>,
+    /// Unix timestamp, in milliseconds, of when the guild was created.
+    pub created_timestamp: Option<u64>,
+    /// Document key of the guild's roster.
+    pub roster: Option<DocumentKey<GuildRoster>>,
+    /// Document key of the guild's achievements.
+    pub achievements: Option<DocumentKey<GuildAchievements>>,
+}
+
+impl Guild {
+    /// Gets the time the guild was created, if available.
+    pub fn get_created(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.created_timestamp?)
+    }
+}
+
+/// Reference to a guild from another profile document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildReference {
+    /// Document key of the guild profile.
+    pub key: Option<DocumentKey<Guild>>,
+    /// Guild name.
+    pub name: String,
+    /// Numeric guild ID.
+    pub id: u64,
+    /// Reference to the guild's realm.
+    pub realm: Option<RealmReference>,
+    /// Faction of the guild.
+    pub faction: Option<FactionDetails>,
+}
+
+/// Roster of a guild, listing each member along with their guild rank.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildRoster {
+    /// Top-level document link of the guild's roster.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the guild.
+    pub guild: GuildReference,
+    /// Characters in the guild.
+    #[serde(default)]
+    pub members: Vec<GuildMember>,
+}
+
+impl GuildRoster {
+    /// Gets the guild master, holding the guild's highest rank of zero.
+    pub fn get_guild_master(&self) -> Option<&GuildMember> {
+        self.members.iter().find(|member| member.rank == 0)
+    }
+
+    /// Gets all members holding the guild rank, where zero is the guild master's rank.
+    pub fn get_members_by_rank(&self, rank: u32) -> Vec<&GuildMember> {
+        self.members
+            .iter()
+            .filter(|member| member.rank == rank)
+            .collect()
+    }
+}
+
+/// A character in a guild, along with their guild rank.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildMember {
+    /// The member's character.
+    pub character: GuildMemberCharacter,
+    /// Guild rank of the member, where zero is the guild master's rank.
+    pub rank: u32,
+}
+
+/// Character data of a guild member.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildMemberCharacter {
+    /// Document key of the character profile.
+    pub key: Option<DocumentKey<CharacterProfile>>,
+    /// Character name.
+    pub name: String,
+    /// Numeric character ID.
+    pub id: u64,
+    /// Reference to the character's realm.
+    pub realm: Option<RealmReference>,
+    /// Character level.
+    pub level: Option<u32>,
+    /// Reference to the character's class.
+    pub playable_class: Option<DocumentReference<PlayableClass>>,
+    /// Reference to the character's race.
+    pub playable_race: Option<DocumentReference<PlayableRace>>,
+}
+
+/// Achievements earned by a guild, along with their progress.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildAchievements {
+    /// Top-level document link of the guild's achievements.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the guild.
+    pub guild: GuildReference,
+    /// Total number of achievements earned by the guild.
+    pub total_quantity: Option<u32>,
+    /// Total achievement points earned by the guild.
+    pub total_points: Option<u32>,
+    /// Achievements earned or in progress by the guild.
+    #[serde(default)]
+    pub achievements: Vec<AchievementProgress>,
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Builds a guild profile URL, slugifying the guild name as required by Blizzard.
+    fn get_guild_url(&self, realm_slug: &str, guild_name: &str, document: &str) -> String {
+        let path = format!("guild/{}/{}{}", realm_slug, slugify(guild_name), document);
+
+        self.get_profile_data_url(&path)
+    }
+
+    /// Retrieves a guild's profile summary based on the realm slug and guild name. Guild names
+    /// are slugified, so both `Bob's Raiding Team` and `bobs-raiding-team` are accepted.
+    pub async fn get_guild(
+        &self,
+        realm_slug: &str,
+        guild_name: &str,
+    ) -> BubbleHearthResult<Option<Guild>> {
+        let url = self.get_guild_url(realm_slug, guild_name, "");
+
+        let guild = self
+            .client
+            .send_request_and_optionally_deserialize::<Guild>(url)
+            .await?;
+
+        Ok(guild)
+    }
+
+    /// Retrieves a guild's roster based on the realm slug and guild name.
+    pub async fn get_guild_roster(
+        &self,
+        realm_slug: &str,
+        guild_name: &str,
+    ) -> BubbleHearthResult<Option<GuildRoster>> {
+        let url = self.get_guild_url(realm_slug, guild_name, "/roster");
+
+        let roster = self
+            .client
+            .send_request_and_optionally_deserialize::<GuildRoster>(url)
+            .await?;
+
+        Ok(roster)
+    }
+
+    /// Retrieves a guild's achievements based on the realm slug and guild name.
+    pub async fn get_guild_achievements(
+        &self,
+        realm_slug: &str,
+        guild_name: &str,
+    ) -> BubbleHearthResult<Option<GuildAchievements>> {
+        let url = self.get_guild_url(realm_slug, guild_name, "/achievements");
+
+        let achievements = self
+            .client
+            .send_request_and_optionally_deserialize::<GuildAchievements>(url)
+            .await?;
+
+        Ok(achievements)
+    }
+}
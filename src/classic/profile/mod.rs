@@ -0,0 +1,100 @@
+//! Profile APIs for World of Warcraft Classic, served from the `profile-classic-{region}` namespace
+//! or the `profile-classic1x-{region}` namespace for Classic Era realms. Profiles are addressed by
+//! realm slug and name, with unknown or inactive profiles returned as `None`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::documents::DocumentReference;
+
+pub mod characters;
+pub mod guilds;
+
+/// An achievement earned or in progress by a character or guild.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementProgress {
+    /// Numeric achievement ID.
+    pub id: u64,
+    /// Reference to the achievement.
+    pub achievement: DocumentReference,
+    /// Progress of the achievement's criteria.
+    pub criteria: Option<AchievementCriteriaProgress>,
+    /// Unix timestamp, in milliseconds, of when the achievement was completed, if completed.
+    pub completed_timestamp: Option<u64>,
+}
+
+impl AchievementProgress {
+    /// Determines if the achievement has been completed.
+    pub fn is_completed(&self) -> bool {
+        self.completed_timestamp.is_some()
+            || self
+                .criteria
+                .as_ref()
+                .is_some_and(|criteria| criteria.is_completed)
+    }
+}
+
+/// Progress of an achievement's criteria.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementCriteriaProgress {
+    /// Numeric criteria ID.
+    pub id: u64,
+    /// Flag for indicating if the criteria has been completed.
+    #[serde(default)]
+    pub is_completed: bool,
+    /// Progress towards the criteria, i.e. number of creatures killed.
+    pub amount: Option<f64>,
+    /// Progress of each criteria the criteria is composed of.
+    #[serde(default)]
+    pub child_criteria: Vec<AchievementCriteriaProgress>,
+}
+
+/// Converts a guild or character name into the slug used to address its profile, i.e.
+/// `Bob's Raiding Team` becomes `bobs-raiding-team`. Names are lowercased, apostrophes are
+/// removed, and each run of whitespace is replaced with a single hyphen.
+///
+/// ```rust
+/// use bubblehearth::classic::profile::slugify;
+///
+/// assert_eq!(slugify("Bob's  Raiding Team"), "bobs-raiding-team");
+/// ```
+pub fn slugify(name: &str) -> String {
+    name.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| !matches!(c, '\'' | '’'))
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod profile_tests {
+    use crate::classic::profile::slugify;
+
+    #[test]
+    fn returns_slug_when_name_has_spaces_and_apostrophes() {
+        // Arrange
+        let name = "  Bob’s Raiding  Team ' ";
+
+        // Act
+        let slug = slugify(name);
+
+        // Assert
+        assert_eq!(slug, "bobs-raiding-team");
+    }
+
+    #[test]
+    fn returns_lowercase_slug_when_name_has_accented_characters() {
+        // Arrange
+        let name = "Ÿüñgëst Ëlves";
+
+        // Act
+        let slug = slugify(name);
+
+        // Assert
+        assert_eq!(slug, "ÿüñgëst-ëlves");
+    }
+}
@@ -0,0 +1,398 @@
+//! PvP season, region, and leaderboard data and APIs for World of Warcraft Classic, covering
+//! arena ladders across each season and PvP region.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::classic::profile::characters::{CharacterReference, PvpMatchStatistics};
+use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{get_timestamp, DocumentKey, DocumentReference, Links};
+use crate::errors::{BubbleHearthError, BubbleHearthResult};
+use crate::factions::FactionDetails;
+use crate::localization::StringOrStructLocale;
+
+/// Response structure from the PvP season index endpoints, listing all seasons along with the current season.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct PvpSeasonsIndex {
+    /// Top-level document link for a selected season ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// List of available PvP seasons.
+    #[serde(default)]
+    pub seasons: Vec<DocumentReference<PvpSeason>>,
+    /// Reference to the current PvP season.
+    pub current_season: Option<DocumentReference<PvpSeason>>,
+}
+
+/// PvP season metadata, including a key to the season's leaderboards.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PvpSeason {
+    /// Top-level document link of the selected season ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric PvP season ID.
+    pub id: u32,
+    /// Localized name of the season, if named.
+    pub season_name: Option<StringOrStructLocale>,
+    /// Document key of the season's leaderboards.
+    pub leaderboards: Option<DocumentKey<PvpLeaderboardsIndex>>,
+    /// Document key of the season's rewards.
+    pub rewards: Option<DocumentKey>,
+    /// Unix timestamp, in milliseconds, of when the season started.
+    pub season_start_timestamp: Option<u64>,
+    /// Unix timestamp, in milliseconds, of when the season ended, if the season has ended.
+    pub season_end_timestamp: Option<u64>,
+}
+
+impl PvpSeason {
+    /// Gets the time the season started, if available.
+    pub fn get_season_start(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.season_start_timestamp?)
+    }
+
+    /// Gets the time the season ended, if the season has ended.
+    pub fn get_season_end(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.season_end_timestamp?)
+    }
+}
+
+/// Response structure from the PvP region index endpoint, listing all PvP regions.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct PvpRegionsIndex {
+    /// Top-level document link for a selected PvP region ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// List of available PvP regions, keyed to each region's season index.
+    #[serde(default)]
+    pub pvp_regions: Vec<DocumentReference<PvpSeasonsIndex>>,
+}
+
+/// Response structure from the PvP leaderboard index endpoints, listing all leaderboards for a season.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct PvpLeaderboardsIndex {
+    /// Top-level document link for a selected leaderboard.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the season of the leaderboards.
+    pub season: Option<DocumentReference<PvpSeason>>,
+    /// List of available leaderboards.
+    #[serde(default)]
+    pub leaderboards: Vec<PvpLeaderboardReference>,
+}
+
+/// Reference to a PvP leaderboard of a season.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PvpLeaderboardReference {
+    /// Document key of the leaderboard.
+    pub key: Option<DocumentKey<PvpLeaderboard>>,
+    /// Name of the leaderboard, i.e. 3v3.
+    pub name: Option<String>,
+    /// Numeric leaderboard ID.
+    pub id: Option<u32>,
+}
+
+/// Ranked entries of a PvP leaderboard for a bracket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PvpLeaderboard {
+    /// Top-level document link of the leaderboard.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Reference to the season of the leaderboard.
+    pub season: Option<DocumentReference<PvpSeason>>,
+    /// Name of the leaderboard, i.e. 3v3.
+    pub name: Option<String>,
+    /// Bracket of the leaderboard.
+    pub bracket: Option<PvpBracketDetails>,
+    /// Ranked entries of the leaderboard, ordered by rank.
+    #[serde(default)]
+    pub entries: Vec<PvpLeaderboardEntry>,
+}
+
+/// A ranked character on a PvP leaderboard.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PvpLeaderboardEntry {
+    /// Reference to the ranked character.
+    pub character: CharacterReference,
+    /// Faction of the ranked character.
+    pub faction: Option<FactionDetails>,
+    /// Rank of the character on the leaderboard.
+    pub rank: u32,
+    /// Rating of the character.
+    pub rating: u32,
+    /// Matches played, won, and lost by the character during the season.
+    pub season_match_statistics: Option<PvpMatchStatistics>,
+    /// Reference to the reward tier achieved by the character, if any.
+    pub tier: Option<DocumentReference>,
+}
+
+/// Bracket data of a leaderboard.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PvpBracketDetails {
+    /// Numeric bracket ID.
+    pub id: u32,
+    /// Typed bracket.
+    #[serde(rename = "type")]
+    pub bracket_type: PvpBracket,
+}
+
+/// PvP brackets with ranked leaderboards.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq, Hash)]
+pub enum PvpBracket {
+    /// Represents 2v2 arena.
+    #[serde(rename = "ARENA_2v2")]
+    Arena2v2,
+    /// Represents 3v3 arena.
+    #[serde(rename = "ARENA_3v3")]
+    Arena3v3,
+    /// Represents 5v5 arena.
+    #[serde(rename = "ARENA_5v5")]
+    Arena5v5,
+    /// Represents rated battlegrounds.
+    #[serde(rename = "BATTLEGROUNDS")]
+    RatedBattlegrounds,
+    /// Represents an unknown bracket, detected when the provided bracket is not able to be parsed.
+    #[serde(rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl PvpBracket {
+    /// Gets the bracket's path segment used to address its leaderboards, i.e. `3v3`.
+    /// Unknown brackets have no leaderboards to address.
+    pub fn get_bracket_slug(&self) -> Option<&str> {
+        match self {
+            PvpBracket::Arena2v2 => Some("2v2"),
+            PvpBracket::Arena3v3 => Some("3v3"),
+            PvpBracket::Arena5v5 => Some("5v5"),
+            PvpBracket::RatedBattlegrounds => Some("rbg"),
+            PvpBracket::Unknown => None,
+        }
+    }
+}
+
+impl From<String> for PvpBracket {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ARENA_2v2" => PvpBracket::Arena2v2,
+            "ARENA_3v3" => PvpBracket::Arena3v3,
+            "ARENA_5v5" => PvpBracket::Arena5v5,
+            "BATTLEGROUNDS" => PvpBracket::RatedBattlegrounds,
+            _ => PvpBracket::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PvpBracket {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom(
+                "Invalid data type for PvP bracket.",
+            )),
+        }
+    }
+}
+
+impl<'a> WorldOfWarcraftClassicConnector<'a> {
+    /// Retrieves all PvP seasons, along with the current season.
+    pub async fn get_pvp_seasons(&self) -> BubbleHearthResult<PvpSeasonsIndex> {
+        let url = self.get_dynamic_data_url("pvp-season/index");
+
+        let seasons = self
+            .client
+            .send_request_and_deserialize::<PvpSeasonsIndex>(url)
+            .await?;
+
+        Ok(seasons)
+    }
+
+    /// Retrieves a PvP season's metadata based on the season ID.
+    pub async fn get_pvp_season(
+        &self,
+        pvp_season_id: u32,
+    ) -> BubbleHearthResult<Option<PvpSeason>> {
+        let url = self.get_dynamic_data_url(&format!("pvp-season/{}", pvp_season_id));
+
+        let season = self
+            .client
+            .send_request_and_optionally_deserialize::<PvpSeason>(url)
+            .await?;
+
+        Ok(season)
+    }
+
+    /// Retrieves the leaderboards of a PvP season based on the season ID.
+    pub async fn get_pvp_leaderboards(
+        &self,
+        pvp_season_id: u32,
+    ) -> BubbleHearthResult<Option<PvpLeaderboardsIndex>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "pvp-season/{}/pvp-leaderboard/index",
+            pvp_season_id
+        ));
+
+        let leaderboards = self
+            .client
+            .send_request_and_optionally_deserialize::<PvpLeaderboardsIndex>(url)
+            .await?;
+
+        Ok(leaderboards)
+    }
+
+    /// Retrieves a PvP season's leaderboard for the bracket. Unknown brackets return an error.
+    pub async fn get_pvp_leaderboard(
+        &self,
+        pvp_season_id: u32,
+        bracket: PvpBracket,
+    ) -> BubbleHearthResult<Option<PvpLeaderboard>> {
+        let bracket_slug = bracket
+            .get_bracket_slug()
+            .ok_or(BubbleHearthError::PvpBracketUnknown)?;
+
+        let url = self.get_dynamic_data_url(&format!(
+            "pvp-season/{}/pvp-leaderboard/{}",
+            pvp_season_id, bracket_slug
+        ));
+
+        let leaderboard = self
+            .client
+            .send_request_and_optionally_deserialize::<PvpLeaderboard>(url)
+            .await?;
+
+        Ok(leaderboard)
+    }
+
+    /// Retrieves all PvP regions.
+    pub async fn get_pvp_regions(&self) -> BubbleHearthResult<PvpRegionsIndex> {
+        let url = self.get_dynamic_data_url("pvp-region/index");
+
+        let regions = self
+            .client
+            .send_request_and_deserialize::<PvpRegionsIndex>(url)
+            .await?;
+
+        Ok(regions)
+    }
+
+    /// Retrieves all PvP seasons of a PvP region, along with the region's current season.
+    pub async fn get_pvp_region_seasons(
+        &self,
+        pvp_region_id: u32,
+    ) -> BubbleHearthResult<Option<PvpSeasonsIndex>> {
+        let url =
+            self.get_dynamic_data_url(&format!("pvp-region/{}/pvp-season/index", pvp_region_id));
+
+        let seasons = self
+            .client
+            .send_request_and_optionally_deserialize::<PvpSeasonsIndex>(url)
+            .await?;
+
+        Ok(seasons)
+    }
+
+    /// Retrieves a PvP region's season metadata based on the region and season IDs.
+    pub async fn get_pvp_region_season(
+        &self,
+        pvp_region_id: u32,
+        pvp_season_id: u32,
+    ) -> BubbleHearthResult<Option<PvpSeason>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "pvp-region/{}/pvp-season/{}",
+            pvp_region_id, pvp_season_id
+        ));
+
+        let season = self
+            .client
+            .send_request_and_optionally_deserialize::<PvpSeason>(url)
+            .await?;
+
+        Ok(season)
+    }
+
+    /// Retrieves the leaderboards of a PvP region's season based on the region and season IDs.
+    pub async fn get_pvp_region_leaderboards(
+        &self,
+        pvp_region_id: u32,
+        pvp_season_id: u32,
+    ) -> BubbleHearthResult<Option<PvpLeaderboardsIndex>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "pvp-region/{}/pvp-season/{}/pvp-leaderboard/index",
+            pvp_region_id, pvp_season_id
+        ));
+
+        let leaderboards = self
+            .client
+            .send_request_and_optionally_deserialize::<PvpLeaderboardsIndex>(url)
+            .await?;
+
+        Ok(leaderboards)
+    }
+
+    /// Retrieves a PvP region's season leaderboard for the bracket. Unknown brackets return an error.
+    pub async fn get_pvp_region_leaderboard(
+        &self,
+        pvp_region_id: u32,
+        pvp_season_id: u32,
+        bracket: PvpBracket,
+    ) -> BubbleHearthResult<Option<PvpLeaderboard>> {
+        let bracket_slug = bracket
+            .get_bracket_slug()
+            .ok_or(BubbleHearthError::PvpBracketUnknown)?;
+
+        let url = self.get_dynamic_data_url(&format!(
+            "pvp-region/{}/pvp-season/{}/pvp-leaderboard/{}",
+            pvp_region_id, pvp_season_id, bracket_slug
+        ));
+
+        let leaderboard = self
+            .client
+            .send_request_and_optionally_deserialize::<PvpLeaderboard>(url)
+            .await?;
+
+        Ok(leaderboard)
+    }
+}
+
+#[cfg(test)]
+mod pvp_tests {
+    use serde::Deserialize;
+
+    use crate::classic::pvp::PvpBracket;
+
+    #[derive(Deserialize)]
+    struct TestStruct {
+        bracket: PvpBracket,
+    }
+
+    #[test]
+    fn returns_bracket_when_match_found() {
+        // Arrange
+        let json = r#"{ "bracket": "ARENA_3v3" }"#;
+
+        // Act
+        let result = serde_json::from_str::<TestStruct>(json).unwrap();
+
+        // Assert
+        assert_eq!(result.bracket, PvpBracket::Arena3v3);
+        assert_eq!(result.bracket.get_bracket_slug(), Some("3v3"));
+    }
+
+    #[test]
+    fn returns_unknown_without_slug_when_bracket_unrecognized() {
+        // Arrange
+        let json = r#"{ "bracket": "SHUFFLE" }"#;
+
+        // Act
+        let result = serde_json::from_str::<TestStruct>(json).unwrap();
+
+        // Assert
+        assert_eq!(result.bracket, PvpBracket::Unknown);
+        assert_eq!(result.bracket.get_bracket_slug(), None);
+    }
+}
@@ -2,11 +2,14 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::classic::connected_realms::ConnectedRealm;
+use crate::classic::regions::Region;
+use crate::classic::{ClassicFlavor, WorldOfWarcraftClassicConnector};
+use crate::connectors::WorldOfWarcraftUrls;
 use crate::documents::{DocumentKey, Links};
 use crate::errors::BubbleHearthResult;
 use crate::localization::StringOrStructLocale;
-use crate::search::SearchResult;
+use crate::search::{SearchField, SearchPaginator, SearchQuery, SearchResult};
 use crate::timezone::Timezone;
 
 /// Response structure from the realms index endpoint, listing all available realms
@@ -27,7 +30,7 @@ pub struct Realm {
     #[serde(rename = "_links")]
     pub links: Option<Links>,
     /// Document key for the realm, defaults to the URL.
-    pub key: Option<DocumentKey>,
+    pub key: Option<DocumentKey<Realm>>,
     /// Localized realm name.
     pub name: StringOrStructLocale,
     /// Numeric Realm ID.
@@ -44,16 +47,35 @@ pub struct Realm {
     pub is_tournament: Option<bool>,
     /// Realm region, including document links.
     pub region: Option<RealmRegion>,
+    /// Document key of the connected realm the realm belongs to.
+    pub connected_realm: Option<DocumentKey<ConnectedRealm>>,
     /// Realm type metadata.
     #[serde(rename = "type")]
     pub realm_type: Option<RealmType>,
+    /// Classic flavor of the namespace the realm was retrieved from, tagged by the connector
+    /// as Blizzard does not include it in realm data.
+    #[serde(skip)]
+    pub flavor: Option<ClassicFlavor>,
+}
+
+/// Reference to a realm from profile data, including the realm slug used to address profiles.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealmReference {
+    /// Document key of the realm.
+    pub key: Option<DocumentKey<Realm>>,
+    /// Localized realm name.
+    pub name: Option<StringOrStructLocale>,
+    /// Numeric realm ID.
+    pub id: u64,
+    /// Slugified realm name.
+    pub slug: String,
 }
 
 /// Realm region data, including document links and name.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RealmRegion {
     /// Document key of the realm region.
-    pub key: Option<DocumentKey>,
+    pub key: Option<DocumentKey<Region>>,
     /// Name of the realm region.
     pub name: StringOrStructLocale,
     /// ID of the realm region.
@@ -70,67 +92,127 @@ pub struct RealmType {
     pub name: StringOrStructLocale,
 }
 
+/// Searchable fields of realm documents. Localized fields, i.e. the name, category, and region name,
+/// should be filtered on with a locale.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RealmSearchField {
+    /// Numeric realm ID.
+    Id,
+    /// Localized realm name.
+    Name,
+    /// Slugified realm name.
+    Slug,
+    /// Localized realm category, i.e. US West, US East, Oceanic, etc.
+    Category,
+    /// Realm locale.
+    Locale,
+    /// Realm timezone name, i.e. America/New_York, America/Los_Angeles, etc.
+    Timezone,
+    /// Flag for indicating if the realm is a PVP-based tournament realm.
+    IsTournament,
+    /// ID of the realm region.
+    RegionId,
+    /// Localized name of the realm region.
+    RegionName,
+    /// Base realm type, i.e. NORMAL, RP, etc.
+    RealmType,
+}
+
+impl SearchField for RealmSearchField {
+    fn get_field_name(&self) -> &str {
+        match self {
+            RealmSearchField::Id => "id",
+            RealmSearchField::Name => "name",
+            RealmSearchField::Slug => "slug",
+            RealmSearchField::Category => "category",
+            RealmSearchField::Locale => "locale",
+            RealmSearchField::Timezone => "timezone",
+            RealmSearchField::IsTournament => "is_tournament",
+            RealmSearchField::RegionId => "region.id",
+            RealmSearchField::RegionName => "region.name",
+            RealmSearchField::RealmType => "type.type",
+        }
+    }
+}
+
 impl<'a> WorldOfWarcraftClassicConnector<'a> {
     /// Retrieves data about all available realms.
     pub async fn get_realms(&self) -> BubbleHearthResult<RealmsIndex> {
-        let url = format!(
-            "https://{}.api.blizzard.com/data/wow/realm/index?locale={}",
-            self.client.region.get_region_abbreviation(),
-            self.client.locale.get_normalized_locale(),
-        );
+        let url = self.get_dynamic_data_url("realm/index");
 
-        let realms = self
+        let mut realms = self
             .client
             .send_request_and_deserialize::<RealmsIndex>(url)
             .await?;
 
+        self.tag_realms(&mut realms.realms);
+
         Ok(realms)
     }
 
     /// Retrieves a realm's metadata based on the realm slug.
     pub async fn get_realm(&self, slug: &str) -> BubbleHearthResult<Option<Realm>> {
-        let url = format!(
-            "https://{}.api.blizzard.com/data/wow/realm/{}?locale={}",
-            self.client.region.get_region_abbreviation(),
-            slug,
-            self.client.locale.get_normalized_locale()
-        );
+        let url = self.get_dynamic_data_url(&format!("realm/{}", slug));
 
         let realm = self
             .client
             .send_request_and_optionally_deserialize::<Realm>(url)
-            .await?;
+            .await?
+            .map(|realm| Realm {
+                flavor: Some(self.flavor),
+                ..realm
+            });
 
         Ok(realm)
     }
 
-    /// Searches for realms with optional timezone, order by, and page query parameters.
+    /// Retrieves metadata for each of the realm slugs using the client's bulk concurrency,
+    /// returning each realm's result in the same order as the provided slugs.
+    pub async fn get_realms_by_slugs(
+        &self,
+        slugs: &[&str],
+    ) -> Vec<BubbleHearthResult<Option<Realm>>> {
+        self.client
+            .fetch_bulk(slugs.iter().copied(), |slug| self.get_realm(slug))
+            .await
+    }
+
+    /// Searches for realms matching the query, returning the requested page or the first page when omitted.
     pub async fn search_realms(
         &self,
-        timezone: Option<Timezone>,
-        order_by: Option<String>,
+        query: &SearchQuery<RealmSearchField>,
         page: Option<u32>,
     ) -> BubbleHearthResult<SearchResult<Realm>> {
-        let mut url = format!(
-            "https://{}.api.blizzard.com/data/wow/search/realm?_page={}",
-            self.client.region.get_region_abbreviation(),
-            page.unwrap_or(1)
-        );
-
-        if let Some(zone) = timezone {
-            let zone_string: String = zone.into();
-            url.push_str(&format!("&timezone={}", zone_string));
-        }
-
-        if let Some(order) = order_by {
-            url.push_str(&format!("&orderby={}", order));
-        }
+        let url =
+            query.get_search_page_url(&self.get_dynamic_search_url("realm"), page.unwrap_or(1));
 
-        let search_result = self
+        let mut search_result = self
             .client
             .send_request_and_deserialize::<SearchResult<Realm>>(url)
             .await?;
 
+        for item in search_result.results.iter_mut() {
+            item.data.flavor = Some(self.flavor);
+        }
+
         Ok(search_result)
     }
+
+    /// Constructs a paginator over all realm search pages matching the query.
+    pub fn search_realms_paginator(
+        &self,
+        query: &SearchQuery<RealmSearchField>,
+    ) -> SearchPaginator<'a, Realm> {
+        let flavor = self.flavor;
+
+        SearchPaginator::for_query(self.client, &self.get_dynamic_search_url("realm"), query)
+            .with_item_mapper(move |realm: &mut Realm| realm.flavor = Some(flavor))
+    }
+
+    /// Tags each realm with the connector's flavor, as realm data does not indicate its namespace.
+    pub(crate) fn tag_realms(&self, realms: &mut [Realm]) {
+        for realm in realms.iter_mut() {
+            realm.flavor = Some(self.flavor);
+        }
+    }
 }
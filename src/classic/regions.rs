@@ -3,6 +3,7 @@
 use serde::Deserialize;
 
 use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::connectors::WorldOfWarcraftUrls;
 use crate::documents::Links;
 use crate::errors::BubbleHearthResult;
 
@@ -33,11 +34,7 @@ pub struct Region {
 impl<'a> WorldOfWarcraftClassicConnector<'a> {
     /// Retrieves data about all available regions.
     pub async fn get_regions(&self) -> BubbleHearthResult<RegionsIndex> {
-        let url = format!(
-            "https://{}.api.blizzard.com/data/wow/region/index?locale={}",
-            self.client.region.get_region_abbreviation(),
-            self.client.locale.get_normalized_locale(),
-        );
+        let url = self.get_dynamic_data_url("region/index");
 
         let regions = self
             .client
@@ -49,11 +46,7 @@ impl<'a> WorldOfWarcraftClassicConnector<'a> {
 
     /// Retrieves data about all available regions.
     pub async fn get_region(&self, region_id: u32) -> BubbleHearthResult<Option<Region>> {
-        let url = format!(
-            "https://{}.api.blizzard.com/data/wow/region/{region_id}?locale={}",
-            self.client.region.get_region_abbreviation(),
-            self.client.locale.get_normalized_locale(),
-        );
+        let url = self.get_dynamic_data_url(&format!("region/{}", region_id));
 
         let region = self
             .client
@@ -62,4 +55,17 @@ impl<'a> WorldOfWarcraftClassicConnector<'a> {
 
         Ok(region)
     }
+
+    /// Retrieves data for each of the region IDs using the client's bulk concurrency,
+    /// returning each region's result in the same order as the provided IDs.
+    pub async fn get_regions_by_ids(
+        &self,
+        region_ids: &[u32],
+    ) -> Vec<BubbleHearthResult<Option<Region>>> {
+        self.client
+            .fetch_bulk(region_ids.iter().copied(), |region_id| {
+                self.get_region(region_id)
+            })
+            .await
+    }
 }
@@ -1,22 +1,37 @@
 //! A top-level client client for interacting with Blizzard Game Data APIs,
 //! including authentication and all publicly available APIs for Blizzard games.
 
+use std::future::Future;
 use std::ops::Add;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use http::{HeaderMap, StatusCode};
+#[cfg(any(feature = "classic", feature = "wow"))]
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use time::OffsetDateTime;
 
 use crate::auth::AccessTokenResponse;
 use crate::builder::BubbleHearthClientOptions;
-use crate::classic::WorldOfWarcraftClassicConnector;
+use crate::bulk::{fetch_with_concurrency, DEFAULT_BULK_CONCURRENCY};
+use crate::cassette::{BodyEncoding, CassettePlayer, RecordedRequest};
+#[cfg(feature = "classic")]
+use crate::classic::{ClassicFlavor, WorldOfWarcraftClassicConnector};
+#[cfg(any(feature = "classic", feature = "hearthstone", feature = "wow"))]
 use crate::connectors::ClientConnector;
+use crate::documents::DocumentKey;
 use crate::errors::{BubbleHearthError, BubbleHearthResult};
+#[cfg(feature = "hearthstone")]
 use crate::hearthstone::HearthstoneConnector;
+use crate::interceptors::Interceptor;
 use crate::localization::Locale;
+use crate::references::ResourceReference;
 use crate::regionality::AccountRegion;
+#[cfg(any(feature = "classic", feature = "wow"))]
+use crate::streaming::DocumentStream;
+#[cfg(feature = "wow")]
+use crate::wow::WorldOfWarcraftConnector;
 
 /// Default the reqwest HTTP timeout to 5 seconds, overridable if provided.
 const DEFAULT_TIMEOUT_SECONDS: u8 = 5;
@@ -51,6 +66,10 @@ const DEFAULT_TIMEOUT_SECONDS: u8 = 5;
 pub struct BubbleHearthClient {
     /// Reference to the internal client, configured for timeout and other defaults.
     http: reqwest::Client,
+    /// Internal HTTP client for streamed responses, only limiting the time spent connecting as
+    /// large streamed bodies, i.e. auction snapshots, can take longer than the timeout to receive.
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    stream_http: reqwest::Client,
     /// Configured account region.
     pub(crate) region: AccountRegion,
     /// Configured locale for all API calls.
@@ -63,6 +82,18 @@ pub struct BubbleHearthClient {
     access_token: Mutex<Option<String>>,
     /// Expiration of the access token, typically on the order of 24 hours.
     expires_at: Mutex<OffsetDateTime>,
+    /// Held while refreshing the access token, ensuring concurrent requests share a single refresh.
+    token_refresh: futures::lock::Mutex<()>,
+    /// Optional API gateway override, defaulting to the regional Blizzard gateway.
+    base_url: Option<String>,
+    /// Optional OAuth token endpoint override, defaulting to the regional token endpoint.
+    token_url: Option<String>,
+    /// Optional cassette for recording or replaying interactions.
+    cassette: Option<CassettePlayer>,
+    /// Registered interceptors, invoked for every request and response in order of registration.
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    /// Number of in-flight requests allowed for bulk fetches.
+    pub(crate) bulk_concurrency: usize,
 }
 
 impl BubbleHearthClient {
@@ -92,12 +123,20 @@ impl BubbleHearthClient {
 
         Self {
             http: client,
+            #[cfg(any(feature = "classic", feature = "wow"))]
+            stream_http: get_stream_client(timeout),
             client_id,
             client_secret,
             region,
             locale,
             access_token: Mutex::new(None),
             expires_at: Mutex::new(OffsetDateTime::UNIX_EPOCH),
+            token_refresh: futures::lock::Mutex::new(()),
+            base_url: None,
+            token_url: None,
+            cassette: None,
+            interceptors: Vec::new(),
+            bulk_concurrency: DEFAULT_BULK_CONCURRENCY,
         }
     }
 
@@ -107,33 +146,49 @@ impl BubbleHearthClient {
             return Err(BubbleHearthError::InvalidClientOptions);
         }
 
-        let client = match options.http {
-            // If we're not given any reqwest configurations,
-            // check to see which type of client should be built
-            None => match options.timeout {
-                // If no timeout was given, construct one with the default timeout
-                None => reqwest::ClientBuilder::new()
-                    .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECONDS.into()))
-                    .build()
-                    .unwrap(),
-                // If we have a configured timeout, use that instead
-                Some(timeout) => reqwest::ClientBuilder::new()
-                    .timeout(timeout)
-                    .build()
-                    .unwrap(),
-            },
+        // If no timeout was given, fall back to the default timeout
+        let timeout = options
+            .timeout
+            .unwrap_or_else(|| Duration::from_secs(DEFAULT_TIMEOUT_SECONDS.into()));
+
+        let client = match &options.http {
+            // If we're not given any reqwest configurations, build one with the timeout
+            None => reqwest::ClientBuilder::new()
+                .timeout(timeout)
+                .build()
+                .unwrap(),
             // If we're given a preconfigured HTTP client, use that over all other configurations
+            Some(http) => http.clone(),
+        };
+
+        // Preconfigured HTTP clients are trusted to be configured for streamed responses as well
+        #[cfg(any(feature = "classic", feature = "wow"))]
+        let stream_client = match options.http {
+            None => get_stream_client(timeout),
             Some(http) => http,
         };
 
+        let cassette = match options.cassette {
+            None => None,
+            Some(mode) => Some(CassettePlayer::new(mode)?),
+        };
+
         Ok(Self {
             http: client,
+            #[cfg(any(feature = "classic", feature = "wow"))]
+            stream_http: stream_client,
             client_id: options.client_id.unwrap(),
             client_secret: options.client_secret.unwrap(),
             region: options.region.unwrap(),
             locale: options.locale.unwrap(),
             access_token: Mutex::new(None),
             expires_at: Mutex::new(OffsetDateTime::UNIX_EPOCH),
+            token_refresh: futures::lock::Mutex::new(()),
+            base_url: options.base_url,
+            token_url: options.token_url,
+            cassette,
+            interceptors: options.interceptors,
+            bulk_concurrency: options.bulk_concurrency.unwrap_or(DEFAULT_BULK_CONCURRENCY),
         })
     }
 
@@ -177,16 +232,24 @@ impl BubbleHearthClient {
             return Ok(cached_token);
         }
 
+        // Only a single refresh is sent at a time, with requests waiting on the refresh reusing its token
+        let _refresh_guard = self.token_refresh.lock().await;
+
+        if let Ok(Some(cached_token)) = self.try_access_token() {
+            return Ok(cached_token);
+        }
+
         let form = reqwest::multipart::Form::new().text("grant_type", "client_credentials");
-        let token_response = self
+        let request = self
             .http
-            .post(self.region.get_token_endpoint())
+            .post(self.get_token_url())
             .multipart(form)
             .basic_auth(&self.client_id, Some(&self.client_secret))
-            .send()
-            .await?
-            .json::<AccessTokenResponse>()
-            .await?;
+            .build()?;
+        let request = self.intercept_request(request)?;
+        let response = self.http.execute(request).await?;
+        self.intercept_response(&response)?;
+        let token_response = response.json::<AccessTokenResponse>().await?;
         let access_token = token_response.access_token;
 
         if let Ok(mut token_lock) = self.access_token.try_lock() {
@@ -201,30 +264,144 @@ impl BubbleHearthClient {
         Ok(access_token)
     }
 
+    /// Gets the base URL of the API gateway, favoring any configured override over the regional gateway.
+    pub(crate) fn get_base_url(&self) -> String {
+        match &self.base_url {
+            Some(base_url) => base_url.trim_end_matches('/').to_string(),
+            None => format!("https://{}", self.region.get_api_host()),
+        }
+    }
+
+    /// Gets the OAuth token endpoint, favoring any configured override over the regional endpoint.
+    fn get_token_url(&self) -> &str {
+        match &self.token_url {
+            Some(token_url) => token_url,
+            None => self.region.get_token_endpoint(),
+        }
+    }
+
     /// Gets the region-specific namespace based on the region localilty.
     fn get_namespace_locality(&self) -> String {
         format!("dynamic-classic-{}", self.region.get_region_abbreviation())
     }
 
-    /// Sends a request with the required namespace and authentication token.
-    async fn send_request(&self, url: String) -> BubbleHearthResult<reqwest::Response> {
-        let token = self.get_access_token().await?;
+    /// Runs the outgoing request through all registered interceptors.
+    fn intercept_request(
+        &self,
+        mut request: reqwest::Request,
+    ) -> BubbleHearthResult<reqwest::Request> {
+        for interceptor in &self.interceptors {
+            interceptor.intercept_request(&mut request)?;
+        }
+
+        Ok(request)
+    }
+
+    /// Runs the response through all registered interceptors before deserialization.
+    fn intercept_response(&self, response: &reqwest::Response) -> BubbleHearthResult<()> {
+        for interceptor in &self.interceptors {
+            interceptor.intercept_response(response)?;
+        }
+
+        Ok(())
+    }
+
+    /// Builds a request with the required namespace and authentication token. Namespaces provided
+    /// through the URL's query string take precedence and are mirrored into the namespace header,
+    /// otherwise the client's namespace is sent.
+    async fn build_request(&self, url: String) -> BubbleHearthResult<reqwest::Request> {
         let mut headers = HeaderMap::new();
-        headers.append(
-            "Battlenet-Namespace",
-            self.get_namespace_locality().parse().unwrap(),
-        );
+        let namespace = reqwest::Url::parse(&url)
+            .ok()
+            .and_then(|parsed| {
+                parsed
+                    .query_pairs()
+                    .find(|(key, _)| key == "namespace")
+                    .map(|(_, value)| value.into_owned())
+            })
+            .unwrap_or_else(|| self.get_namespace_locality());
+
+        headers.append("Battlenet-Namespace", namespace.parse().unwrap());
+
+        let mut request = self.http.get(url).headers(headers);
+
+        // Replayed requests never leave the client, so there's no need to authenticate
+        if !self.cassette.as_ref().is_some_and(|c| c.is_replaying()) {
+            let token = self.get_access_token().await?;
+            request = request.bearer_auth(token);
+        }
+
+        self.intercept_request(request.build()?)
+    }
+
+    /// Sends a request with the required namespace and authentication token. When configured
+    /// with a cassette, responses are either recorded or replayed.
+    async fn send_request(&self, url: String) -> BubbleHearthResult<reqwest::Response> {
+        let request = self.build_request(url).await?;
         let response = self
-            .http
-            .get(url)
-            .headers(headers)
-            .bearer_auth(token)
-            .send()
+            .execute(&self.http, request, BodyEncoding::Text)
             .await?;
 
+        self.intercept_response(&response)?;
+
         Ok(response)
     }
 
+    /// Sends a request with the required namespace and authentication token through the streaming
+    /// HTTP client, leaving the response body to be received for as long as it takes. When
+    /// recording to a cassette, the body is buffered in full into the recorded interaction.
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    async fn send_streamed_request(&self, url: String) -> BubbleHearthResult<reqwest::Response> {
+        let request = self.build_request(url).await?;
+        let response = self
+            .execute(&self.stream_http, request, BodyEncoding::Text)
+            .await?;
+
+        self.intercept_response(&response)?;
+
+        Ok(response)
+    }
+
+    /// Executes the request with the given HTTP client, recording the response with the given
+    /// body encoding or replaying a recorded response when configured with a cassette.
+    async fn execute(
+        &self,
+        http: &reqwest::Client,
+        request: reqwest::Request,
+        encoding: BodyEncoding,
+    ) -> BubbleHearthResult<reqwest::Response> {
+        let Some(cassette) = &self.cassette else {
+            return Ok(http.execute(request).await?);
+        };
+
+        let recorded_request =
+            RecordedRequest::new(request.method(), request.url().as_str(), request.headers());
+
+        if cassette.is_replaying() {
+            cassette.replay(&recorded_request)
+        } else {
+            let response = http.execute(request).await?;
+            cassette.record(recorded_request, response, encoding).await
+        }
+    }
+
+    /// Downloads a static asset, i.e. a media image from Blizzard's render CDN. Assets are public,
+    /// so no namespace or authentication is sent, though requests still run through the interceptors.
+    /// When configured with a cassette, asset bodies are recorded as base64.
+    #[cfg(feature = "crest")]
+    pub(crate) async fn download_asset(&self, url: String) -> BubbleHearthResult<Vec<u8>> {
+        let request = self.intercept_request(self.http.get(url).build()?)?;
+        let response = self
+            .execute(&self.http, request, BodyEncoding::Base64)
+            .await?;
+
+        self.intercept_response(&response)?;
+
+        let bytes = response.error_for_status()?.bytes().await?;
+
+        Ok(bytes.to_vec())
+    }
+
     /// Sends a request with the required namespace and authentication token and deserializes the response.
     pub(crate) async fn send_request_and_deserialize<T: for<'de> Deserialize<'de>>(
         &self,
@@ -250,13 +427,163 @@ impl BubbleHearthClient {
         Ok(Some(response))
     }
 
+    /// Sends a request with the required namespace and authentication token, streaming the items
+    /// of the top-level array field of the response as they're deserialized.
+    #[cfg(feature = "wow")]
+    pub(crate) async fn send_request_and_stream<T: DeserializeOwned + Send + 'static>(
+        &self,
+        url: String,
+        field: &'static str,
+    ) -> BubbleHearthResult<DocumentStream<T>> {
+        let response = self.send_streamed_request(url).await?.error_for_status()?;
+        Ok(DocumentStream::from_response(response, field))
+    }
+
+    /// Sends a request with the required namespace and authentication token, streaming the items
+    /// of the top-level array field of the response as they're deserialized.
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    pub(crate) async fn send_request_and_optionally_stream<T: DeserializeOwned + Send + 'static>(
+        &self,
+        url: String,
+        field: &'static str,
+    ) -> BubbleHearthResult<Option<DocumentStream<T>>> {
+        let response = self.send_streamed_request(url).await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let response = response.error_for_status()?;
+
+        Ok(Some(DocumentStream::from_response(response, field)))
+    }
+
+    /// Resolves a document key into the document it points to, adding authentication and
+    /// the client's locale. Any namespace already present in the key's URL is respected,
+    /// and any configured base URL override replaces the key's host. Without an override,
+    /// keys pointing anywhere other than a Blizzard API gateway are rejected.
+    ///
+    /// ```rust
+    /// use bubblehearth::localization::Locale;
+    /// use bubblehearth::regionality::AccountRegion;
+    /// use bubblehearth::testing::FakeBlizzardServer;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let server = FakeBlizzardServer::start().await;
+    ///     let client = server.client(AccountRegion::US, Locale::EnglishUS);
+    ///     let realm = client.classic().get_realm("atiesh").await.unwrap().unwrap();
+    ///
+    ///     // Keys are typed by the document they point to, a realm's region resolving to a region
+    ///     let region_key = realm.region.unwrap().key.unwrap();
+    ///     let region = client.resolve(&region_key).await.unwrap();
+    ///
+    ///     assert_eq!(region.unwrap().tag, Some("US".to_string()));
+    /// }
+    /// ```
+    pub async fn resolve<T: for<'de> Deserialize<'de>>(
+        &self,
+        key: &DocumentKey<T>,
+    ) -> BubbleHearthResult<Option<T>> {
+        let url = self.get_document_url(&key.href)?;
+        self.send_request_and_optionally_deserialize::<T>(url).await
+    }
+
+    /// Builds the URL for a document href, rewriting the host for any configured
+    /// base URL override and adding the client's locale when not already present.
+    /// Without an override, hrefs must point to a Blizzard API gateway over HTTPS,
+    /// as the access token is sent along with the request.
+    fn get_document_url(&self, href: &str) -> BubbleHearthResult<String> {
+        let mut url = reqwest::Url::parse(href)
+            .map_err(|_| BubbleHearthError::InvalidDocumentHref(href.to_string()))?;
+
+        if self.base_url.is_none() {
+            // References only parse from hrefs with a Blizzard API gateway host
+            href.parse::<ResourceReference>()?;
+
+            if url.scheme() != "https" {
+                return Err(BubbleHearthError::InsecureDocumentHref(href.to_string()));
+            }
+        } else {
+            let base_url = reqwest::Url::parse(&self.get_base_url())
+                .map_err(|_| BubbleHearthError::InvalidDocumentHref(href.to_string()))?;
+            let path = format!("{}{}", base_url.path().trim_end_matches('/'), url.path());
+            let query = url.query().map(str::to_string);
+            url = base_url;
+            url.set_path(&path);
+            url.set_query(query.as_deref());
+        }
+
+        if !url.query_pairs().any(|(key, _)| key == "locale") {
+            url.query_pairs_mut()
+                .append_pair("locale", self.locale.get_normalized_locale());
+        }
+
+        Ok(url.to_string())
+    }
+
+    /// Runs the fetch for each item with the configured bulk concurrency, returning each
+    /// item's output in input order. Useful for composing bulk fetches over any connector API.
+    pub async fn fetch_bulk<I, F, Fut>(&self, items: I, fetch: F) -> Vec<Fut::Output>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Fut,
+        Fut: Future,
+    {
+        fetch_with_concurrency(items, self.bulk_concurrency, fetch).await
+    }
+
+    /// Writes the interactions recorded so far to the cassette file, doing nothing without a recording
+    /// cassette. Recorded interactions are also saved when the client is dropped, though errors are
+    /// only reported when saving explicitly.
+    pub fn save_cassette(&self) -> BubbleHearthResult<()> {
+        match &self.cassette {
+            Some(cassette) => cassette.save(),
+            None => Ok(()),
+        }
+    }
+
     /// A client connector for interacting with World of Warcraft Classic Game Data APIs.
-    pub fn classic(&self) -> WorldOfWarcraftClassicConnector {
+    #[cfg(feature = "classic")]
+    pub fn classic(&self) -> WorldOfWarcraftClassicConnector<'_> {
         WorldOfWarcraftClassicConnector::new_connector(self)
     }
 
+    /// A client connector for interacting with World of Warcraft Classic Era Game Data APIs,
+    /// including Hardcore and seasonal realms.
+    #[cfg(feature = "classic")]
+    pub fn classic_era(&self) -> WorldOfWarcraftClassicConnector<'_> {
+        self.classic_with_flavor(ClassicFlavor::Era)
+    }
+
+    /// A client connector for interacting with World of Warcraft Classic Game Data APIs of the given flavor.
+    #[cfg(feature = "classic")]
+    pub fn classic_with_flavor(
+        &self,
+        flavor: ClassicFlavor,
+    ) -> WorldOfWarcraftClassicConnector<'_> {
+        WorldOfWarcraftClassicConnector::new_with_flavor(self, flavor)
+    }
+
     /// A client connector for interacting with Hearthstone Game Data APIs.
-    pub fn hearthstone(&self) -> HearthstoneConnector {
+    #[cfg(feature = "hearthstone")]
+    pub fn hearthstone(&self) -> HearthstoneConnector<'_> {
         HearthstoneConnector::new_connector(self)
     }
+
+    /// A client connector for interacting with World of Warcraft Game Data APIs.
+    #[cfg(feature = "wow")]
+    pub fn wow(&self) -> WorldOfWarcraftConnector<'_> {
+        WorldOfWarcraftConnector::new_connector(self)
+    }
+}
+
+/// Builds the HTTP client for streamed responses, limiting the time spent connecting to the timeout
+/// without limiting the time spent receiving the body.
+#[cfg(any(feature = "classic", feature = "wow"))]
+fn get_stream_client(timeout: Duration) -> reqwest::Client {
+    reqwest::ClientBuilder::new()
+        .connect_timeout(timeout)
+        .build()
+        .unwrap()
 }
@@ -0,0 +1,159 @@
+//! Connected realm data shared between World of Warcraft Classic and retail connected realms.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::localization::StringOrStructLocale;
+
+/// Status data of a connected realm, including the localized name of the status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectedRealmStatus {
+    /// Typed realm status.
+    #[serde(rename = "type")]
+    pub status_type: RealmStatus,
+    /// Localized name of the status.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Population data of a connected realm, including the localized name of the population.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectedRealmPopulation {
+    /// Typed realm population.
+    #[serde(rename = "type")]
+    pub population_type: RealmPopulation,
+    /// Localized name of the population.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Realm statuses reported for connected realms.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RealmStatus {
+    /// Represents realms available for login.
+    Up,
+    /// Represents realms unavailable for login, i.e. during maintenance.
+    Down,
+    /// Represents an unknown status, detected when the provided status is not able to be parsed.
+    Unknown,
+}
+
+/// Realm populations reported for connected realms.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RealmPopulation {
+    /// Represents realms with a low population.
+    Low,
+    /// Represents realms with a medium population.
+    Medium,
+    /// Represents realms with a high population.
+    High,
+    /// Represents realms at their population limit.
+    Full,
+    /// Represents realms with character creation locked.
+    Locked,
+    /// Represents newly opened realms.
+    New,
+    /// Represents realms recommended for new characters.
+    Recommended,
+    /// Represents realms reserved for new players.
+    NewPlayers,
+    /// Represents an unknown population, detected when the provided population is not able to be parsed.
+    Unknown,
+}
+
+impl From<String> for RealmStatus {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "UP" => RealmStatus::Up,
+            "DOWN" => RealmStatus::Down,
+            _ => RealmStatus::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RealmStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom(
+                "Invalid data type for realm status.",
+            )),
+        }
+    }
+}
+
+impl From<String> for RealmPopulation {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "LOW" => RealmPopulation::Low,
+            "MEDIUM" => RealmPopulation::Medium,
+            "HIGH" => RealmPopulation::High,
+            "FULL" => RealmPopulation::Full,
+            "LOCKED" => RealmPopulation::Locked,
+            "NEW" => RealmPopulation::New,
+            "RECOMMENDED" => RealmPopulation::Recommended,
+            "NEW_PLAYERS" => RealmPopulation::NewPlayers,
+            _ => RealmPopulation::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RealmPopulation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom(
+                "Invalid data type for realm population.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod connected_realm_tests {
+    use serde::Deserialize;
+
+    use crate::connected_realms::{RealmPopulation, RealmStatus};
+
+    #[derive(Deserialize)]
+    struct TestStruct {
+        status: RealmStatus,
+        population: RealmPopulation,
+    }
+
+    #[test]
+    fn returns_status_and_population_when_match_found() {
+        // Arrange
+        let json = r#"{ "status": "DOWN", "population": "NEW_PLAYERS" }"#;
+
+        // Act
+        let parsed = serde_json::from_str::<TestStruct>(json).unwrap();
+
+        // Assert
+        assert_eq!(parsed.status, RealmStatus::Down);
+        assert_eq!(parsed.population, RealmPopulation::NewPlayers);
+    }
+
+    #[test]
+    fn returns_unknown_status_and_population_when_no_match_found() {
+        // Arrange
+        let json = r#"{ "status": "SIDEWAYS", "population": "CROWDED" }"#;
+
+        // Act
+        let parsed = serde_json::from_str::<TestStruct>(json).unwrap();
+
+        // Assert
+        assert_eq!(parsed.status, RealmStatus::Unknown);
+        assert_eq!(parsed.population, RealmPopulation::Unknown);
+    }
+}
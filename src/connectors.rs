@@ -9,3 +9,94 @@ pub trait ClientConnector<'a> {
     where
         Self: Sized;
 }
+
+/// URL building shared by the World of Warcraft connectors, which only differ by the
+/// segment their namespaces carry between the namespace kind and region.
+#[cfg(any(feature = "classic", feature = "wow"))]
+pub(crate) trait WorldOfWarcraftUrls {
+    /// Gets the parent client containing the base URL and locale.
+    fn get_client(&self) -> &BubbleHearthClient;
+
+    /// Gets the namespace segment following the kind, i.e. `classic1x` for `static-classic1x-us`,
+    /// or none for namespaces without one, i.e. `static-us`.
+    fn get_namespace_segment(&self) -> Option<&str>;
+
+    /// Gets the region-specific namespace of the given kind, i.e. `static-us` or `static-classic1x-us`.
+    fn get_namespace(&self, kind: &str) -> String {
+        let region = self.get_client().region.get_region_abbreviation();
+
+        match self.get_namespace_segment() {
+            Some(segment) => format!("{}-{}-{}", kind, segment, region),
+            None => format!("{}-{}", kind, region),
+        }
+    }
+
+    /// Builds a Game Data URL for the path within the namespace, including the client's locale.
+    fn get_data_url(&self, namespace: &str, path: &str) -> String {
+        let client = self.get_client();
+
+        format!(
+            "{}/data/wow/{}?namespace={}&locale={}",
+            client.get_base_url(),
+            path,
+            namespace,
+            client.locale.get_normalized_locale()
+        )
+    }
+
+    /// Builds a Game Data search endpoint URL for the document type within the namespace,
+    /// excluding any search query parameters.
+    fn get_search_url(&self, namespace: &str, document_type: &str) -> String {
+        format!(
+            "{}/data/wow/search/{}?namespace={}",
+            self.get_client().get_base_url(),
+            document_type,
+            namespace
+        )
+    }
+
+    /// Builds a Game Data URL for the path within the dynamic namespace, used for game data
+    /// that changes outside of patches, i.e. realms, auctions, etc.
+    fn get_dynamic_data_url(&self, path: &str) -> String {
+        self.get_data_url(&self.get_namespace("dynamic"), path)
+    }
+
+    /// Builds a Game Data search endpoint URL for the document type within the dynamic namespace.
+    #[cfg(feature = "classic")]
+    fn get_dynamic_search_url(&self, document_type: &str) -> String {
+        self.get_search_url(&self.get_namespace("dynamic"), document_type)
+    }
+
+    /// Builds a Profile API URL for the path within the profile namespace, used for character
+    /// and guild data, including the client's locale.
+    #[cfg(feature = "classic")]
+    fn get_profile_url(&self, path: &str) -> String {
+        let client = self.get_client();
+
+        format!(
+            "{}/profile/wow/{}?namespace={}&locale={}",
+            client.get_base_url(),
+            path,
+            self.get_namespace("profile"),
+            client.locale.get_normalized_locale()
+        )
+    }
+
+    /// Builds a Game Data URL for the path within the profile namespace, used for profile data
+    /// served under the Game Data paths, i.e. guilds.
+    #[cfg(feature = "classic")]
+    fn get_profile_data_url(&self, path: &str) -> String {
+        self.get_data_url(&self.get_namespace("profile"), path)
+    }
+
+    /// Builds a Game Data URL for the path within the static namespace, used for game data
+    /// that only changes with patches, i.e. creatures, items, etc.
+    fn get_static_data_url(&self, path: &str) -> String {
+        self.get_data_url(&self.get_namespace("static"), path)
+    }
+
+    /// Builds a Game Data search endpoint URL for the document type within the static namespace.
+    fn get_static_search_url(&self, document_type: &str) -> String {
+        self.get_search_url(&self.get_namespace("static"), document_type)
+    }
+}
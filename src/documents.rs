@@ -1,12 +1,77 @@
 //! General data types for document derived data from the Game Data, Profile, and Community APIs.
 
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
 use serde::{Deserialize, Serialize};
+#[cfg(any(feature = "classic", feature = "wow"))]
+use time::OffsetDateTime;
 
-/// A document key associated to all model responses from the Game Data APIs.
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DocumentKey {
+use crate::localization::StringOrStructLocale;
+
+/// A document key associated to all model responses from the Game Data APIs. Keys are typed
+/// by the document they point to, allowing the client to resolve them into the correct model,
+/// and default to raw JSON for keys pointing to documents without a model.
+///
+/// ```rust
+/// use bubblehearth::classic::regions::Region;
+/// use bubblehearth::documents::DocumentKey;
+///
+/// let key: DocumentKey<Region> =
+///     DocumentKey::new("https://us.api.blizzard.com/data/wow/region/41?namespace=dynamic-classic-us");
+/// let untyped_key: DocumentKey = key.cast();
+///
+/// assert_eq!(key.href, untyped_key.href);
+/// ```
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct DocumentKey<T = serde_json::Value> {
     /// URL of the associated document.
     pub href: String,
+    /// Marker for the type of document the key points to.
+    #[serde(skip)]
+    document: PhantomData<fn() -> T>,
+}
+
+impl<T> DocumentKey<T> {
+    /// Constructs a new document key pointing to the given URL.
+    pub fn new(href: impl Into<String>) -> Self {
+        Self {
+            href: href.into(),
+            document: PhantomData,
+        }
+    }
+
+    /// Reinterprets the key as pointing to a different type of document.
+    pub fn cast<U>(&self) -> DocumentKey<U> {
+        DocumentKey::new(self.href.clone())
+    }
+}
+
+impl<T> Default for DocumentKey<T> {
+    fn default() -> Self {
+        Self::new(String::default())
+    }
+}
+
+impl<T> Debug for DocumentKey<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DocumentKey")
+            .field("href", &self.href)
+            .finish()
+    }
+}
+
+impl<T> Clone for DocumentKey<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.href.clone())
+    }
+}
+
+impl<T> PartialEq for DocumentKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.href == other.href
+    }
 }
 
 /// Self reference link for retrieving individual realm data. Not particularly useful,
@@ -17,3 +82,100 @@ pub struct Links {
     #[serde(rename = "self")]
     pub self_ref: DocumentKey,
 }
+
+/// A keyed reference to another document, embedded within a parent document,
+/// i.e. the family of a creature or the class of an item.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct DocumentReference<T = serde_json::Value> {
+    /// Document key of the referenced document.
+    pub key: Option<DocumentKey<T>>,
+    /// Localized name of the referenced document, if included.
+    pub name: Option<StringOrStructLocale>,
+    /// Numeric ID of the referenced document.
+    pub id: u64,
+}
+
+impl<T> Debug for DocumentReference<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DocumentReference")
+            .field("key", &self.key)
+            .field("name", &self.name)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<T> Clone for DocumentReference<T> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            name: self.name.clone(),
+            id: self.id,
+        }
+    }
+}
+
+impl<T> PartialEq for DocumentReference<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.name == other.name && self.id == other.id
+    }
+}
+
+/// Converts a Unix timestamp in milliseconds, as returned by Blizzard, into a timestamp.
+#[cfg(any(feature = "classic", feature = "wow"))]
+pub(crate) fn get_timestamp(timestamp: u64) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp_nanos(i128::from(timestamp) * 1_000_000).ok()
+}
+
+#[cfg(test)]
+mod document_tests {
+    use serde::Deserialize;
+
+    use crate::documents::DocumentKey;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct TestDocument {
+        id: u32,
+    }
+
+    #[derive(Deserialize)]
+    struct TestStruct {
+        key: DocumentKey<TestDocument>,
+    }
+
+    #[test]
+    fn returns_typed_key_when_deserialized() {
+        // Arrange
+        let data = r#"
+        {
+            "key": {
+                "href": "https://us.api.blizzard.com/data/wow/region/41?namespace=dynamic-classic-us"
+            }
+        }
+        "#;
+
+        // Act
+        let test_struct: TestStruct = serde_json::from_str(data).unwrap();
+
+        // Assert
+        assert_eq!(
+            test_struct.key,
+            DocumentKey::new(
+                "https://us.api.blizzard.com/data/wow/region/41?namespace=dynamic-classic-us"
+            )
+        );
+    }
+
+    #[test]
+    fn returns_same_href_when_serialized() {
+        // Arrange
+        let key: DocumentKey<TestDocument> = DocumentKey::new("https://localhost/document");
+
+        // Act
+        let serialized = serde_json::to_string(&key).unwrap();
+
+        // Assert
+        assert_eq!(serialized, r#"{"href":"https://localhost/document"}"#);
+    }
+}
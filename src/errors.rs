@@ -39,4 +39,34 @@ pub enum BubbleHearthError {
     /// Represents an error when attempting to construct a client instance with any options.
     #[error("One more required client options is missing.")]
     InvalidClientOptionField,
+    /// Represents an error occurring when reading or writing a cassette file.
+    #[error("Cassette failed: {0}")]
+    CassetteFailed(String),
+    /// Represents an error occurring when replaying a request that was not recorded to the cassette.
+    #[error("No recorded interaction was found for request {0}.")]
+    CassetteInteractionNotFound(String),
+    /// Represents an error raised by a registered interceptor, aborting the request.
+    #[error("Interceptor failed: {0}")]
+    InterceptorFailed(String),
+    /// Represents an error occurring when a document href is not a valid URL.
+    #[error("Document href {0} is not a valid URL.")]
+    InvalidDocumentHref(String),
+    /// Represents an error occurring when a document href points to a host other than a Blizzard API gateway.
+    #[error("Document href {0} does not point to a Blizzard API gateway.")]
+    UntrustedDocumentHost(String),
+    /// Represents an error occurring when a document href does not use https.
+    #[error("Document href {0} does not use https.")]
+    InsecureDocumentHref(String),
+    /// Represents an error occurring when determining a typed region from a region abbreviation.
+    #[error("Region {0} is unknown.")]
+    RegionUnknown(String),
+    /// Represents an error occurring when downloading, decoding, or composing guild crest layers.
+    #[error("Guild crest rendering failed: {0}")]
+    GuildCrestRenderFailed(String),
+    /// Represents an error occurring when a streamed document is malformed or an item fails to deserialize.
+    #[error("Document stream failed: {0}")]
+    DocumentStreamFailed(String),
+    /// Represents an error occurring when requesting leaderboards for an unknown PvP bracket.
+    #[error("PvP bracket is unknown and has no leaderboards.")]
+    PvpBracketUnknown,
 }
@@ -0,0 +1,85 @@
+//! Player factions shared by races, characters, guilds, and leaderboards.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::localization::StringOrStructLocale;
+
+/// Faction data, including the localized name of the faction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactionDetails {
+    /// Typed faction.
+    #[serde(rename = "type")]
+    pub faction_type: Faction,
+    /// Localized name of the faction.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Player factions.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Faction {
+    /// Represents the Alliance.
+    Alliance,
+    /// Represents the Horde.
+    Horde,
+    /// Represents neutral characters, i.e. Pandaren before choosing a faction.
+    Neutral,
+    /// Represents an unknown faction, detected when the provided faction is not able to be parsed.
+    Unknown,
+}
+
+impl From<String> for Faction {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ALLIANCE" => Faction::Alliance,
+            "HORDE" => Faction::Horde,
+            "NEUTRAL" => Faction::Neutral,
+            _ => Faction::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Faction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom("Invalid data type for faction.")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod faction_tests {
+    use crate::factions::{Faction, FactionDetails};
+
+    #[test]
+    fn returns_faction_when_match_found() {
+        // Arrange
+        let json = r#"{ "type": "HORDE", "name": "Horde" }"#;
+
+        // Act
+        let faction = serde_json::from_str::<FactionDetails>(json).unwrap();
+
+        // Assert
+        assert_eq!(faction.faction_type, Faction::Horde);
+    }
+
+    #[test]
+    fn returns_faction_unknown_when_no_match_found() {
+        // Arrange
+        let json = r#"{ "type": "SCOURGE" }"#;
+
+        // Act
+        let faction = serde_json::from_str::<FactionDetails>(json).unwrap();
+
+        // Assert
+        assert_eq!(faction.faction_type, Faction::Unknown);
+        assert_eq!(faction.name, None);
+    }
+}
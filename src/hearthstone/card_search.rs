@@ -96,10 +96,60 @@ impl<'a> CardSearchQueryBuilder<'a> {
     }
 }
 
+impl<'a> CardSearchQuery<'a> {
+    /// Encodes the configured filters as query string parameters, omitting any filters not provided.
+    pub(crate) fn get_query_parameters(&self) -> String {
+        let mut parameters = String::new();
+
+        if let Some(set) = self.set {
+            parameters.push_str(&format!("&set={}", set));
+        }
+
+        if let Some(class) = self.class {
+            parameters.push_str(&format!("&class={}", class));
+        }
+
+        if let Some(mana_costs) = &self.mana_costs {
+            let mana_costs: Vec<String> = mana_costs.iter().map(|cost| cost.to_string()).collect();
+            parameters.push_str(&format!("&manaCost={}", mana_costs.join(",")));
+        }
+
+        parameters
+    }
+}
+
 #[cfg(test)]
 mod card_search_queries {
     use crate::hearthstone::card_search::CardSearchQueryBuilder;
 
+    #[test]
+    fn returns_query_parameters_for_configured_filters() {
+        // Arrange
+        let builder = CardSearchQueryBuilder::new();
+
+        // Act
+        let query = builder
+            .with_class("mage")
+            .with_mana_cost(vec![3, 1])
+            .build()
+            .unwrap();
+
+        // Assert
+        assert_eq!(query.get_query_parameters(), "&class=mage&manaCost=1,3");
+    }
+
+    #[test]
+    fn returns_empty_query_parameters_when_no_filters_configured() {
+        // Arrange
+        let builder = CardSearchQueryBuilder::new();
+
+        // Act
+        let query = builder.build().unwrap();
+
+        // Assert
+        assert!(query.get_query_parameters().is_empty());
+    }
+
     #[test]
     fn returns_ok_when_validly_built() {
         // Arrange
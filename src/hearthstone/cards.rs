@@ -1,12 +1,18 @@
 //! Hearthstone card APIs for searching and retrieving cards and card backs.
 
+use futures::stream::BoxStream;
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 
 use crate::errors::BubbleHearthResult;
 use crate::hearthstone::card_search::CardSearchQuery;
 use crate::hearthstone::HearthstoneConnector;
+use crate::search::stream_pages;
 use crate::BubbleHearthId;
 
+/// Maximum number of cards Blizzard returns per page of card search results.
+pub const MAX_CARD_PAGE_SIZE: BubbleHearthId = 500;
+
 /// A generalized cards response for all Hearthstone card-based responses.
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,54 +41,167 @@ pub struct Card {
     pub class_id: BubbleHearthId,
     /// Available multi-class IDs of the card.
     pub multi_class_ids: Vec<BubbleHearthId>,
+    /// Spell school ID of the card, if the card is a spell.
     pub spell_school_id: Option<BubbleHearthId>,
+    /// Type ID of the card, i.e. minion, spell, weapon, etc.
     pub card_type_id: BubbleHearthId,
+    /// ID of the set the card belongs to.
     pub card_set_id: BubbleHearthId,
+    /// Rarity ID of the card.
     pub rarity_id: BubbleHearthId,
+    /// Name of the artist for the card art.
     pub artist_name: String,
+    /// Mana cost required to play the card.
     pub mana_cost: BubbleHearthId,
+    /// Name of the card.
     pub name: String,
+    /// Card text describing its effects.
     pub text: String,
+    /// URL of the card image.
     pub image: String,
+    /// URL of the golden card image.
     pub image_gold: String,
+    /// Flavor text of the card.
     pub flavor_text: String,
+    /// URL of the cropped card art.
     pub crop_image: String,
+    /// Keyword IDs associated to the card, i.e. taunt, battlecry, etc.
     pub keyword_ids: Option<Vec<BubbleHearthId>>,
+    /// Duels metadata for the card.
     pub duels: Option<Duels>,
+    /// ID of the card this card is a copy of, if any.
     pub copy_of_card_id: Option<BubbleHearthId>,
+    /// Health of the card, if the card is a minion.
     pub health: Option<BubbleHearthId>,
+    /// Attack of the card, if the card is a minion or weapon.
     pub attack: Option<BubbleHearthId>,
+    /// Minion type ID of the card, i.e. beast, dragon, etc.
     pub minion_type_id: Option<BubbleHearthId>,
+    /// IDs of any child cards generated by the card.
     pub child_ids: Option<Vec<BubbleHearthId>>,
+    /// Rune cost of the card, only applicable to Death Knight cards.
     pub rune_cost: Option<RuneCost>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Duels game mode metadata for a card.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Duels {
+    /// Flag indicating if the card is relevant to Duels.
     pub relevant: bool,
+    /// Flag indicating if the card is available in constructed Duels.
     pub constructed: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Death Knight rune costs required to play a card.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct RuneCost {
+    /// Number of blood runes required.
     pub blood: i64,
+    /// Number of frost runes required.
     pub frost: i64,
+    /// Number of unholy runes required.
     pub unholy: i64,
 }
 
+/// Progress of a multi-page card search, reported after each page is fetched.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardSearchProgress {
+    /// Number of pages fetched so far.
+    pub pages_fetched: BubbleHearthId,
+    /// Total number of pages in the search results.
+    pub page_count: BubbleHearthId,
+    /// Number of cards fetched so far.
+    pub cards_fetched: BubbleHearthId,
+    /// Total number of cards in the search results.
+    pub card_count: BubbleHearthId,
+}
+
 impl<'a> HearthstoneConnector<'a> {
     /// Searches for cards fronts and back based on the provided search criteria.
     pub async fn search_cards(
         &self,
-        _query: Option<CardSearchQuery<'a>>,
+        query: Option<CardSearchQuery<'a>>,
+    ) -> BubbleHearthResult<Cards> {
+        self.search_cards_page(query.as_ref(), None, None).await
+    }
+
+    /// Searches for a single page of cards based on the provided search criteria.
+    async fn search_cards_page(
+        &self,
+        query: Option<&CardSearchQuery<'a>>,
+        page: Option<BubbleHearthId>,
+        page_size: Option<BubbleHearthId>,
     ) -> BubbleHearthResult<Cards> {
-        let cards: Cards = self
-            .client
-            .send_request_and_deserialize(format!(
-                "https://us.api.blizzard.com/hearthstone/cards?locale={}",
-                self.client.locale.get_normalized_locale()
-            ))
-            .await?;
+        let mut url = format!(
+            "{}/hearthstone/cards?locale={}",
+            self.client.get_base_url(),
+            self.client.locale.get_normalized_locale()
+        );
+
+        if let Some(query) = query {
+            url.push_str(&query.get_query_parameters());
+        }
+
+        if let Some(page) = page {
+            url.push_str(&format!("&page={}", page));
+        }
+
+        if let Some(size) = page_size {
+            url.push_str(&format!("&pageSize={}", size));
+        }
+
+        let cards: Cards = self.client.send_request_and_deserialize(url).await?;
+
+        Ok(cards)
+    }
+
+    /// Streams every page of cards matching the provided search criteria, using the maximum page size.
+    /// After the first page determines the page count, the remaining pages are fetched concurrently
+    /// using the client's bulk concurrency, though still yielded in page order.
+    pub fn search_cards_pages(
+        &self,
+        query: Option<CardSearchQuery<'a>>,
+    ) -> BoxStream<'a, BubbleHearthResult<Cards>> {
+        let connector = *self;
+        let fetch_page = move |page| {
+            let query = query.clone();
+            async move {
+                connector
+                    .search_cards_page(query.as_ref(), Some(page), Some(MAX_CARD_PAGE_SIZE))
+                    .await
+            }
+        };
+
+        stream_pages(
+            fetch_page,
+            |cards: &Cards| cards.page_count,
+            None,
+            self.client.bulk_concurrency,
+        )
+    }
+
+    /// Collects every card matching the provided search criteria across all pages, reporting
+    /// progress after each page is fetched. Returns the first error encountered, if any.
+    pub async fn collect_all_cards(
+        &self,
+        query: Option<CardSearchQuery<'a>>,
+        mut on_progress: impl FnMut(CardSearchProgress),
+    ) -> BubbleHearthResult<Vec<Card>> {
+        let mut pages = self.search_cards_pages(query);
+        let mut progress = CardSearchProgress::default();
+        let mut cards = Vec::new();
+
+        while let Some(page) = pages.next().await {
+            let page = page?;
+            progress = CardSearchProgress {
+                pages_fetched: progress.pages_fetched + 1,
+                page_count: page.page_count,
+                cards_fetched: progress.cards_fetched + page.cards.len() as BubbleHearthId,
+                card_count: page.card_count,
+            };
+            cards.extend(page.cards);
+            on_progress(progress);
+        }
 
         Ok(cards)
     }
@@ -8,7 +8,7 @@ pub mod card_search;
 pub mod cards;
 
 /// A client for WoW Classic, utilizing the base client authentication.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct HearthstoneConnector<'a> {
     /// Parent client containing the HTTP client and authorization context.
     client: &'a BubbleHearthClient,
@@ -0,0 +1,55 @@
+//! Interceptor hooks for observing and modifying the HTTP interactions sent by the client.
+//! Interceptors see every outgoing request before it's sent, including token requests,
+//! and every response before it's deserialized, allowing for correlation headers,
+//! auditing, URL rewrites for internal gateways, etc.
+//!
+//! ```rust
+//! use bubblehearth::builder::BubbleHearthClientBuilder;
+//! use bubblehearth::errors::BubbleHearthResult;
+//! use bubblehearth::interceptors::Interceptor;
+//! use bubblehearth::localization::Locale;
+//! use bubblehearth::regionality::AccountRegion;
+//!
+//! #[derive(Debug)]
+//! struct CorrelationInterceptor;
+//!
+//! impl Interceptor for CorrelationInterceptor {
+//!     fn intercept_request(&self, request: &mut reqwest::Request) -> BubbleHearthResult<()> {
+//!         request
+//!             .headers_mut()
+//!             .insert("X-Correlation-Id", "bubblehearth".parse().unwrap());
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let client = BubbleHearthClientBuilder::new()
+//!     .with_client_id("client_id".to_string())
+//!     .with_client_secret("client_secret".to_string())
+//!     .with_region(AccountRegion::US)
+//!     .with_locale(Locale::EnglishUS)
+//!     .with_interceptor(CorrelationInterceptor)
+//!     .build();
+//!
+//! assert!(client.is_ok());
+//! ```
+
+use std::fmt::Debug;
+
+use crate::errors::BubbleHearthResult;
+
+/// Hooks invoked by the client for every request and response, in the order interceptors were registered.
+/// Both hooks default to doing nothing, so implementors only need to override the hooks they're interested in.
+pub trait Interceptor: Debug + Send + Sync {
+    /// Invoked with every outgoing request before it's sent, including token requests.
+    /// Requests may be freely modified, i.e. adding headers or rewriting the URL.
+    /// Returning an error will abort the request, propagating the error back to the caller.
+    fn intercept_request(&self, _request: &mut reqwest::Request) -> BubbleHearthResult<()> {
+        Ok(())
+    }
+
+    /// Invoked with every response before it's deserialized, including token responses.
+    /// Returning an error will abort deserialization, propagating the error back to the caller.
+    fn intercept_response(&self, _response: &reqwest::Response) -> BubbleHearthResult<()> {
+        Ok(())
+    }
+}
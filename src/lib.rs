@@ -1,5 +1,18 @@
 //! Common Blizzard HTTP interactions and downstream calls, leveraging [reqwest](https://docs.rs/reqwest/latest/reqwest/) for
 //! connecting to Blizzard API endpoints.
+//!
+//! Game connectors are compiled based on the enabled cargo features, allowing consumers
+//! to only pull in the APIs they use:
+//!
+//! ```text
+//! classic => World of Warcraft Classic Game Data APIs (default)
+//! hearthstone => Hearthstone Game Data APIs (default)
+//! wow => World of Warcraft Game Data APIs (default)
+//! native-tls => TLS through the platform's native TLS implementation (default)
+//! rustls-tls => TLS through rustls, useful for environments without OpenSSL
+//! testing => An offline testing kit with a fixture-backed fake Blizzard API
+//! crest => Offline guild crest rendering for World of Warcraft Classic, pulling in the image crate
+//! ```
 
 #![forbid(unsafe_code)]
 #![warn(
@@ -13,17 +26,36 @@
     clippy::single_char_pattern
 )]
 
+#[cfg(feature = "hearthstone")]
 type BubbleHearthId = u32;
 
+pub mod auctions;
 mod auth;
 pub mod builder;
+pub mod bulk;
+pub mod cassette;
+#[cfg(feature = "classic")]
 pub mod classic;
 pub mod client;
+#[cfg(any(feature = "classic", feature = "wow"))]
+pub mod connected_realms;
+#[cfg(any(feature = "classic", feature = "hearthstone", feature = "wow"))]
 mod connectors;
-mod documents;
+pub mod documents;
 pub mod errors;
+pub mod factions;
+#[cfg(feature = "hearthstone")]
 pub mod hearthstone;
+pub mod interceptors;
 pub mod localization;
+pub mod media;
+pub mod references;
 pub mod regionality;
 pub mod search;
+#[cfg(any(feature = "classic", feature = "wow"))]
+pub mod streaming;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod timezone;
+#[cfg(feature = "wow")]
+pub mod wow;
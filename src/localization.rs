@@ -225,7 +225,7 @@ impl<'de> Deserialize<'de> for StringOrStructLocale {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "classic"))]
 mod localization_tests {
     use crate::classic::realms::RealmsIndex;
     use crate::localization::StringOrStructLocale::StructLocale;
@@ -0,0 +1,132 @@
+//! Media data models shared by the media endpoints of the Game Data APIs, i.e. creature displays,
+//! item icons, class icons, etc.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::documents::Links;
+
+/// Media document listing the assets available for a Game Data document.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Media {
+    /// Top-level document link to follow of the selected media.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Assets available for the document, i.e. icons, zoomed renders, etc.
+    #[serde(default)]
+    pub assets: Vec<MediaAsset>,
+    /// Numeric media ID.
+    pub id: Option<u64>,
+}
+
+impl Media {
+    /// Gets the first asset of the given kind, if available.
+    pub fn get_asset(&self, key: MediaAssetKey) -> Option<&MediaAsset> {
+        self.assets.iter().find(|asset| asset.key == key)
+    }
+}
+
+/// A single media asset, pointing to the rendered file on Blizzard's CDN.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaAsset {
+    /// Kind of asset, i.e. icon, zoom, etc.
+    pub key: MediaAssetKey,
+    /// URL of the asset file.
+    pub value: String,
+    /// Numeric ID of the asset file within the game client's data, if provided.
+    pub file_data_id: Option<u64>,
+}
+
+/// Kinds of media assets.
+#[derive(Debug, Copy, Clone, Serialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum MediaAssetKey {
+    /// Represents an icon, i.e. of an item, spell, or class.
+    Icon,
+    /// Represents a zoomed render, i.e. of a creature display.
+    Zoom,
+    /// Represents a character's avatar, cropped to their face.
+    Avatar,
+    /// Represents a character's inset render, cropped to their upper body.
+    Inset,
+    /// Represents a character's full body render, including the background.
+    Main,
+    /// Represents a character's full body render, without the background.
+    MainRaw,
+    /// Represents a standalone image, i.e. of a guild crest emblem or border.
+    Image,
+    /// Represents an unknown asset kind, detected when the provided key is not able to be parsed.
+    Unknown,
+}
+
+impl From<String> for MediaAssetKey {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "icon" => MediaAssetKey::Icon,
+            "zoom" => MediaAssetKey::Zoom,
+            "avatar" => MediaAssetKey::Avatar,
+            "inset" => MediaAssetKey::Inset,
+            "main" => MediaAssetKey::Main,
+            "main-raw" => MediaAssetKey::MainRaw,
+            "image" => MediaAssetKey::Image,
+            _ => MediaAssetKey::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MediaAssetKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json_value: Value = Deserialize::deserialize(deserializer)?;
+
+        match json_value {
+            Value::String(s) => Ok(s.into()),
+            _ => Err(serde::de::Error::custom(
+                "Invalid data type for media asset key.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod media_tests {
+    use crate::media::{Media, MediaAssetKey};
+
+    #[test]
+    fn returns_typed_asset_keys_when_deserialized() {
+        // Arrange
+        let json = r#"{
+            "assets": [
+                { "key": "main-raw", "value": "https://render.worldofwarcraft.com/main-raw.png" },
+                { "key": "avatar", "value": "https://render.worldofwarcraft.com/avatar.jpg" },
+                { "key": "not-an-asset", "value": "https://render.worldofwarcraft.com/unknown.jpg" }
+            ]
+        }"#;
+
+        // Act
+        let media = serde_json::from_str::<Media>(json).unwrap();
+
+        // Assert
+        assert_eq!(media.assets[0].key, MediaAssetKey::MainRaw);
+        assert_eq!(media.assets[2].key, MediaAssetKey::Unknown);
+        assert_eq!(
+            media.get_asset(MediaAssetKey::Avatar).unwrap().value,
+            "https://render.worldofwarcraft.com/avatar.jpg"
+        );
+        assert!(media.get_asset(MediaAssetKey::Icon).is_none());
+    }
+
+    #[test]
+    fn returns_error_when_asset_key_not_a_string() {
+        // Arrange
+        let json = r#"{ "assets": [{ "key": 1, "value": "" }] }"#;
+
+        // Act
+        let media = serde_json::from_str::<Media>(json);
+
+        // Assert
+        assert!(media.is_err());
+    }
+}
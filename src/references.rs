@@ -0,0 +1,305 @@
+//! Structured resource references parsed from document hrefs, encoding the region, namespace,
+//! game, and resource of a document. Useful for keying documents without storing full URLs.
+
+use std::str::FromStr;
+
+use crate::documents::DocumentKey;
+use crate::errors::{BubbleHearthError, BubbleHearthResult};
+use crate::regionality::{AccountRegion, CN_API_HOST};
+
+/// Blizzard games addressable by document hrefs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Game {
+    /// Represents World of Warcraft, addressed by the `wow` path segment.
+    WorldOfWarcraft,
+    /// Represents World of Warcraft Classic, addressed by the `wow` path segment within a classic namespace.
+    WorldOfWarcraftClassic,
+    /// Represents Hearthstone, addressed by the `hearthstone` path segment.
+    Hearthstone,
+    /// Represents StarCraft II, addressed by the `sc2` path segment.
+    StarCraft2,
+    /// Represents Diablo III, addressed by the `d3` path segment.
+    Diablo3,
+}
+
+/// API families prefixing the game within document paths.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ResourceApi {
+    /// Represents the Game Data APIs, prefixed by the `data` path segment.
+    GameData,
+    /// Represents the Profile APIs, prefixed by the `profile` path segment.
+    Profile,
+}
+
+/// A structured reference to a document, parsed from or built into a document href.
+///
+/// ```rust
+/// use bubblehearth::references::{Game, ResourceReference};
+/// use bubblehearth::regionality::AccountRegion;
+///
+/// let href = "https://us.api.blizzard.com/data/wow/realm/4372?namespace=dynamic-classic-us";
+/// let reference: ResourceReference = href.parse().unwrap();
+///
+/// assert_eq!(reference.region, AccountRegion::US);
+/// assert_eq!(reference.namespace, Some("dynamic-classic-us".to_string()));
+/// assert_eq!(reference.game, Game::WorldOfWarcraftClassic);
+/// assert_eq!(reference.path, vec!["realm".to_string(), "4372".to_string()]);
+/// assert_eq!(reference.id, Some(4372));
+/// assert_eq!(reference.to_href(), href);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ResourceReference {
+    /// Region of the API gateway the document belongs to.
+    pub region: AccountRegion,
+    /// Namespace of the document, i.e. `dynamic-classic-us`, if one was provided.
+    pub namespace: Option<String>,
+    /// API family of the document, if the path is prefixed by one.
+    pub api: Option<ResourceApi>,
+    /// Game the document belongs to.
+    pub game: Game,
+    /// Resource path segments following the game, i.e. `["realm", "4372"]`.
+    pub path: Vec<String>,
+    /// Numeric ID of the document, parsed from the last path segment when numeric.
+    pub id: Option<u64>,
+}
+
+impl Game {
+    /// Gets the path segment used to address the game within document paths.
+    pub fn get_path_segment(&self) -> &str {
+        match self {
+            Game::WorldOfWarcraft | Game::WorldOfWarcraftClassic => "wow",
+            Game::Hearthstone => "hearthstone",
+            Game::StarCraft2 => "sc2",
+            Game::Diablo3 => "d3",
+        }
+    }
+}
+
+impl ResourceApi {
+    /// Gets the path segment prefixing the game within document paths.
+    pub fn get_path_segment(&self) -> &str {
+        match self {
+            ResourceApi::GameData => "data",
+            ResourceApi::Profile => "profile",
+        }
+    }
+}
+
+impl ResourceReference {
+    /// Constructs a reference from its parts, deriving the numeric ID from the last path segment.
+    pub fn new(
+        region: AccountRegion,
+        namespace: Option<String>,
+        api: Option<ResourceApi>,
+        game: Game,
+        path: Vec<String>,
+    ) -> Self {
+        let id = path.last().and_then(|segment| segment.parse::<u64>().ok());
+
+        Self {
+            region,
+            namespace,
+            api,
+            game,
+            path,
+            id,
+        }
+    }
+
+    /// Builds the document href the reference points to.
+    pub fn to_href(&self) -> String {
+        let host = self.region.get_api_host();
+        let mut segments = Vec::new();
+
+        if let Some(api) = &self.api {
+            segments.push(api.get_path_segment());
+        }
+
+        segments.push(self.game.get_path_segment());
+        segments.extend(self.path.iter().map(String::as_str));
+
+        let mut href = format!("https://{}/{}", host, segments.join("/"));
+
+        if let Some(namespace) = &self.namespace {
+            href.push_str(&format!("?namespace={}", namespace));
+        }
+
+        href
+    }
+
+    /// Builds a typed document key pointing to the referenced document.
+    pub fn to_document_key<T>(&self) -> DocumentKey<T> {
+        DocumentKey::new(self.to_href())
+    }
+}
+
+/// Parses a document href into its structured reference.
+impl FromStr for ResourceReference {
+    type Err = BubbleHearthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid_href = || BubbleHearthError::InvalidDocumentHref(s.to_string());
+        let url = reqwest::Url::parse(s).map_err(|_| invalid_href())?;
+
+        let region = match url.host_str() {
+            Some(CN_API_HOST) => AccountRegion::CN,
+            Some(host) => match host.strip_suffix(".api.blizzard.com") {
+                Some(abbreviation) => abbreviation.parse::<AccountRegion>()?,
+                None => return Err(BubbleHearthError::UntrustedDocumentHost(s.to_string())),
+            },
+            None => return Err(invalid_href()),
+        };
+
+        let namespace = url
+            .query_pairs()
+            .find(|(key, _)| key == "namespace")
+            .map(|(_, value)| value.into_owned());
+
+        let mut segments = url
+            .path_segments()
+            .ok_or_else(invalid_href)?
+            .filter(|segment| !segment.is_empty())
+            .peekable();
+
+        let api = match segments.peek() {
+            Some(&"data") => Some(ResourceApi::GameData),
+            Some(&"profile") => Some(ResourceApi::Profile),
+            _ => None,
+        };
+
+        if api.is_some() {
+            segments.next();
+        }
+
+        let is_classic = namespace
+            .as_ref()
+            .is_some_and(|namespace| namespace.contains("-classic"));
+
+        let game = match segments.next() {
+            Some("wow") if is_classic => Game::WorldOfWarcraftClassic,
+            Some("wow") => Game::WorldOfWarcraft,
+            Some("hearthstone") => Game::Hearthstone,
+            Some("sc2") => Game::StarCraft2,
+            Some("d3") => Game::Diablo3,
+            _ => return Err(invalid_href()),
+        };
+
+        let path = segments.map(str::to_string).collect();
+
+        Ok(Self::new(region, namespace, api, game, path))
+    }
+}
+
+impl<T> DocumentKey<T> {
+    /// Parses the key's href into a structured reference of the document it points to.
+    pub fn parse_reference(&self) -> BubbleHearthResult<ResourceReference> {
+        self.href.parse()
+    }
+}
+
+#[cfg(test)]
+mod reference_tests {
+    use crate::documents::DocumentKey;
+    use crate::errors::BubbleHearthError;
+    use crate::references::{Game, ResourceApi, ResourceReference};
+    use crate::regionality::AccountRegion;
+
+    #[test]
+    fn returns_reference_when_href_is_classic_game_data() {
+        // Arrange
+        let key: DocumentKey = DocumentKey::new(
+            "https://eu.api.blizzard.com/data/wow/region/41?namespace=dynamic-classic-eu",
+        );
+
+        // Act
+        let reference = key.parse_reference().unwrap();
+
+        // Assert
+        assert_eq!(reference.region, AccountRegion::EU);
+        assert_eq!(reference.api, Some(ResourceApi::GameData));
+        assert_eq!(reference.game, Game::WorldOfWarcraftClassic);
+        assert_eq!(reference.path, vec!["region", "41"]);
+        assert_eq!(reference.id, Some(41));
+    }
+
+    #[test]
+    fn returns_reference_without_id_when_last_segment_not_numeric() {
+        // Arrange
+        let href = "https://us.api.blizzard.com/profile/wow/character/atiesh/bubblehearth?namespace=profile-us";
+
+        // Act
+        let reference: ResourceReference = href.parse().unwrap();
+
+        // Assert
+        assert_eq!(reference.api, Some(ResourceApi::Profile));
+        assert_eq!(reference.game, Game::WorldOfWarcraft);
+        assert_eq!(reference.path, vec!["character", "atiesh", "bubblehearth"]);
+        assert_eq!(reference.id, None);
+    }
+
+    #[test]
+    fn returns_reference_when_href_has_no_api_prefix_or_namespace() {
+        // Arrange
+        let href = "https://gateway.battlenet.com.cn/hearthstone/cards/678";
+
+        // Act
+        let reference: ResourceReference = href.parse().unwrap();
+
+        // Assert
+        assert_eq!(reference.region, AccountRegion::CN);
+        assert_eq!(reference.namespace, None);
+        assert_eq!(reference.api, None);
+        assert_eq!(reference.game, Game::Hearthstone);
+        assert_eq!(reference.id, Some(678));
+        assert_eq!(reference.to_href(), href);
+    }
+
+    #[test]
+    fn returns_href_when_built_from_parts() {
+        // Arrange
+        let reference = ResourceReference::new(
+            AccountRegion::KR,
+            Some("static-kr".to_string()),
+            Some(ResourceApi::GameData),
+            Game::WorldOfWarcraft,
+            vec!["achievement".to_string(), "6".to_string()],
+        );
+
+        // Act
+        let href = reference.to_href();
+
+        // Assert
+        assert_eq!(reference.id, Some(6));
+        assert_eq!(
+            href,
+            "https://kr.api.blizzard.com/data/wow/achievement/6?namespace=static-kr"
+        );
+    }
+
+    #[test]
+    fn returns_error_when_host_is_not_a_blizzard_gateway() {
+        // Arrange
+        let href = "https://localhost/data/wow/realm/4372";
+
+        // Act
+        let reference = href.parse::<ResourceReference>();
+
+        // Assert
+        assert!(matches!(
+            reference,
+            Err(BubbleHearthError::UntrustedDocumentHost(_))
+        ));
+    }
+
+    #[test]
+    fn returns_error_when_game_unknown() {
+        // Arrange
+        let href = "https://us.api.blizzard.com/data/overwatch/hero/1";
+
+        // Act
+        let reference = href.parse::<ResourceReference>();
+
+        // Assert
+        assert!(reference.is_err());
+    }
+}
@@ -1,5 +1,9 @@
 //! Region-based metadata for targeting specific instances of the Blizzard APIs.
 
+use std::str::FromStr;
+
+use crate::errors::BubbleHearthError;
+
 /// Authorize endpoint for global regionalities.
 pub const GLOBAL_AUTHORIZE_ENDPOINT: &str = "https://oauth.battle.net/authorize";
 
@@ -12,8 +16,11 @@ pub const CN_AUTHORIZE_ENDPOINT: &str = "https://oauth.battlenet.com.cn/authoriz
 /// Authorize endpoint for China regionality.
 pub const CN_TOKEN_ENDPOINT: &str = "https://oauth.battlenet.com.cn/token";
 
+/// Host of the China API gateway, the only region not following the regional subdomain convention.
+pub const CN_API_HOST: &str = "gateway.battlenet.com.cn";
+
 /// Regions associated to their corresponding API gateways.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum AccountRegion {
     /// Represents the China region and China API gateway.
     CN,
@@ -46,4 +53,28 @@ impl AccountRegion {
             AccountRegion::TW => "tw",
         }
     }
+
+    /// Gets the host of the region's API gateway, i.e. `us.api.blizzard.com`.
+    pub fn get_api_host(&self) -> String {
+        match self {
+            AccountRegion::CN => CN_API_HOST.to_string(),
+            _ => format!("{}.api.blizzard.com", self.get_region_abbreviation()),
+        }
+    }
+}
+
+/// Maps a region abbreviation, as used by API gateway subdomains and namespaces, into a typed region.
+impl FromStr for AccountRegion {
+    type Err = BubbleHearthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cn" => Ok(AccountRegion::CN),
+            "us" => Ok(AccountRegion::US),
+            "eu" => Ok(AccountRegion::EU),
+            "kr" => Ok(AccountRegion::KR),
+            "tw" => Ok(AccountRegion::TW),
+            &_ => Err(BubbleHearthError::RegionUnknown(s.to_string())),
+        }
+    }
 }
@@ -1,8 +1,22 @@
 //! Search result data models and utilities for all Game Data, Community, and Profile APIs.
 
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+
+use futures::stream::{self, BoxStream};
+use futures::{StreamExt, TryStreamExt};
 use serde::Deserialize;
 
+use crate::client::BubbleHearthClient;
 use crate::documents::DocumentKey;
+use crate::errors::BubbleHearthResult;
+use crate::localization::Locale;
+
+/// Shared mapper applied to each search result item's data as pages are received.
+type ItemMapper<'a, T> = Arc<dyn Fn(&mut T) + Send + Sync + 'a>;
 
 /// Paginated search results from various Game Data APIs.
 #[derive(Debug, Deserialize)]
@@ -23,8 +37,619 @@ pub struct SearchResult<T> {
 /// Generic paged data returned from the search result.
 #[derive(Debug, Deserialize)]
 pub struct SearchResultItem<T> {
-    /// Key of the search result item
-    pub key: DocumentKey,
+    /// Key of the search result item, resolvable into the item's full document.
+    pub key: DocumentKey<T>,
     /// Generic item data returned from the search.
     pub data: T,
 }
+
+/// Builds the search URL for the given page, appending the paging parameters to any existing search filters.
+pub(crate) fn get_search_page_url(url: &str, page: u32, page_size: Option<u32>) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let mut page_url = format!("{}{}_page={}", url, separator, page);
+
+    if let Some(size) = page_size {
+        page_url.push_str(&format!("&_pageSize={}", size));
+    }
+
+    page_url
+}
+
+/// Streams each page in order, requesting the first page to determine the page count before
+/// requesting the remaining pages with up to the given number of requests in flight. Stops at
+/// the last page, the page limit, or after yielding the first error encountered.
+pub(crate) fn stream_pages<'a, P, F, Fut>(
+    fetch_page: F,
+    get_page_count: fn(&P) -> u32,
+    max_pages: Option<u32>,
+    concurrency: usize,
+) -> BoxStream<'a, BubbleHearthResult<P>>
+where
+    P: Send + 'a,
+    F: Fn(u32) -> Fut + Clone + Send + 'a,
+    Fut: Future<Output = BubbleHearthResult<P>> + Send + 'a,
+{
+    if max_pages == Some(0) {
+        return stream::empty().boxed();
+    }
+
+    let first_page = fetch_page(1);
+
+    stream::once(first_page)
+        .flat_map(move |first_page| match first_page {
+            Err(e) => stream::once(async { Err(e) }).boxed(),
+            Ok(first_page) => {
+                let page_count = get_page_count(&first_page);
+                let last_page = match max_pages {
+                    Some(max) => page_count.min(max),
+                    None => page_count,
+                };
+                let remaining_pages = stream::iter(2..=last_page)
+                    .map(fetch_page.clone())
+                    .buffered(concurrency.max(1));
+
+                stream::once(async { Ok(first_page) })
+                    .chain(remaining_pages)
+                    .boxed()
+            }
+        })
+        .scan(false, |failed, page| {
+            // Stop paging once an error has been yielded, as later pages are unlikely to succeed
+            let item = if *failed { None } else { Some(page) };
+            *failed = item.as_ref().is_some_and(|page| page.is_err());
+            async move { item }
+        })
+        .boxed()
+}
+
+/// Searchable fields of a Game Data document, used to type the filters and ordering of a search query.
+pub trait SearchField {
+    /// Gets the field name as sent in search queries, i.e. `name`, `region.id`, etc.
+    fn get_field_name(&self) -> &str;
+}
+
+/// Sort direction for ordering search results by a field.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SortOrder {
+    /// Orders results from lowest to highest.
+    Ascending,
+    /// Orders results from highest to lowest.
+    Descending,
+}
+
+impl SortOrder {
+    /// Gets the sort direction suffix used in the `orderby` parameter.
+    fn get_suffix(&self) -> &str {
+        match self {
+            SortOrder::Ascending => "asc",
+            SortOrder::Descending => "desc",
+        }
+    }
+}
+
+/// A typed search query for Game Data search endpoints, encoding filters, ordering,
+/// and page size over the searchable fields of a document.
+///
+/// ```rust
+/// use bubblehearth::classic::realms::RealmSearchField;
+/// use bubblehearth::localization::Locale;
+/// use bubblehearth::search::{SearchQuery, SortOrder};
+/// use bubblehearth::timezone::Timezone;
+///
+/// let query = SearchQuery::new()
+///     .filter(RealmSearchField::Timezone, Timezone::AmericaNewYork)
+///     .filter_localized(RealmSearchField::Name, Locale::EnglishUS, "Whitemane")
+///     .filter_not(RealmSearchField::IsTournament, true)
+///     .filter_range(RealmSearchField::Id, 4372..=4400)
+///     .order_by_localized(RealmSearchField::Name, Locale::EnglishUS, SortOrder::Ascending)
+///     .order_by(RealmSearchField::Id, SortOrder::Descending)
+///     .with_page_size(50);
+///
+/// assert_eq!(
+///     query.to_query_string(),
+///     "timezone=America/New_York&name.en_US=Whitemane&is_tournament!=true&id=[4372,4400]&orderby=name.en_US:asc,id:desc&_pageSize=50"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery<F> {
+    /// Encoded filters, in the order they were added.
+    filters: Vec<String>,
+    /// Encoded ordering keys, in order of precedence.
+    order_by: Vec<String>,
+    /// Optional number of results per page, sent as the `_pageSize` parameter.
+    page_size: Option<u32>,
+    /// Marker for the searchable fields of the query.
+    fields: PhantomData<fn() -> F>,
+}
+
+impl<F> Default for SearchQuery<F> {
+    fn default() -> Self {
+        Self {
+            filters: Vec::new(),
+            order_by: Vec::new(),
+            page_size: None,
+            fields: PhantomData,
+        }
+    }
+}
+
+impl<F: SearchField> SearchQuery<F> {
+    /// Constructs an empty search query, matching all documents.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filters on documents with the field equal to the value, i.e. `timezone=America/New_York`.
+    pub fn filter(self, field: F, value: impl Display) -> Self {
+        self.with_filter(field.get_field_name(), "=", value.to_string())
+    }
+
+    /// Filters on documents with the localized field equal to the value, i.e. `name.en_US=Atiesh`.
+    pub fn filter_localized(self, field: F, locale: Locale, value: impl Display) -> Self {
+        let field_name = get_localized_field_name(&field, locale);
+        self.with_filter(&field_name, "=", value.to_string())
+    }
+
+    /// Filters on documents with the field not equal to the value, i.e. `is_tournament!=true`.
+    pub fn filter_not(self, field: F, value: impl Display) -> Self {
+        self.with_filter(field.get_field_name(), "!=", value.to_string())
+    }
+
+    /// Filters on documents with the field equal to any of the values, i.e. `id=4372||4373`.
+    pub fn filter_any<V: Display>(self, field: F, values: impl IntoIterator<Item = V>) -> Self {
+        let value = values
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join("||");
+        self.with_filter(field.get_field_name(), "=", value)
+    }
+
+    /// Filters on documents with the field within the range, with inclusive bounds encoded as
+    /// brackets, exclusive bounds as parentheses, and unbounded ends left empty following the
+    /// bracket Blizzard expects for each side, i.e. `id=[1,10]`, `id=(,5]`, or `id=(1,]`.
+    pub fn filter_range<V: Display>(self, field: F, range: impl RangeBounds<V>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(start) => format!("[{}", start),
+            Bound::Excluded(start) => format!("({}", start),
+            Bound::Unbounded => "(".to_string(),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => format!("{}]", end),
+            Bound::Excluded(end) => format!("{})", end),
+            Bound::Unbounded => "]".to_string(),
+        };
+        self.with_filter(field.get_field_name(), "=", format!("{},{}", start, end))
+    }
+
+    /// Orders results by the field, with earlier ordering keys taking precedence.
+    pub fn order_by(mut self, field: F, order: SortOrder) -> Self {
+        self.order_by
+            .push(format!("{}:{}", field.get_field_name(), order.get_suffix()));
+        self
+    }
+
+    /// Orders results by the localized field, with earlier ordering keys taking precedence.
+    pub fn order_by_localized(mut self, field: F, locale: Locale, order: SortOrder) -> Self {
+        self.order_by.push(format!(
+            "{}:{}",
+            get_localized_field_name(&field, locale),
+            order.get_suffix()
+        ));
+        self
+    }
+
+    /// Sets the number of results per page, capped by Blizzard at the search result's maximum page size.
+    pub fn with_page_size(self, page_size: u32) -> Self {
+        Self {
+            page_size: Some(page_size),
+            ..self
+        }
+    }
+
+    /// Gets the number of results per page, if one was set.
+    pub fn get_page_size(&self) -> Option<u32> {
+        self.page_size
+    }
+
+    /// Encodes the query into its query string, without a leading `?`.
+    pub fn to_query_string(&self) -> String {
+        let mut parameters = self.get_filter_parameters();
+
+        if let Some(size) = self.page_size {
+            parameters.push(format!("_pageSize={}", size));
+        }
+
+        parameters.join("&")
+    }
+
+    /// Builds the search URL for the endpoint, including filters and ordering but excluding paging.
+    /// Endpoint URLs may already include parameters, i.e. a namespace, which the query is appended to.
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    pub(crate) fn get_search_url(&self, endpoint_url: &str) -> String {
+        let parameters = self.get_filter_parameters();
+
+        if parameters.is_empty() {
+            endpoint_url.to_string()
+        } else {
+            let separator = if endpoint_url.contains('?') { '&' } else { '?' };
+            format!("{}{}{}", endpoint_url, separator, parameters.join("&"))
+        }
+    }
+
+    /// Builds the search URL for the endpoint for the given page, including the query's page size.
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    pub(crate) fn get_search_page_url(&self, endpoint_url: &str, page: u32) -> String {
+        get_search_page_url(&self.get_search_url(endpoint_url), page, self.page_size)
+    }
+
+    /// Adds an encoded filter for the field name, comparison, and raw value.
+    fn with_filter(mut self, field_name: &str, comparison: &str, value: String) -> Self {
+        self.filters.push(format!(
+            "{}{}{}",
+            field_name,
+            comparison,
+            encode_search_value(&value)
+        ));
+        self
+    }
+
+    /// Gets all encoded filter and ordering parameters, excluding paging.
+    fn get_filter_parameters(&self) -> Vec<String> {
+        let mut parameters = self.filters.clone();
+
+        if !self.order_by.is_empty() {
+            parameters.push(format!("orderby={}", self.order_by.join(",")));
+        }
+
+        parameters
+    }
+}
+
+/// Gets the locale-qualified name of a field, i.e. `name.en_US`.
+fn get_localized_field_name(field: &impl SearchField, locale: Locale) -> String {
+    format!(
+        "{}.{}",
+        field.get_field_name(),
+        locale.get_normalized_locale()
+    )
+}
+
+/// Percent-encodes a search value, leaving the range, list, and path characters used by the search syntax intact.
+fn encode_search_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~'
+            | b'/'
+            | b'['
+            | b']'
+            | b'('
+            | b')'
+            | b','
+            | b'|'
+            | b':' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// A paginator over all pages of a search endpoint, yielding pages or individual
+/// search result items as an async stream. Pages are requested lazily as the stream
+/// is polled, starting from the first page until the page count or page limit is reached.
+///
+/// ```rust
+/// use futures::TryStreamExt;
+/// use bubblehearth::localization::Locale;
+/// use bubblehearth::regionality::AccountRegion;
+/// use bubblehearth::search::SearchQuery;
+/// use bubblehearth::testing::FakeBlizzardServer;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let server = FakeBlizzardServer::start().await;
+///     let client = server.client(AccountRegion::US, Locale::EnglishUS);
+///     let realms: Vec<_> = client
+///         .classic()
+///         .search_realms_paginator(&SearchQuery::new())
+///         .with_page_size(100)
+///         .with_max_pages(5)
+///         .items()
+///         .try_collect()
+///         .await
+///         .unwrap();
+///
+///     assert!(!realms.is_empty());
+/// }
+/// ```
+pub struct SearchPaginator<'a, T> {
+    /// Parent client containing the HTTP client and authorization context.
+    client: &'a BubbleHearthClient,
+    /// Search endpoint URL, including any search filters but excluding paging parameters.
+    url: String,
+    /// Optional limit on the number of pages requested.
+    max_pages: Option<u32>,
+    /// Optional number of results per page, sent as the `_pageSize` parameter.
+    page_size: Option<u32>,
+    /// Flag indicating if the following page should be requested alongside the current page.
+    prefetch: bool,
+    /// Optional mapper applied to each search result item's data as pages are received.
+    item_mapper: Option<ItemMapper<'a, T>>,
+    /// Marker for the type of search result data yielded by the paginator.
+    data: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Debug for SearchPaginator<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchPaginator")
+            .field("url", &self.url)
+            .field("max_pages", &self.max_pages)
+            .field("page_size", &self.page_size)
+            .field("prefetch", &self.prefetch)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T> SearchPaginator<'a, T>
+where
+    T: for<'de> Deserialize<'de> + Send + 'a,
+{
+    /// Constructs a new paginator for the search endpoint URL, which should include
+    /// any search filters but exclude the `_page` and `_pageSize` parameters.
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    pub(crate) fn new(client: &'a BubbleHearthClient, url: String) -> Self {
+        Self {
+            client,
+            url,
+            max_pages: None,
+            page_size: None,
+            prefetch: false,
+            item_mapper: None,
+            data: PhantomData,
+        }
+    }
+
+    /// Constructs a new paginator for the search endpoint URL, applying the query's filters, ordering, and page size.
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    pub(crate) fn for_query<F: SearchField>(
+        client: &'a BubbleHearthClient,
+        endpoint_url: &str,
+        query: &SearchQuery<F>,
+    ) -> Self {
+        Self {
+            page_size: query.get_page_size(),
+            ..Self::new(client, query.get_search_url(endpoint_url))
+        }
+    }
+
+    /// Limits the number of pages requested, regardless of the number of available pages.
+    pub fn with_max_pages(self, max_pages: u32) -> Self {
+        Self {
+            max_pages: Some(max_pages),
+            ..self
+        }
+    }
+
+    /// Sets the number of results per page, capped by Blizzard at the search result's maximum page size.
+    pub fn with_page_size(self, page_size: u32) -> Self {
+        Self {
+            page_size: Some(page_size),
+            ..self
+        }
+    }
+
+    /// Requests the following page alongside the current page when enabled, trading
+    /// an extra in-flight request for less time waiting between pages.
+    pub fn with_prefetch(self, prefetch: bool) -> Self {
+        Self { prefetch, ..self }
+    }
+
+    /// Applies the mapper to each search result item's data as pages are received, allowing
+    /// connectors to enrich results with context unavailable in the response.
+    #[cfg(feature = "classic")]
+    pub(crate) fn with_item_mapper(self, item_mapper: impl Fn(&mut T) + Send + Sync + 'a) -> Self {
+        Self {
+            item_mapper: Some(Arc::new(item_mapper)),
+            ..self
+        }
+    }
+
+    /// Streams each page of search results in order, stopping at the last page, the page limit,
+    /// or after yielding the first error encountered.
+    pub fn pages(self) -> BoxStream<'a, BubbleHearthResult<SearchResult<T>>> {
+        let Self {
+            client,
+            url,
+            max_pages,
+            page_size,
+            prefetch,
+            item_mapper,
+            ..
+        } = self;
+
+        let fetch_page = move |page| {
+            let page_url = get_search_page_url(&url, page, page_size);
+            client.send_request_and_deserialize::<SearchResult<T>>(page_url)
+        };
+
+        stream_pages(
+            fetch_page,
+            |page: &SearchResult<T>| page.page_count,
+            max_pages,
+            if prefetch { 2 } else { 1 },
+        )
+        .map_ok(move |mut page| {
+            if let Some(item_mapper) = &item_mapper {
+                for item in page.results.iter_mut() {
+                    item_mapper(&mut item.data);
+                }
+            }
+
+            page
+        })
+        .boxed()
+    }
+
+    /// Streams each search result item across all pages in order.
+    pub fn items(self) -> BoxStream<'a, BubbleHearthResult<SearchResultItem<T>>> {
+        self.pages()
+            .map_ok(|page| stream::iter(page.results.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use std::ops::Bound;
+
+    use crate::localization::Locale;
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    use crate::search::SortOrder;
+    use crate::search::{get_search_page_url, SearchField, SearchQuery};
+
+    /// Searchable fields of a test document.
+    #[derive(Debug, Copy, Clone)]
+    enum TestField {
+        Id,
+        Name,
+    }
+
+    impl SearchField for TestField {
+        fn get_field_name(&self) -> &str {
+            match self {
+                TestField::Id => "id",
+                TestField::Name => "name",
+            }
+        }
+    }
+
+    #[test]
+    fn returns_page_url_when_no_filters_provided() {
+        // Arrange
+        let url = "https://us.api.blizzard.com/data/wow/search/realm";
+
+        // Act
+        let page_url = get_search_page_url(url, 2, None);
+
+        // Assert
+        assert_eq!(
+            page_url,
+            "https://us.api.blizzard.com/data/wow/search/realm?_page=2"
+        );
+    }
+
+    #[test]
+    fn returns_page_url_with_page_size_when_filters_provided() {
+        // Arrange
+        let url = "https://us.api.blizzard.com/data/wow/search/realm?timezone=America/New_York";
+
+        // Act
+        let page_url = get_search_page_url(url, 1, Some(100));
+
+        // Assert
+        assert_eq!(
+            page_url,
+            "https://us.api.blizzard.com/data/wow/search/realm?timezone=America/New_York&_page=1&_pageSize=100"
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    fn returns_empty_query_string_when_no_filters_provided() {
+        // Arrange
+        let query = SearchQuery::<TestField>::new();
+
+        // Act
+        let search_url = query.get_search_url("https://localhost/search");
+
+        // Assert
+        assert_eq!(query.to_query_string(), "");
+        assert_eq!(search_url, "https://localhost/search");
+    }
+
+    #[test]
+    fn returns_encoded_values_when_values_contain_reserved_characters() {
+        // Arrange
+        let query = SearchQuery::new()
+            .filter_localized(TestField::Name, Locale::French, "Ébène & Co")
+            .filter_any(TestField::Id, [1, 2, 3]);
+
+        // Act
+        let query_string = query.to_query_string();
+
+        // Assert
+        assert_eq!(
+            query_string,
+            "name.fr_FR=%C3%89b%C3%A8ne%20%26%20Co&id=1||2||3"
+        );
+    }
+
+    #[test]
+    fn returns_exclusive_and_unbounded_ranges() {
+        // Arrange
+        let query = SearchQuery::new()
+            .filter_range(TestField::Id, 1..10)
+            .filter_range(TestField::Id, ..=5);
+
+        // Act
+        let query_string = query.to_query_string();
+
+        // Assert
+        assert_eq!(query_string, "id=[1,10)&id=(,5]");
+    }
+
+    #[test]
+    fn returns_exclusive_start_with_unbounded_end_range() {
+        // Arrange
+        let query =
+            SearchQuery::new().filter_range(TestField::Id, (Bound::Excluded(1), Bound::Unbounded));
+
+        // Act
+        let query_string = query.to_query_string();
+
+        // Assert
+        assert_eq!(query_string, "id=(1,]");
+    }
+
+    #[test]
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    fn returns_search_url_appended_to_existing_parameters() {
+        // Arrange
+        let query = SearchQuery::new().filter(TestField::Id, 4372);
+
+        // Act
+        let search_url = query.get_search_url("https://localhost/search?namespace=static-us");
+
+        // Assert
+        assert_eq!(
+            search_url,
+            "https://localhost/search?namespace=static-us&id=4372"
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "classic", feature = "wow"))]
+    fn returns_page_url_with_query_page_size_and_ordering() {
+        // Arrange
+        let query = SearchQuery::new()
+            .filter(TestField::Id, 4372)
+            .order_by(TestField::Id, SortOrder::Descending)
+            .with_page_size(25);
+
+        // Act
+        let page_url = query.get_search_page_url("https://localhost/search", 3);
+
+        // Assert
+        assert_eq!(
+            page_url,
+            "https://localhost/search?id=4372&orderby=id:desc&_page=3&_pageSize=25"
+        );
+    }
+}
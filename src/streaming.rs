@@ -0,0 +1,437 @@
+//! Streaming deserialization of large documents, yielding the items of a document's top-level
+//! array one at a time as the response body arrives rather than buffering the entire body.
+//! Useful for documents spanning hundreds of megabytes, i.e. region-wide commodity auctions.
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{self, BoxStream};
+use futures::{Stream, StreamExt};
+use http::header::LAST_MODIFIED;
+use serde::de::DeserializeOwned;
+use time::format_description::well_known::Rfc2822;
+use time::OffsetDateTime;
+
+use crate::errors::{BubbleHearthError, BubbleHearthResult};
+
+/// A stream of items deserialized one at a time from an array within a document, along with
+/// the time the document was last modified.
+pub struct DocumentStream<T> {
+    /// Time the document was last modified, parsed from the `Last-Modified` response header.
+    last_modified: Option<OffsetDateTime>,
+    /// Items deserialized from the document's array as the body arrives.
+    items: BoxStream<'static, BubbleHearthResult<T>>,
+}
+
+impl<T> Debug for DocumentStream<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DocumentStream")
+            .field("last_modified", &self.last_modified)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Stream for DocumentStream<T> {
+    type Item = BubbleHearthResult<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.items.poll_next_unpin(cx)
+    }
+}
+
+impl<T: DeserializeOwned + Send + 'static> DocumentStream<T> {
+    /// Constructs a stream over the items of the top-level array field of the response's document.
+    pub(crate) fn from_response(response: reqwest::Response, field: &'static str) -> Self {
+        let last_modified = response
+            .headers()
+            .get(LAST_MODIFIED)
+            .and_then(|last_modified| last_modified.to_str().ok())
+            .and_then(parse_last_modified);
+        let chunks = stream::unfold(response, |mut response| async move {
+            match response.chunk().await {
+                Ok(Some(chunk)) => Some((Ok(chunk), response)),
+                Ok(None) => None,
+                Err(e) => Some((Err(e.into()), response)),
+            }
+        });
+
+        Self {
+            last_modified,
+            items: stream_array_items(chunks, field),
+        }
+    }
+}
+
+impl<T> DocumentStream<T> {
+    /// Gets the time the document was last modified, i.e. when an auction snapshot was taken, if available.
+    pub fn get_last_modified(&self) -> Option<OffsetDateTime> {
+        self.last_modified
+    }
+}
+
+/// Parses an HTTP date, i.e. `Tue, 20 Feb 2024 14:05:11 GMT`, into a timestamp.
+fn parse_last_modified(last_modified: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(last_modified, &Rfc2822).ok()
+}
+
+/// Streams the items of the top-level array field from the chunks of a JSON document.
+fn stream_array_items<T, S, B>(
+    chunks: S,
+    field: &'static str,
+) -> BoxStream<'static, BubbleHearthResult<T>>
+where
+    T: DeserializeOwned + Send + 'static,
+    S: Stream<Item = BubbleHearthResult<B>> + Send + 'static,
+    B: AsRef<[u8]> + Send + 'static,
+{
+    let state = (
+        chunks.boxed(),
+        ArrayScanner::new(field),
+        VecDeque::<BubbleHearthResult<T>>::new(),
+        false,
+    );
+
+    stream::unfold(
+        state,
+        |(mut chunks, mut scanner, mut items, mut done)| async move {
+            loop {
+                if let Some(item) = items.pop_front() {
+                    return Some((item, (chunks, scanner, items, done)));
+                }
+
+                if done {
+                    return None;
+                }
+
+                let (scanned, error) = match chunks.next().await {
+                    Some(Ok(chunk)) => scanner.scan(chunk.as_ref()),
+                    Some(Err(e)) => (Vec::new(), Some(e)),
+                    None => (Vec::new(), scanner.finish().err()),
+                };
+
+                items.extend(scanned.into_iter().map(Ok));
+                done = scanner.is_finished();
+
+                if let Some(e) = error {
+                    items.push_back(Err(e));
+                    done = true;
+                }
+            }
+        },
+    )
+    .boxed()
+}
+
+/// Progress of the scanner through the document.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ScanState {
+    /// Scanning the top-level object for the array field.
+    SeekingArray,
+    /// Scanning the items of the array field.
+    InArray,
+    /// The array has been closed, and the remainder of the document is ignored.
+    Finished,
+}
+
+/// An incremental JSON scanner, splitting the items of a top-level array field as bytes arrive.
+/// Only the bytes of the item currently being scanned are buffered.
+#[derive(Debug)]
+struct ArrayScanner {
+    /// Name of the top-level field containing the array.
+    field: &'static str,
+    /// Bytes received but not yet consumed.
+    buffer: Vec<u8>,
+    /// Index of the next byte to scan within the buffer.
+    position: usize,
+    /// Index within the buffer of the start of the item currently being scanned.
+    item_start: Option<usize>,
+    /// Current nesting depth of objects and arrays.
+    depth: usize,
+    /// Flag for indicating if the scanner is within a string.
+    in_string: bool,
+    /// Flag for indicating if the previous byte within a string was an escape.
+    escaped: bool,
+    /// Most recent string scanned within the top-level object, used for matching field names.
+    key: Vec<u8>,
+    /// Progress of the scanner through the document.
+    state: ScanState,
+}
+
+impl ArrayScanner {
+    /// Constructs a scanner for the top-level array field.
+    fn new(field: &'static str) -> Self {
+        Self {
+            field,
+            buffer: Vec::new(),
+            position: 0,
+            item_start: None,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            key: Vec::new(),
+            state: ScanState::SeekingArray,
+        }
+    }
+
+    /// Determines if the array has been closed.
+    fn is_finished(&self) -> bool {
+        self.state == ScanState::Finished
+    }
+
+    /// Scans the next chunk of the document, deserializing any items completed by the chunk.
+    /// Items completed before a malformed item are returned along with the error.
+    fn scan<T: DeserializeOwned>(&mut self, chunk: &[u8]) -> (Vec<T>, Option<BubbleHearthError>) {
+        let mut items = Vec::new();
+
+        if self.is_finished() {
+            return (items, None);
+        }
+
+        self.buffer.extend_from_slice(chunk);
+        let scanned = self.scan_items(&mut items);
+
+        (items, scanned.err())
+    }
+
+    /// Scans the buffered bytes, pushing each completed item until the buffer is exhausted.
+    fn scan_items<T: DeserializeOwned>(&mut self, items: &mut Vec<T>) -> BubbleHearthResult<()> {
+        while self.position < self.buffer.len() && !self.is_finished() {
+            let byte = self.buffer[self.position];
+            let array_level = self.state == ScanState::InArray && self.depth == 2;
+            let key_level = self.state == ScanState::SeekingArray && self.depth == 1;
+
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                } else if key_level {
+                    self.key.push(byte);
+                }
+
+                self.position += 1;
+                continue;
+            }
+
+            if array_level
+                && self.item_start.is_none()
+                && !byte.is_ascii_whitespace()
+                && byte != b','
+                && byte != b']'
+            {
+                self.item_start = Some(self.position);
+            }
+
+            match byte {
+                b'"' => {
+                    self.in_string = true;
+
+                    if key_level {
+                        self.key.clear();
+                    }
+                }
+                b'[' if key_level && self.key == self.field.as_bytes() => {
+                    self.state = ScanState::InArray;
+                    self.depth += 1;
+                }
+                b'{' | b'[' => self.depth += 1,
+                b',' if array_level => items.push(self.take_item()?),
+                b']' if array_level => {
+                    if self.item_start.is_some() {
+                        items.push(self.take_item()?);
+                    }
+
+                    self.state = ScanState::Finished;
+                }
+                b'}' | b']' => {
+                    self.depth = self.depth.checked_sub(1).ok_or_else(|| {
+                        BubbleHearthError::DocumentStreamFailed(
+                            "Document contains an unbalanced closing bracket.".to_string(),
+                        )
+                    })?;
+                }
+                _ => {}
+            }
+
+            self.position += 1;
+        }
+
+        self.compact();
+
+        Ok(())
+    }
+
+    /// Validates the array was found and closed once the document has been fully received.
+    fn finish(&self) -> BubbleHearthResult<()> {
+        match self.state {
+            ScanState::Finished => Ok(()),
+            ScanState::SeekingArray => Err(BubbleHearthError::DocumentStreamFailed(format!(
+                "Document does not contain an array field named {}.",
+                self.field
+            ))),
+            ScanState::InArray => Err(BubbleHearthError::DocumentStreamFailed(format!(
+                "Document ended before the {} array was closed.",
+                self.field
+            ))),
+        }
+    }
+
+    /// Deserializes the item ending at the current position.
+    fn take_item<T: DeserializeOwned>(&mut self) -> BubbleHearthResult<T> {
+        let start = self.item_start.take().ok_or_else(|| {
+            BubbleHearthError::DocumentStreamFailed(format!(
+                "The {} array contains an empty item.",
+                self.field
+            ))
+        })?;
+
+        serde_json::from_slice(&self.buffer[start..self.position])
+            .map_err(|e| BubbleHearthError::DocumentStreamFailed(e.to_string()))
+    }
+
+    /// Drops scanned bytes from the buffer, retaining only the item currently being scanned.
+    fn compact(&mut self) {
+        let consumed = self.item_start.unwrap_or(self.position);
+
+        self.buffer.drain(..consumed);
+        self.position -= consumed;
+        self.item_start = self.item_start.map(|_| 0);
+    }
+}
+
+#[cfg(test)]
+mod streaming_tests {
+    use futures::{stream, StreamExt, TryStreamExt};
+    use serde::Deserialize;
+
+    use crate::errors::{BubbleHearthError, BubbleHearthResult};
+    use crate::streaming::{parse_last_modified, stream_array_items};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Listing {
+        id: u64,
+        name: String,
+    }
+
+    /// Splits the document into chunks of the given size, simulating a body arriving over the network.
+    async fn collect_listings(
+        document: &str,
+        chunk_size: usize,
+    ) -> BubbleHearthResult<Vec<Listing>> {
+        let chunks: Vec<BubbleHearthResult<Vec<u8>>> = document
+            .as_bytes()
+            .chunks(chunk_size)
+            .map(|chunk| Ok(chunk.to_vec()))
+            .collect();
+
+        stream_array_items(stream::iter(chunks), "auctions")
+            .try_collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn returns_items_split_across_chunks() {
+        // Arrange
+        let document = r#"
+            {
+              "_links": { "self": { "href": "https://us.api.blizzard.com/data/wow/auctions/commodities" } },
+              "name": "auctions",
+              "nested": { "auctions": [{ "id": 0, "name": "nested" }] },
+              "auctions": [
+                { "id": 1, "name": "Brackets ] and } in \"strings\"" },
+                { "id": 2, "name": "Escaped \\" },
+                { "id": 3, "name": "Nested", "extra": [[1, 2], { "a": [] }] }
+              ],
+              "trailing": [{ "id": 4 }]
+            }
+        "#;
+
+        // Act
+        let listings = collect_listings(document, 7).await.unwrap();
+        let single_chunk_listings = collect_listings(document, document.len()).await.unwrap();
+
+        // Assert
+        assert_eq!(listings.len(), 3);
+        assert_eq!(listings[0].name, r#"Brackets ] and } in "strings""#);
+        assert_eq!(listings[1].name, r#"Escaped \"#);
+        assert_eq!(listings[2].id, 3);
+        assert_eq!(listings, single_chunk_listings);
+    }
+
+    #[tokio::test]
+    async fn returns_no_items_when_array_empty() {
+        // Arrange
+        let document = r#"{ "auctions": [ ] }"#;
+
+        // Act
+        let listings = collect_listings(document, 3).await.unwrap();
+
+        // Assert
+        assert!(listings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_array_missing_or_unclosed() {
+        // Arrange
+        let missing = r#"{ "commodities": [{ "id": 1, "name": "Linen Cloth" }] }"#;
+        let unclosed = r#"{ "auctions": [{ "id": 1, "name": "Linen Cloth" }, "#;
+
+        // Act
+        let missing_error = collect_listings(missing, 4).await.unwrap_err();
+        let unclosed_error = collect_listings(unclosed, 4).await.unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            missing_error,
+            BubbleHearthError::DocumentStreamFailed(_)
+        ));
+        assert!(matches!(
+            unclosed_error,
+            BubbleHearthError::DocumentStreamFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn returns_items_before_error_when_item_malformed() {
+        // Arrange
+        let document = r#"{ "auctions": [{ "id": 1, "name": "Linen Cloth" }, { "id": "two" }, { "id": 3, "name": "Wool Cloth" }] }"#;
+        let chunks: Vec<BubbleHearthResult<Vec<u8>>> = vec![Ok(document.as_bytes().to_vec())];
+
+        // Act
+        let results: Vec<BubbleHearthResult<Listing>> =
+            stream_array_items(stream::iter(chunks), "auctions")
+                .collect()
+                .await;
+
+        // Assert
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &Listing {
+                id: 1,
+                name: "Linen Cloth".to_string()
+            }
+        );
+        assert!(matches!(
+            results[1],
+            Err(BubbleHearthError::DocumentStreamFailed(_))
+        ));
+    }
+
+    #[test]
+    fn returns_last_modified_from_http_date() {
+        // Arrange
+        let last_modified = "Tue, 20 Feb 2024 14:05:11 GMT";
+
+        // Act
+        let parsed = parse_last_modified(last_modified).unwrap();
+
+        // Assert
+        assert_eq!(parsed.unix_timestamp(), 1708437911);
+        assert!(parse_last_modified("yesterday").is_none());
+    }
+}
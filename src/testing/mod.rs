@@ -0,0 +1,405 @@
+//! An offline testing kit for code built on top of the client. Starts an in-process fake
+//! Blizzard API, complete with an OAuth token endpoint and canned responses for realms,
+//! connected realms, regions, and cards, so tests can run without client credentials or
+//! network access.
+//!
+//! ```rust
+//! use bubblehearth::localization::Locale;
+//! use bubblehearth::regionality::AccountRegion;
+//! use bubblehearth::testing::FakeBlizzardServer;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let server = FakeBlizzardServer::start().await;
+//!
+//!     // Custom fixtures can be registered for any path, taking precedence over the canned responses
+//!     server.register_fixture(
+//!         "/data/wow/region/42",
+//!         r#"{ "_links": { "self": { "href": "" } }, "id": 42, "name": "Europe", "tag": "EU" }"#,
+//!     );
+//!
+//!     let client = server.client(AccountRegion::US, Locale::EnglishUS);
+//!     let realm = client.classic().get_realm("atiesh").await.unwrap();
+//!     let region = client.classic().get_region(42).await.unwrap();
+//!
+//!     assert!(realm.is_some());
+//!     assert_eq!(region.unwrap().tag, Some("EU".to_string()));
+//! }
+//! ```
+
+use std::convert::Infallible;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::body::StreamBody;
+use axum::extract::State;
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use futures::stream::{self, StreamExt};
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use http::{HeaderMap, Method, StatusCode, Uri};
+use tokio::sync::oneshot;
+
+use crate::builder::BubbleHearthClientBuilder;
+use crate::client::BubbleHearthClient;
+use crate::localization::Locale;
+use crate::regionality::AccountRegion;
+
+/// Access token handed out by the fake token endpoint, and required on all fake API requests
+/// outside of the public assets path.
+pub const FAKE_ACCESS_TOKEN: &str = "bubblehearth-fake-access-token";
+
+/// Client ID used by clients constructed against the fake server.
+pub const FAKE_CLIENT_ID: &str = "bubblehearth-fake-client-id";
+
+/// Client secret used by clients constructed against the fake server.
+pub const FAKE_CLIENT_SECRET: &str = "bubblehearth-fake-client-secret";
+
+/// Path of the fake OAuth token endpoint.
+pub const FAKE_TOKEN_PATH: &str = "/oauth/token";
+
+/// Path prefix of public assets, served without authentication like Blizzard's render CDN.
+pub const FAKE_ASSETS_PATH: &str = "/assets/";
+
+/// Canned fixtures registered on server startup, keyed by the request path and query they match.
+const DEFAULT_FIXTURES: [(&str, &str); 9] = [
+    (
+        "/data/wow/realm/index",
+        include_str!("fixtures/realm_index.json"),
+    ),
+    (
+        "/data/wow/realm/atiesh",
+        include_str!("fixtures/realm_atiesh.json"),
+    ),
+    (
+        "/data/wow/search/realm",
+        include_str!("fixtures/realm_search.json"),
+    ),
+    (
+        "/data/wow/connected-realm/index",
+        include_str!("fixtures/connected_realm_index.json"),
+    ),
+    (
+        "/data/wow/connected-realm/4372",
+        include_str!("fixtures/connected_realm_4372.json"),
+    ),
+    (
+        "/data/wow/search/connected-realm",
+        include_str!("fixtures/connected_realm_search.json"),
+    ),
+    (
+        "/data/wow/region/index",
+        include_str!("fixtures/region_index.json"),
+    ),
+    (
+        "/data/wow/region/41",
+        include_str!("fixtures/region_41.json"),
+    ),
+    (
+        "/hearthstone/cards",
+        include_str!("fixtures/hearthstone_cards.json"),
+    ),
+];
+
+/// A canned response served for any request matching the fixture's path and query parameters.
+#[derive(Debug, Clone)]
+struct Fixture {
+    /// Request path the fixture is served for.
+    path: String,
+    /// Query parameters required to be present on the request for the fixture to match.
+    query: Vec<(String, String)>,
+    /// Status code of the response.
+    status: StatusCode,
+    /// Additional headers sent along with the response, i.e. `Last-Modified`.
+    headers: HeaderMap,
+    /// Raw JSON body of the response.
+    body: String,
+    /// Delay before sending each line of the body, streaming the body in chunks when set.
+    chunk_delay: Option<Duration>,
+}
+
+/// A request received by the fake server, useful for asserting on what the client sent.
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    /// HTTP method of the request.
+    pub method: Method,
+    /// Path of the request, without the query string.
+    pub path: String,
+    /// Raw query string of the request, if any.
+    pub query: Option<String>,
+    /// Headers sent along with the request.
+    pub headers: HeaderMap,
+}
+
+/// Shared state between the server handle and the running fake server.
+#[derive(Debug, Default)]
+struct FakeServerState {
+    /// Registered fixtures, with later registrations taking precedence.
+    fixtures: Vec<Fixture>,
+    /// Requests received by the server, in the order they were received.
+    requests: Vec<ReceivedRequest>,
+}
+
+/// An in-process fake Blizzard API, serving registered fixtures until dropped.
+#[derive(Debug)]
+pub struct FakeBlizzardServer {
+    /// Local address the fake server is listening on.
+    address: SocketAddr,
+    /// Fixtures and received requests shared with the running server.
+    state: Arc<Mutex<FakeServerState>>,
+    /// Shutdown signal for the running server, sent when the handle is dropped.
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl FakeBlizzardServer {
+    /// Starts a fake server on a random local port, serving the canned realm, connected realm, region, and card fixtures.
+    /// Requires a running tokio runtime, and panics if the server is unable to bind to a local port.
+    pub async fn start() -> Self {
+        let server = Self::start_without_fixtures().await;
+
+        for (path, body) in DEFAULT_FIXTURES {
+            server.register_fixture(path, body);
+        }
+
+        server
+    }
+
+    /// Starts a fake server on a random local port without any fixtures registered,
+    /// responding with a not found status until fixtures are registered.
+    pub async fn start_without_fixtures() -> Self {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").expect("fake server unable to bind to a local port");
+        let address = listener
+            .local_addr()
+            .expect("fake server local address not found");
+        let state = Arc::new(Mutex::new(FakeServerState::default()));
+        let (shutdown, shutdown_signal) = oneshot::channel::<()>();
+
+        let router = Router::new()
+            .fallback(handle_request)
+            .with_state(state.clone());
+        let server = axum::Server::from_tcp(listener)
+            .expect("fake server unable to listen on the bound port")
+            .serve(router.into_make_service())
+            .with_graceful_shutdown(async {
+                shutdown_signal.await.ok();
+            });
+
+        tokio::spawn(server);
+
+        Self {
+            address,
+            state,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// Base URL of the fake server, used as the API gateway for clients.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// URL of the fake OAuth token endpoint.
+    pub fn token_url(&self) -> String {
+        format!("{}{}", self.url(), FAKE_TOKEN_PATH)
+    }
+
+    /// Registers a JSON fixture returned with a successful status for requests matching the path.
+    /// Paths may include a query string, i.e. `/data/wow/search/realm?timezone=America/New_York`,
+    /// in which case the fixture is only served when all of the query parameters are present.
+    pub fn register_fixture(&self, path: &str, body: impl Into<String>) {
+        self.register_fixture_with_status(path, StatusCode::OK, body);
+    }
+
+    /// Registers a JSON fixture returned with the given status for requests matching the path.
+    pub fn register_fixture_with_status(
+        &self,
+        path: &str,
+        status: StatusCode,
+        body: impl Into<String>,
+    ) {
+        self.register_fixture_with_headers(path, status, HeaderMap::new(), body);
+    }
+
+    /// Registers a JSON fixture returned with the given status and additional response headers,
+    /// i.e. a `Last-Modified` header, for requests matching the path.
+    pub fn register_fixture_with_headers(
+        &self,
+        path: &str,
+        status: StatusCode,
+        headers: HeaderMap,
+        body: impl Into<String>,
+    ) {
+        let (path, query) = split_path_and_query(path);
+        let fixture = Fixture {
+            path,
+            query,
+            status,
+            headers,
+            body: body.into(),
+            chunk_delay: None,
+        };
+
+        self.state.lock().unwrap().fixtures.push(fixture);
+    }
+
+    /// Registers a JSON fixture returned with a successful status and additional response headers,
+    /// streamed one line at a time with a delay before each line to simulate slowly receiving a
+    /// large body, i.e. an auction snapshot.
+    pub fn register_slow_fixture(
+        &self,
+        path: &str,
+        headers: HeaderMap,
+        body: impl Into<String>,
+        chunk_delay: Duration,
+    ) {
+        let (path, query) = split_path_and_query(path);
+        let fixture = Fixture {
+            path,
+            query,
+            status: StatusCode::OK,
+            headers,
+            body: body.into(),
+            chunk_delay: Some(chunk_delay),
+        };
+
+        self.state.lock().unwrap().fixtures.push(fixture);
+    }
+
+    /// Returns all requests received by the server so far, including token requests.
+    pub fn received_requests(&self) -> Vec<ReceivedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Constructs a client builder preconfigured with fake credentials and pointed at the fake server.
+    pub fn client_builder(&self) -> BubbleHearthClientBuilder {
+        BubbleHearthClientBuilder::new()
+            .with_client_id(FAKE_CLIENT_ID.to_string())
+            .with_client_secret(FAKE_CLIENT_SECRET.to_string())
+            .with_base_url(self.url())
+            .with_token_url(self.token_url())
+    }
+
+    /// Constructs a client pointed at the fake server for the given region and locale.
+    pub fn client(&self, region: AccountRegion, locale: Locale) -> BubbleHearthClient {
+        self.client_builder()
+            .with_region(region)
+            .with_locale(locale)
+            .build()
+            .expect("fake client options are always valid")
+    }
+}
+
+impl Drop for FakeBlizzardServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
+
+impl FakeServerState {
+    /// Finds the most specific fixture for the request, favoring the most recently registered on ties.
+    fn find_fixture(&self, path: &str, query: &[(String, String)]) -> Option<&Fixture> {
+        self.fixtures
+            .iter()
+            .filter(|fixture| fixture.path == path)
+            .filter(|fixture| fixture.query.iter().all(|pair| query.contains(pair)))
+            .max_by_key(|fixture| fixture.query.len())
+    }
+}
+
+/// Splits a path into its path and decoded query parameters.
+fn split_path_and_query(path_and_query: &str) -> (String, Vec<(String, String)>) {
+    match reqwest::Url::parse(&format!("http://localhost{}", path_and_query)) {
+        Ok(url) => (
+            url.path().to_string(),
+            url.query_pairs()
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect(),
+        ),
+        Err(_) => (path_and_query.to_string(), Vec::new()),
+    }
+}
+
+/// Handles all requests to the fake server, issuing tokens and serving registered fixtures.
+async fn handle_request(
+    State(state): State<Arc<Mutex<FakeServerState>>>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+) -> Response {
+    let mut state = state.lock().unwrap();
+
+    state.requests.push(ReceivedRequest {
+        method: method.clone(),
+        path: uri.path().to_string(),
+        query: uri.query().map(str::to_string),
+        headers: headers.clone(),
+    });
+
+    if uri.path() == FAKE_TOKEN_PATH && method == Method::POST {
+        let token = format!(
+            r#"{{ "access_token": "{}", "token_type": "bearer", "expires_in": 86399, "sub": "{}" }}"#,
+            FAKE_ACCESS_TOKEN, FAKE_CLIENT_ID
+        );
+
+        return json_response(StatusCode::OK, token);
+    }
+
+    let expected_authorization = format!("Bearer {}", FAKE_ACCESS_TOKEN);
+    let authorized = headers
+        .get(AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .is_some_and(|authorization| authorization == expected_authorization);
+
+    if !authorized && !uri.path().starts_with(FAKE_ASSETS_PATH) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let (path, query) = split_path_and_query(
+        uri.path_and_query()
+            .map(|path_and_query| path_and_query.as_str())
+            .unwrap_or_else(|| uri.path()),
+    );
+
+    match state.find_fixture(&path, &query) {
+        Some(Fixture {
+            status,
+            headers,
+            body,
+            chunk_delay: Some(chunk_delay),
+            ..
+        }) => {
+            let chunks: Vec<String> = body.split_inclusive('\n').map(str::to_string).collect();
+            let chunk_delay = *chunk_delay;
+            let body = stream::iter(chunks).then(move |chunk| async move {
+                tokio::time::sleep(chunk_delay).await;
+                Ok::<_, Infallible>(chunk)
+            });
+
+            (
+                *status,
+                headers.clone(),
+                [(CONTENT_TYPE, "application/json")],
+                StreamBody::new(body),
+            )
+                .into_response()
+        }
+        Some(fixture) => (
+            fixture.headers.clone(),
+            json_response(fixture.status, fixture.body.clone()),
+        )
+            .into_response(),
+        None => json_response(
+            StatusCode::NOT_FOUND,
+            r#"{ "code": 404, "type": "BLZWEBAPI00000404", "detail": "Not Found" }"#.to_string(),
+        ),
+    }
+}
+
+/// Constructs a JSON response with the given status.
+fn json_response(status: StatusCode, body: String) -> Response {
+    (status, [(CONTENT_TYPE, "application/json")], body).into_response()
+}
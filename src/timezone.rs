@@ -1,5 +1,7 @@
 //! Unix-based timezones for search queries.
 
+use std::fmt::{Display, Formatter};
+
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
@@ -26,6 +28,13 @@ impl From<Timezone> for String {
     }
 }
 
+impl Display for Timezone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let zone: String = (*self).into();
+        write!(f, "{}", zone)
+    }
+}
+
 impl From<String> for Timezone {
     fn from(value: String) -> Self {
         match value.as_str() {
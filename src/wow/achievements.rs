@@ -0,0 +1,285 @@
+//! Achievement data and APIs for World of Warcraft, including achievement categories, criteria,
+//! media, and searching.
+
+use serde::{Deserialize, Serialize};
+
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::factions::FactionDetails;
+use crate::localization::StringOrStructLocale;
+use crate::media::Media;
+use crate::search::{SearchField, SearchPaginator, SearchQuery, SearchResult};
+use crate::wow::WorldOfWarcraftConnector;
+
+/// Response structure from the achievement categories index endpoint, listing all categories.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct AchievementCategoriesIndex {
+    /// Top-level document link to follow for a selected achievement category ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of all achievement categories, including subcategories.
+    #[serde(default)]
+    pub categories: Vec<DocumentReference<AchievementCategory>>,
+    /// List of top-level character achievement categories.
+    #[serde(default)]
+    pub root_categories: Vec<DocumentReference<AchievementCategory>>,
+    /// List of top-level guild achievement categories.
+    #[serde(default)]
+    pub guild_categories: Vec<DocumentReference<AchievementCategory>>,
+}
+
+/// Achievement category metadata, i.e. Quests, Dungeons & Raids, Feats of Strength, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementCategory {
+    /// Top-level document link to follow of the selected achievement category ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric achievement category ID.
+    pub id: u64,
+    /// Localized achievement category name.
+    pub name: StringOrStructLocale,
+    /// References to the achievements within the category.
+    #[serde(default)]
+    pub achievements: Vec<DocumentReference<Achievement>>,
+    /// References to the subcategories of the category.
+    #[serde(default)]
+    pub subcategories: Vec<DocumentReference<AchievementCategory>>,
+    /// Flag for indicating if the category contains guild achievements.
+    pub is_guild_category: Option<bool>,
+    /// Number of achievements and points available within the category, per faction.
+    pub aggregates_by_faction: Option<AchievementFactionAggregates>,
+    /// Display order of the category within its parent.
+    pub display_order: Option<u32>,
+    /// Reference to the parent category, if the category is a subcategory.
+    pub parent_category: Option<DocumentReference<AchievementCategory>>,
+}
+
+/// Number of achievements and points available within a category, per faction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AchievementFactionAggregates {
+    /// Achievements and points available to the Alliance.
+    pub alliance: AchievementAggregate,
+    /// Achievements and points available to the Horde.
+    pub horde: AchievementAggregate,
+}
+
+/// Number of achievements and total points available.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AchievementAggregate {
+    /// Number of achievements.
+    pub quantity: u32,
+    /// Total achievement points.
+    pub points: u32,
+}
+
+/// Response structure from the achievements index endpoint, listing all achievements.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct AchievementsIndex {
+    /// Top-level document link to follow for a selected achievement ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available achievements.
+    #[serde(default)]
+    pub achievements: Vec<DocumentReference<Achievement>>,
+}
+
+/// Achievement metadata, including its criteria and rewards.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Achievement {
+    /// Top-level document link to follow of the selected achievement ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric achievement ID.
+    pub id: u64,
+    /// Reference to the achievement's category.
+    pub category: Option<DocumentReference<AchievementCategory>>,
+    /// Localized achievement name.
+    pub name: StringOrStructLocale,
+    /// Localized achievement description.
+    pub description: Option<StringOrStructLocale>,
+    /// Achievement points awarded for completing the achievement.
+    pub points: Option<u32>,
+    /// Flag for indicating if the achievement is shared across all characters of the account.
+    pub is_account_wide: Option<bool>,
+    /// Criteria required to complete the achievement.
+    pub criteria: Option<AchievementCriteria>,
+    /// Reference to the achievement that must be completed before this achievement.
+    pub prerequisite_achievement: Option<DocumentReference<Achievement>>,
+    /// Reference to the next achievement in the series, if any.
+    pub next_achievement: Option<DocumentReference<Achievement>>,
+    /// Reference to the achievement's media.
+    pub media: Option<DocumentReference<Media>>,
+    /// Display order of the achievement within its category.
+    pub display_order: Option<u32>,
+    /// Localized description of the reward for completing the achievement, i.e. a title or mount.
+    pub reward_description: Option<StringOrStructLocale>,
+    /// Reference to the item rewarded for completing the achievement, if any.
+    pub reward_item: Option<DocumentReference>,
+    /// Requirements to earn the achievement, i.e. a faction.
+    pub requirements: Option<AchievementRequirements>,
+}
+
+/// Criteria required to complete an achievement, potentially composed of child criteria.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementCriteria {
+    /// Numeric criteria ID.
+    pub id: u64,
+    /// Localized criteria description.
+    pub description: Option<StringOrStructLocale>,
+    /// Amount required to fulfill the criteria.
+    pub amount: Option<f64>,
+    /// Operator combining the child criteria, i.e. all or any.
+    pub operator: Option<AchievementCriteriaOperator>,
+    /// Child criteria combined by the operator.
+    #[serde(default)]
+    pub child_criteria: Vec<AchievementCriteria>,
+}
+
+/// Operator combining the child criteria of an achievement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementCriteriaOperator {
+    /// Operator type, i.e. AND or COMPLETE_AT_LEAST.
+    #[serde(rename = "type")]
+    pub operator_type: String,
+    /// Localized operator name.
+    pub name: Option<StringOrStructLocale>,
+}
+
+/// Requirements to earn an achievement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementRequirements {
+    /// Faction required to earn the achievement.
+    pub faction: Option<FactionDetails>,
+}
+
+/// Searchable fields of achievement documents. Localized fields, i.e. the name, should be filtered on with a locale.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AchievementSearchField {
+    /// Numeric achievement ID.
+    Id,
+    /// Localized achievement name.
+    Name,
+    /// Numeric ID of the achievement's category.
+    CategoryId,
+    /// Achievement points awarded for completing the achievement.
+    Points,
+    /// Flag for indicating if the achievement is shared across all characters of the account.
+    IsAccountWide,
+}
+
+impl SearchField for AchievementSearchField {
+    fn get_field_name(&self) -> &str {
+        match self {
+            AchievementSearchField::Id => "id",
+            AchievementSearchField::Name => "name",
+            AchievementSearchField::CategoryId => "category.id",
+            AchievementSearchField::Points => "points",
+            AchievementSearchField::IsAccountWide => "is_account_wide",
+        }
+    }
+}
+
+impl<'a> WorldOfWarcraftConnector<'a> {
+    /// Retrieves an index of all achievement categories.
+    pub async fn get_achievement_categories(
+        &self,
+    ) -> BubbleHearthResult<AchievementCategoriesIndex> {
+        let url = self.get_static_data_url("achievement-category/index");
+
+        let categories = self
+            .client
+            .send_request_and_deserialize::<AchievementCategoriesIndex>(url)
+            .await?;
+
+        Ok(categories)
+    }
+
+    /// Retrieves an achievement category, including its achievements and subcategories.
+    pub async fn get_achievement_category(
+        &self,
+        category_id: u32,
+    ) -> BubbleHearthResult<Option<AchievementCategory>> {
+        let url = self.get_static_data_url(&format!("achievement-category/{}", category_id));
+
+        let category = self
+            .client
+            .send_request_and_optionally_deserialize::<AchievementCategory>(url)
+            .await?;
+
+        Ok(category)
+    }
+
+    /// Retrieves an index of all achievements.
+    pub async fn get_achievements(&self) -> BubbleHearthResult<AchievementsIndex> {
+        let url = self.get_static_data_url("achievement/index");
+
+        let achievements = self
+            .client
+            .send_request_and_deserialize::<AchievementsIndex>(url)
+            .await?;
+
+        Ok(achievements)
+    }
+
+    /// Retrieves an achievement, including its criteria and rewards.
+    pub async fn get_achievement(
+        &self,
+        achievement_id: u32,
+    ) -> BubbleHearthResult<Option<Achievement>> {
+        let url = self.get_static_data_url(&format!("achievement/{}", achievement_id));
+
+        let achievement = self
+            .client
+            .send_request_and_optionally_deserialize::<Achievement>(url)
+            .await?;
+
+        Ok(achievement)
+    }
+
+    /// Retrieves the media assets of an achievement, i.e. its icon.
+    pub async fn get_achievement_media(
+        &self,
+        achievement_id: u32,
+    ) -> BubbleHearthResult<Option<Media>> {
+        let url = self.get_static_data_url(&format!("media/achievement/{}", achievement_id));
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<Media>(url)
+            .await?;
+
+        Ok(media)
+    }
+
+    /// Searches for achievements matching the query, returning the requested page or the first page when omitted.
+    pub async fn search_achievements(
+        &self,
+        query: &SearchQuery<AchievementSearchField>,
+        page: Option<u32>,
+    ) -> BubbleHearthResult<SearchResult<Achievement>> {
+        let url = query.get_search_page_url(
+            &self.get_static_search_url("achievement"),
+            page.unwrap_or(1),
+        );
+
+        let search_result = self
+            .client
+            .send_request_and_deserialize::<SearchResult<Achievement>>(url)
+            .await?;
+
+        Ok(search_result)
+    }
+
+    /// Constructs a paginator over all achievement search pages matching the query.
+    pub fn search_achievements_paginator(
+        &self,
+        query: &SearchQuery<AchievementSearchField>,
+    ) -> SearchPaginator<'a, Achievement> {
+        SearchPaginator::for_query(
+            self.client,
+            &self.get_static_search_url("achievement"),
+            query,
+        )
+    }
+}
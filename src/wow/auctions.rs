@@ -0,0 +1,144 @@
+//! Auction house data and APIs for World of Warcraft, listing the auctions of each connected
+//! realm along with the region-wide commodities auction house. Auction snapshots can span
+//! hundreds of megabytes, so listings are streamed one at a time rather than buffered.
+//!
+//! ```rust
+//! use futures::TryStreamExt;
+//! use bubblehearth::localization::Locale;
+//! use bubblehearth::regionality::AccountRegion;
+//! use bubblehearth::testing::FakeBlizzardServer;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let server = FakeBlizzardServer::start_without_fixtures().await;
+//!     server.register_fixture(
+//!         "/data/wow/auctions/commodities",
+//!         r#"{ "auctions": [{ "id": 1, "item": { "id": 2589 }, "quantity": 20, "unit_price": 100, "time_left": "LONG" }] }"#,
+//!     );
+//!     let client = server.client(AccountRegion::US, Locale::EnglishUS);
+//!
+//!     let commodities = client.wow().get_commodities().await.unwrap();
+//!     let listings: Vec<_> = commodities.try_collect().await.unwrap();
+//!
+//!     assert_eq!(listings[0].item.id, 2589);
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::auctions::TimeLeft;
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::errors::BubbleHearthResult;
+use crate::streaming::DocumentStream;
+use crate::wow::WorldOfWarcraftConnector;
+
+/// Name of the field containing the listings of auction and commodity documents.
+const AUCTIONS_FIELD: &str = "auctions";
+
+/// A single auction listed on a connected realm's auction house.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Auction {
+    /// Numeric auction ID.
+    pub id: u64,
+    /// Item listed in the auction.
+    pub item: AuctionItem,
+    /// Current bid on the auction in copper, if bidding is allowed.
+    pub bid: Option<u64>,
+    /// Buyout price of the auction in copper, if a buyout was set.
+    pub buyout: Option<u64>,
+    /// Price per item in copper, listed in place of a buyout for stackable items.
+    pub unit_price: Option<u64>,
+    /// Number of items listed in the auction.
+    pub quantity: u32,
+    /// Remaining duration of the auction.
+    pub time_left: TimeLeft,
+}
+
+/// Item listed in an auction, including the bonuses and modifiers distinguishing it from other copies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuctionItem {
+    /// Numeric item ID.
+    pub id: u32,
+    /// Context the item was created in, i.e. a dungeon or raid difficulty.
+    pub context: Option<u32>,
+    /// IDs of the bonuses applied to the item, i.e. item level upgrades or sockets.
+    #[serde(default)]
+    pub bonus_lists: Vec<u32>,
+    /// Modifiers applied to the item, i.e. the level of the character who looted it.
+    #[serde(default)]
+    pub modifiers: Vec<AuctionItemModifier>,
+    /// Breed ID of the listed battle pet, if the item is a caged pet.
+    pub pet_breed_id: Option<u32>,
+    /// Level of the listed battle pet, if the item is a caged pet.
+    pub pet_level: Option<u32>,
+    /// Quality ID of the listed battle pet, if the item is a caged pet.
+    pub pet_quality_id: Option<u32>,
+    /// Species ID of the listed battle pet, if the item is a caged pet.
+    pub pet_species_id: Option<u32>,
+}
+
+/// Modifier applied to an auctioned item.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuctionItemModifier {
+    /// Numeric modifier type.
+    #[serde(rename = "type")]
+    pub modifier_type: u32,
+    /// Value of the modifier.
+    pub value: u64,
+}
+
+/// A single commodity listed on the region-wide commodities auction house, i.e. reagents and consumables.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Commodity {
+    /// Numeric auction ID.
+    pub id: u64,
+    /// Commodity listed in the auction.
+    pub item: CommodityItem,
+    /// Number of items listed in the auction.
+    pub quantity: u32,
+    /// Price per item in copper.
+    pub unit_price: u64,
+    /// Remaining duration of the auction.
+    pub time_left: TimeLeft,
+}
+
+/// Commodity listed in an auction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommodityItem {
+    /// Numeric item ID.
+    pub id: u32,
+}
+
+impl<'a> WorldOfWarcraftConnector<'a> {
+    /// Streams all auctions listed on the connected realm's auction house, excluding commodities.
+    /// Auctions are deserialized one at a time as the snapshot is received, and the time the
+    /// snapshot was taken is available from the stream.
+    pub async fn get_auctions(
+        &self,
+        connected_realm_id: u64,
+    ) -> BubbleHearthResult<Option<DocumentStream<Auction>>> {
+        let url =
+            self.get_dynamic_data_url(&format!("connected-realm/{}/auctions", connected_realm_id));
+
+        let auctions = self
+            .client
+            .send_request_and_optionally_stream::<Auction>(url, AUCTIONS_FIELD)
+            .await?;
+
+        Ok(auctions)
+    }
+
+    /// Streams all commodities listed on the region-wide commodities auction house. Commodities
+    /// are deserialized one at a time as the snapshot is received, and the time the snapshot
+    /// was taken is available from the stream.
+    pub async fn get_commodities(&self) -> BubbleHearthResult<DocumentStream<Commodity>> {
+        let url = self.get_dynamic_data_url("auctions/commodities");
+
+        let commodities = self
+            .client
+            .send_request_and_stream::<Commodity>(url, AUCTIONS_FIELD)
+            .await?;
+
+        Ok(commodities)
+    }
+}
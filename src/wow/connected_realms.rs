@@ -0,0 +1,67 @@
+//! Connected realm data and APIs for World of Warcraft, grouping realms with their shared
+//! status, population, and queue.
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::connected_realms::{
+    ConnectedRealmPopulation, ConnectedRealmStatus, RealmPopulation, RealmStatus,
+};
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentKey, Links};
+use crate::errors::BubbleHearthResult;
+use crate::wow::realms::Realm;
+use crate::wow::WorldOfWarcraftConnector;
+
+/// A group of realms sharing a status, population, and queue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectedRealm {
+    /// Top-level document link to follow of the selected connected realm ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric connected realm ID.
+    pub id: u64,
+    /// Flag for indicating if the connected realms currently have a login queue.
+    pub has_queue: Option<bool>,
+    /// Current status of the connected realms, i.e. up or down.
+    pub status: Option<ConnectedRealmStatus>,
+    /// Current population of the connected realms, i.e. low, high, full, etc.
+    pub population: Option<ConnectedRealmPopulation>,
+    /// Realms belonging to the connected realm.
+    #[serde(default)]
+    pub realms: Vec<Realm>,
+    /// Document key of the connected realm's auctions, streamed with the connector's auction APIs.
+    pub auctions: Option<DocumentKey>,
+}
+
+impl ConnectedRealm {
+    /// Determines if the connected realms are reported as available for login.
+    pub fn is_up(&self) -> bool {
+        self.status
+            .as_ref()
+            .is_some_and(|status| status.status_type == RealmStatus::Up)
+    }
+
+    /// Determines if the connected realms are reported as being at their population limit.
+    pub fn is_full(&self) -> bool {
+        self.population
+            .as_ref()
+            .is_some_and(|population| population.population_type == RealmPopulation::Full)
+    }
+}
+
+impl<'a> WorldOfWarcraftConnector<'a> {
+    /// Retrieves a connected realm's status, population, and member realms based on the connected realm ID.
+    pub async fn get_connected_realm(
+        &self,
+        connected_realm_id: u64,
+    ) -> BubbleHearthResult<Option<ConnectedRealm>> {
+        let url = self.get_dynamic_data_url(&format!("connected-realm/{}", connected_realm_id));
+
+        let connected_realm = self
+            .client
+            .send_request_and_optionally_deserialize::<ConnectedRealm>(url)
+            .await?;
+
+        Ok(connected_realm)
+    }
+}
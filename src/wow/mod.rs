@@ -0,0 +1,60 @@
+//! Connectors for retail World of Warcraft Game Data APIs. Retail game data is served from the
+//! `static-{region}` namespaces for data that only changes with patches, and the
+//! `dynamic-{region}` namespaces for data that changes outside of patches.
+//!
+//! ```rust
+//! use bubblehearth::localization::Locale;
+//! use bubblehearth::regionality::AccountRegion;
+//! use bubblehearth::testing::FakeBlizzardServer;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let server = FakeBlizzardServer::start_without_fixtures().await;
+//!     let client = server.client(AccountRegion::US, Locale::EnglishUS);
+//!
+//!     let achievement = client.wow().get_achievement(6).await.unwrap();
+//!     let achievement_request = server.received_requests().pop().unwrap();
+//!
+//!     assert!(achievement.is_none());
+//!     assert_eq!(
+//!         achievement_request.query,
+//!         Some("namespace=static-us&locale=en_US".to_string())
+//!     );
+//! }
+//! ```
+
+use crate::client::BubbleHearthClient;
+use crate::connectors::{ClientConnector, WorldOfWarcraftUrls};
+
+pub mod achievements;
+pub mod auctions;
+pub mod connected_realms;
+pub mod mythic_keystone;
+pub mod mythic_raid;
+pub mod realms;
+
+/// A client for retail World of Warcraft, utilizing the base client authentication.
+#[derive(Debug)]
+pub struct WorldOfWarcraftConnector<'a> {
+    /// Parent client containing the HTTP client and authorization context.
+    client: &'a BubbleHearthClient,
+}
+
+impl<'a> ClientConnector<'a> for WorldOfWarcraftConnector<'a> {
+    /// Constructs a new WoW connector composed of the base client for querying and authorization.
+    fn new_connector(client: &'a BubbleHearthClient) -> Self {
+        Self { client }
+    }
+}
+
+impl<'a> WorldOfWarcraftUrls for WorldOfWarcraftConnector<'a> {
+    /// Gets the parent client of the connector.
+    fn get_client(&self) -> &BubbleHearthClient {
+        self.client
+    }
+
+    /// Retail namespaces carry no segment, i.e. `static-us`.
+    fn get_namespace_segment(&self) -> Option<&str> {
+        None
+    }
+}
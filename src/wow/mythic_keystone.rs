@@ -0,0 +1,469 @@
+//! Mythic Keystone data and APIs for World of Warcraft, including dungeons, weekly periods,
+//! seasons, affixes, and the per-connected-realm leaderboards of each dungeon and period.
+
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{get_timestamp, DocumentKey, DocumentReference, Links};
+use crate::errors::BubbleHearthResult;
+use crate::factions::FactionDetails;
+use crate::localization::StringOrStructLocale;
+use crate::media::Media;
+use crate::wow::realms::RealmReference;
+use crate::wow::WorldOfWarcraftConnector;
+
+/// Response structure from the keystone affixes index endpoint, listing all affixes.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct KeystoneAffixesIndex {
+    /// Top-level document link to follow for a selected affix ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available keystone affixes.
+    #[serde(default)]
+    pub affixes: Vec<DocumentReference<KeystoneAffix>>,
+}
+
+/// Keystone affix metadata, i.e. Fortified, Tyrannical, Bolstering, etc.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeystoneAffix {
+    /// Top-level document link to follow of the selected affix ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric affix ID.
+    pub id: u64,
+    /// Localized affix name.
+    pub name: StringOrStructLocale,
+    /// Localized description of the affix's effect.
+    pub description: Option<StringOrStructLocale>,
+    /// Reference to the affix's media.
+    pub media: Option<DocumentReference<Media>>,
+}
+
+/// Response structure from the Mythic Keystone dungeons index endpoint, listing all dungeons.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct MythicKeystoneDungeonsIndex {
+    /// Top-level document link to follow for a selected dungeon ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available Mythic Keystone dungeons.
+    #[serde(default)]
+    pub dungeons: Vec<DocumentReference<MythicKeystoneDungeon>>,
+}
+
+/// Mythic Keystone dungeon metadata, including the timers for each keystone upgrade.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicKeystoneDungeon {
+    /// Top-level document link to follow of the selected dungeon ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric Mythic Keystone dungeon ID, also known as the challenge mode ID.
+    pub id: u64,
+    /// Localized dungeon name.
+    pub name: StringOrStructLocale,
+    /// Reference to the dungeon's map.
+    pub map: Option<DocumentReference>,
+    /// Reference to the journal instance of the dungeon.
+    pub dungeon: Option<DocumentReference>,
+    /// Timers required to upgrade the keystone when completing the dungeon.
+    #[serde(default)]
+    pub keystone_upgrades: Vec<KeystoneUpgrade>,
+    /// Flag for indicating if the dungeon is part of the current season's rotation.
+    pub is_tracked: Option<bool>,
+}
+
+/// Timer required to upgrade a keystone by the number of levels.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoneUpgrade {
+    /// Number of levels the keystone is upgraded by.
+    pub upgrade_level: u32,
+    /// Maximum duration, in milliseconds, the dungeon must be completed within for the upgrade.
+    pub qualifying_duration: u64,
+}
+
+impl KeystoneUpgrade {
+    /// Gets the maximum duration the dungeon must be completed within for the upgrade.
+    pub fn get_qualifying_duration(&self) -> Duration {
+        Duration::milliseconds(self.qualifying_duration as i64)
+    }
+}
+
+/// Response structure from the Mythic Keystone periods index endpoint, listing all weekly periods.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct MythicKeystonePeriodsIndex {
+    /// Top-level document link to follow for a selected period ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available periods.
+    #[serde(default)]
+    pub periods: Vec<DocumentReference<MythicKeystonePeriod>>,
+    /// Reference to the current period.
+    pub current_period: Option<DocumentReference<MythicKeystonePeriod>>,
+}
+
+/// A weekly Mythic Keystone period, spanning one weekly reset to the next.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicKeystonePeriod {
+    /// Top-level document link to follow of the selected period ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric period ID.
+    pub id: u64,
+    /// Unix timestamp, in milliseconds, of when the period started.
+    pub start_timestamp: Option<u64>,
+    /// Unix timestamp, in milliseconds, of when the period ends.
+    pub end_timestamp: Option<u64>,
+}
+
+impl MythicKeystonePeriod {
+    /// Gets the time the period started, if available.
+    pub fn get_start(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.start_timestamp?)
+    }
+
+    /// Gets the time the period ends, if available.
+    pub fn get_end(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.end_timestamp?)
+    }
+}
+
+/// Response structure from the Mythic Keystone seasons index endpoint, listing all seasons.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct MythicKeystoneSeasonsIndex {
+    /// Top-level document link to follow for a selected season ID.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// List of available seasons.
+    #[serde(default)]
+    pub seasons: Vec<DocumentReference<MythicKeystoneSeason>>,
+    /// Reference to the current season.
+    pub current_season: Option<DocumentReference<MythicKeystoneSeason>>,
+}
+
+/// A Mythic Keystone season, including the periods within the season.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicKeystoneSeason {
+    /// Top-level document link to follow of the selected season ID.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Numeric season ID.
+    pub id: u64,
+    /// Localized season name, if the season is named.
+    pub season_name: Option<StringOrStructLocale>,
+    /// Unix timestamp, in milliseconds, of when the season started.
+    pub start_timestamp: Option<u64>,
+    /// Unix timestamp, in milliseconds, of when the season ended, omitted for the current season.
+    pub end_timestamp: Option<u64>,
+    /// References to the periods within the season.
+    #[serde(default)]
+    pub periods: Vec<DocumentReference<MythicKeystonePeriod>>,
+}
+
+impl MythicKeystoneSeason {
+    /// Gets the time the season started, if available.
+    pub fn get_start(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.start_timestamp?)
+    }
+
+    /// Gets the time the season ended, if the season has ended.
+    pub fn get_end(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.end_timestamp?)
+    }
+}
+
+/// Response structure from the Mythic Keystone leaderboards index endpoint, listing the
+/// leaderboards of the current period for each dungeon on a connected realm.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct MythicKeystoneLeaderboardsIndex {
+    /// Top-level document link to follow for a selected dungeon's leaderboard.
+    #[serde(rename = "_links")]
+    pub links: Links,
+    /// References to the current period's leaderboard of each dungeon.
+    #[serde(default)]
+    pub current_leaderboards: Vec<DocumentReference<MythicKeystoneLeaderboard>>,
+}
+
+/// Leaderboard of the fastest groups to complete a dungeon on a connected realm during a period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicKeystoneLeaderboard {
+    /// Top-level document link of the leaderboard.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Localized dungeon name.
+    pub name: Option<StringOrStructLocale>,
+    /// Reference to the dungeon's map.
+    pub map: Option<DocumentReference>,
+    /// Numeric Mythic Keystone dungeon ID of the leaderboard.
+    pub map_challenge_mode_id: Option<u64>,
+    /// Numeric ID of the leaderboard's period.
+    pub period: u64,
+    /// Unix timestamp, in milliseconds, of when the leaderboard's period started.
+    pub period_start_timestamp: Option<u64>,
+    /// Unix timestamp, in milliseconds, of when the leaderboard's period ends.
+    pub period_end_timestamp: Option<u64>,
+    /// Document key of the connected realm the leaderboard belongs to.
+    pub connected_realm: Option<DocumentKey>,
+    /// Groups on the leaderboard, ordered by their ranking.
+    #[serde(default)]
+    pub leading_groups: Vec<MythicKeystoneLeaderboardEntry>,
+    /// Affixes active on keystones during the period, along with the level each affix starts at.
+    #[serde(default)]
+    pub keystone_affixes: Vec<KeystoneAffixLevel>,
+}
+
+impl MythicKeystoneLeaderboard {
+    /// Gets the time the leaderboard's period started, if available.
+    pub fn get_period_start(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.period_start_timestamp?)
+    }
+
+    /// Gets the time the leaderboard's period ends, if available.
+    pub fn get_period_end(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.period_end_timestamp?)
+    }
+}
+
+/// An affix active on keystones during a period, along with the keystone level it starts at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeystoneAffixLevel {
+    /// Reference to the affix.
+    pub keystone_affix: DocumentReference<KeystoneAffix>,
+    /// Keystone level the affix is active from.
+    pub starting_level: u32,
+}
+
+/// A group's completed run on a Mythic Keystone leaderboard.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicKeystoneLeaderboardEntry {
+    /// Ranking of the group on the leaderboard, starting from one.
+    pub ranking: u32,
+    /// Duration of the run, in milliseconds.
+    pub duration: u64,
+    /// Time the run was completed, sent by Blizzard as a Unix timestamp in milliseconds.
+    #[serde(with = "time::serde::timestamp::milliseconds")]
+    pub completed_timestamp: OffsetDateTime,
+    /// Level of the keystone completed.
+    pub keystone_level: u32,
+    /// Characters in the group.
+    #[serde(default)]
+    pub members: Vec<MythicKeystoneMember>,
+    /// Mythic rating awarded for the run, if available.
+    pub mythic_rating: Option<MythicRating>,
+}
+
+impl MythicKeystoneLeaderboardEntry {
+    /// Gets the duration of the run.
+    pub fn get_duration(&self) -> Duration {
+        Duration::milliseconds(self.duration as i64)
+    }
+}
+
+/// A character in a group on a Mythic Keystone leaderboard.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicKeystoneMember {
+    /// Profile of the character.
+    pub profile: MythicKeystoneMemberProfile,
+    /// Faction of the character.
+    pub faction: Option<FactionDetails>,
+    /// Reference to the specialization the character completed the run with.
+    pub specialization: Option<DocumentReference>,
+}
+
+/// Profile of a character in a group on a Mythic Keystone leaderboard.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicKeystoneMemberProfile {
+    /// Character name.
+    pub name: String,
+    /// Numeric character ID.
+    pub id: u64,
+    /// Reference to the character's realm.
+    pub realm: Option<RealmReference>,
+}
+
+/// Mythic rating awarded for a run.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicRating {
+    /// Mythic rating of the run.
+    pub rating: f64,
+}
+
+impl<'a> WorldOfWarcraftConnector<'a> {
+    /// Retrieves an index of all keystone affixes.
+    pub async fn get_keystone_affixes(&self) -> BubbleHearthResult<KeystoneAffixesIndex> {
+        let url = self.get_static_data_url("keystone-affix/index");
+
+        let affixes = self
+            .client
+            .send_request_and_deserialize::<KeystoneAffixesIndex>(url)
+            .await?;
+
+        Ok(affixes)
+    }
+
+    /// Retrieves a keystone affix based on the affix ID.
+    pub async fn get_keystone_affix(
+        &self,
+        affix_id: u64,
+    ) -> BubbleHearthResult<Option<KeystoneAffix>> {
+        let url = self.get_static_data_url(&format!("keystone-affix/{}", affix_id));
+
+        let affix = self
+            .client
+            .send_request_and_optionally_deserialize::<KeystoneAffix>(url)
+            .await?;
+
+        Ok(affix)
+    }
+
+    /// Retrieves the media assets of a keystone affix, i.e. its icon.
+    pub async fn get_keystone_affix_media(
+        &self,
+        affix_id: u64,
+    ) -> BubbleHearthResult<Option<Media>> {
+        let url = self.get_static_data_url(&format!("media/keystone-affix/{}", affix_id));
+
+        let media = self
+            .client
+            .send_request_and_optionally_deserialize::<Media>(url)
+            .await?;
+
+        Ok(media)
+    }
+
+    /// Retrieves an index of all Mythic Keystone dungeons.
+    pub async fn get_mythic_keystone_dungeons(
+        &self,
+    ) -> BubbleHearthResult<MythicKeystoneDungeonsIndex> {
+        let url = self.get_dynamic_data_url("mythic-keystone/dungeon/index");
+
+        let dungeons = self
+            .client
+            .send_request_and_deserialize::<MythicKeystoneDungeonsIndex>(url)
+            .await?;
+
+        Ok(dungeons)
+    }
+
+    /// Retrieves a Mythic Keystone dungeon based on the dungeon ID.
+    pub async fn get_mythic_keystone_dungeon(
+        &self,
+        dungeon_id: u64,
+    ) -> BubbleHearthResult<Option<MythicKeystoneDungeon>> {
+        let url = self.get_dynamic_data_url(&format!("mythic-keystone/dungeon/{}", dungeon_id));
+
+        let dungeon = self
+            .client
+            .send_request_and_optionally_deserialize::<MythicKeystoneDungeon>(url)
+            .await?;
+
+        Ok(dungeon)
+    }
+
+    /// Retrieves an index of all Mythic Keystone periods, including the current period.
+    pub async fn get_mythic_keystone_periods(
+        &self,
+    ) -> BubbleHearthResult<MythicKeystonePeriodsIndex> {
+        let url = self.get_dynamic_data_url("mythic-keystone/period/index");
+
+        let periods = self
+            .client
+            .send_request_and_deserialize::<MythicKeystonePeriodsIndex>(url)
+            .await?;
+
+        Ok(periods)
+    }
+
+    /// Retrieves a Mythic Keystone period based on the period ID.
+    pub async fn get_mythic_keystone_period(
+        &self,
+        period_id: u64,
+    ) -> BubbleHearthResult<Option<MythicKeystonePeriod>> {
+        let url = self.get_dynamic_data_url(&format!("mythic-keystone/period/{}", period_id));
+
+        let period = self
+            .client
+            .send_request_and_optionally_deserialize::<MythicKeystonePeriod>(url)
+            .await?;
+
+        Ok(period)
+    }
+
+    /// Retrieves the current Mythic Keystone period from the periods index, if one is in progress.
+    pub async fn get_current_mythic_keystone_period(
+        &self,
+    ) -> BubbleHearthResult<Option<MythicKeystonePeriod>> {
+        let periods = self.get_mythic_keystone_periods().await?;
+
+        match periods.current_period {
+            None => Ok(None),
+            Some(current_period) => self.get_mythic_keystone_period(current_period.id).await,
+        }
+    }
+
+    /// Retrieves an index of all Mythic Keystone seasons, including the current season.
+    pub async fn get_mythic_keystone_seasons(
+        &self,
+    ) -> BubbleHearthResult<MythicKeystoneSeasonsIndex> {
+        let url = self.get_dynamic_data_url("mythic-keystone/season/index");
+
+        let seasons = self
+            .client
+            .send_request_and_deserialize::<MythicKeystoneSeasonsIndex>(url)
+            .await?;
+
+        Ok(seasons)
+    }
+
+    /// Retrieves a Mythic Keystone season based on the season ID.
+    pub async fn get_mythic_keystone_season(
+        &self,
+        season_id: u64,
+    ) -> BubbleHearthResult<Option<MythicKeystoneSeason>> {
+        let url = self.get_dynamic_data_url(&format!("mythic-keystone/season/{}", season_id));
+
+        let season = self
+            .client
+            .send_request_and_optionally_deserialize::<MythicKeystoneSeason>(url)
+            .await?;
+
+        Ok(season)
+    }
+
+    /// Retrieves the current period's Mythic Keystone leaderboards of each dungeon on the connected realm.
+    pub async fn get_mythic_keystone_leaderboards(
+        &self,
+        connected_realm_id: u64,
+    ) -> BubbleHearthResult<Option<MythicKeystoneLeaderboardsIndex>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "connected-realm/{}/mythic-leaderboard/index",
+            connected_realm_id
+        ));
+
+        let leaderboards = self
+            .client
+            .send_request_and_optionally_deserialize::<MythicKeystoneLeaderboardsIndex>(url)
+            .await?;
+
+        Ok(leaderboards)
+    }
+
+    /// Retrieves the Mythic Keystone leaderboard of the dungeon on the connected realm for the period.
+    pub async fn get_mythic_keystone_leaderboard(
+        &self,
+        connected_realm_id: u64,
+        dungeon_id: u64,
+        period_id: u64,
+    ) -> BubbleHearthResult<Option<MythicKeystoneLeaderboard>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "connected-realm/{}/mythic-leaderboard/{}/period/{}",
+            connected_realm_id, dungeon_id, period_id
+        ));
+
+        let leaderboard = self
+            .client
+            .send_request_and_optionally_deserialize::<MythicKeystoneLeaderboard>(url)
+            .await?;
+
+        Ok(leaderboard)
+    }
+}
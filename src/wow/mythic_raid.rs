@@ -0,0 +1,112 @@
+//! Mythic Raid leaderboard data and APIs for World of Warcraft, listing each faction's Hall of
+//! Fame of the first guilds to defeat a raid's final boss on Mythic difficulty, i.e. the
+//! Race to World First standings.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{get_timestamp, DocumentKey, Links};
+use crate::errors::BubbleHearthResult;
+use crate::factions::FactionDetails;
+use crate::regionality::AccountRegion;
+use crate::wow::realms::RealmReference;
+use crate::wow::WorldOfWarcraftConnector;
+
+/// Factions with a Mythic Raid Hall of Fame.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HallOfFameFaction {
+    /// Represents the Alliance Hall of Fame.
+    Alliance,
+    /// Represents the Horde Hall of Fame.
+    Horde,
+}
+
+impl HallOfFameFaction {
+    /// Gets the faction slug used to address the faction's Hall of Fame.
+    pub fn get_faction_slug(&self) -> &str {
+        match self {
+            HallOfFameFaction::Alliance => "alliance",
+            HallOfFameFaction::Horde => "horde",
+        }
+    }
+}
+
+/// Hall of Fame of a raid for a faction, ranking the first guilds to defeat the raid's final boss on Mythic difficulty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicRaidLeaderboard {
+    /// Top-level document link of the leaderboard.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Slug of the raid, i.e. uldir.
+    pub slug: String,
+    /// Criteria the leaderboard is ranked by, i.e. hall-of-fame.
+    pub criteria_type: Option<String>,
+    /// Guilds on the leaderboard, ordered by their rank.
+    #[serde(default)]
+    pub entries: Vec<MythicRaidLeaderboardEntry>,
+}
+
+/// A guild's ranking within a Mythic Raid Hall of Fame.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicRaidLeaderboardEntry {
+    /// Faction of the guild.
+    pub faction: Option<FactionDetails>,
+    /// Reference to the guild.
+    pub guild: MythicRaidGuild,
+    /// Abbreviation of the guild's region, i.e. us.
+    pub region: Option<String>,
+    /// Rank of the guild within the Hall of Fame, starting from one.
+    pub rank: u32,
+    /// Unix timestamp, in milliseconds, of when the guild defeated the raid's final boss.
+    pub timestamp: u64,
+}
+
+impl MythicRaidLeaderboardEntry {
+    /// Gets the time the guild defeated the raid's final boss.
+    pub fn get_completed(&self) -> Option<OffsetDateTime> {
+        get_timestamp(self.timestamp)
+    }
+
+    /// Gets the typed region of the guild, if the region is known.
+    pub fn get_region(&self) -> Option<AccountRegion> {
+        AccountRegion::from_str(self.region.as_ref()?).ok()
+    }
+}
+
+/// Reference to a guild within a Mythic Raid Hall of Fame.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MythicRaidGuild {
+    /// Document key of the guild profile.
+    pub key: Option<DocumentKey>,
+    /// Guild name.
+    pub name: String,
+    /// Numeric guild ID.
+    pub id: u64,
+    /// Reference to the guild's realm.
+    pub realm: RealmReference,
+}
+
+impl<'a> WorldOfWarcraftConnector<'a> {
+    /// Retrieves the faction's Hall of Fame for the raid based on the raid slug, i.e. `uldir`.
+    pub async fn get_mythic_raid_leaderboard(
+        &self,
+        raid_slug: &str,
+        faction: HallOfFameFaction,
+    ) -> BubbleHearthResult<Option<MythicRaidLeaderboard>> {
+        let url = self.get_dynamic_data_url(&format!(
+            "leaderboard/hall-of-fame/{}/{}",
+            raid_slug,
+            faction.get_faction_slug()
+        ));
+
+        let leaderboard = self
+            .client
+            .send_request_and_optionally_deserialize::<MythicRaidLeaderboard>(url)
+            .await?;
+
+        Ok(leaderboard)
+    }
+}
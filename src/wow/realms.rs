@@ -0,0 +1,62 @@
+//! Realm data and APIs for World of Warcraft, referenced by characters and guilds on leaderboards.
+
+use serde::{Deserialize, Serialize};
+
+use crate::connectors::WorldOfWarcraftUrls;
+use crate::documents::{DocumentKey, Links};
+use crate::errors::BubbleHearthResult;
+use crate::localization::StringOrStructLocale;
+use crate::timezone::Timezone;
+use crate::wow::connected_realms::ConnectedRealm;
+use crate::wow::WorldOfWarcraftConnector;
+
+/// Realm metadata for all available World of Warcraft servers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Realm {
+    /// Top-level document link to follow of the selected realm.
+    #[serde(rename = "_links")]
+    pub links: Option<Links>,
+    /// Localized realm name.
+    pub name: StringOrStructLocale,
+    /// Numeric realm ID.
+    pub id: u64,
+    /// Slugified realm name.
+    pub slug: String,
+    /// Realm category correlating to its location, i.e. US West, US East, Oceanic, etc.
+    pub category: Option<StringOrStructLocale>,
+    /// Realm locale.
+    pub locale: Option<String>,
+    /// Realm timezone name, i.e. America/New_York, America/Los_Angeles, etc.
+    pub timezone: Option<Timezone>,
+    /// Flag for indicating if the realm is a PVP-based tournament realm.
+    pub is_tournament: Option<bool>,
+    /// Document key of the connected realm the realm belongs to.
+    pub connected_realm: Option<DocumentKey<ConnectedRealm>>,
+}
+
+/// Reference to a realm from leaderboard and profile data, including the realm slug used to address profiles.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealmReference {
+    /// Document key of the realm.
+    pub key: Option<DocumentKey<Realm>>,
+    /// Localized realm name.
+    pub name: Option<StringOrStructLocale>,
+    /// Numeric realm ID.
+    pub id: u64,
+    /// Slugified realm name.
+    pub slug: String,
+}
+
+impl<'a> WorldOfWarcraftConnector<'a> {
+    /// Retrieves a realm's metadata based on the realm slug.
+    pub async fn get_realm(&self, slug: &str) -> BubbleHearthResult<Option<Realm>> {
+        let url = self.get_dynamic_data_url(&format!("realm/{}", slug));
+
+        let realm = self
+            .client
+            .send_request_and_optionally_deserialize::<Realm>(url)
+            .await?;
+
+        Ok(realm)
+    }
+}
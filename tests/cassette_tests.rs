@@ -0,0 +1,152 @@
+#![cfg(feature = "classic")]
+
+mod cassette_tests {
+    use std::path::PathBuf;
+
+    use bubblehearth::cassette::{Cassette, CassetteMode};
+    use bubblehearth::client::BubbleHearthClient;
+    use bubblehearth::errors::BubbleHearthError;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::{FakeBlizzardServer, FAKE_ACCESS_TOKEN};
+
+    fn get_cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("bubblehearth-cassettes-{}", std::process::id()))
+            .join(format!("{}.json", name))
+    }
+
+    fn get_cassette_client(server: &FakeBlizzardServer, mode: CassetteMode) -> BubbleHearthClient {
+        server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_cassette(mode)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn records_interactions_with_redacted_token() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let path = get_cassette_path("records_interactions_with_redacted_token");
+        let client = get_cassette_client(&server, CassetteMode::Record(path.clone()));
+
+        // Act
+        let realm = client.classic().get_realm("atiesh").await;
+        let missing_realm = client.classic().get_realm("not-atiesh").await;
+        client.save_cassette().unwrap();
+        let cassette = Cassette::load(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+
+        // Assert
+        assert!(realm.unwrap().is_some());
+        assert!(missing_realm.unwrap().is_none());
+        assert_eq!(cassette.interactions.len(), 2);
+        assert_eq!(cassette.interactions[0].response.status, 200);
+        assert_eq!(cassette.interactions[1].response.status, 404);
+        assert!(!contents.contains(FAKE_ACCESS_TOKEN));
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_interactions_without_sending_requests() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let path = get_cassette_path("replays_recorded_interactions_without_sending_requests");
+        let recording_client = get_cassette_client(&server, CassetteMode::Record(path.clone()));
+        let recorded_realm = recording_client.classic().get_realm("atiesh").await;
+        let recorded_missing_realm = recording_client.classic().get_realm("not-atiesh").await;
+        recording_client.save_cassette().unwrap();
+        let sent_requests = server.received_requests().len();
+        let replaying_client = get_cassette_client(&server, CassetteMode::Replay(path));
+
+        // Act
+        let replayed_realm = replaying_client.classic().get_realm("atiesh").await;
+        let replayed_missing_realm = replaying_client.classic().get_realm("not-atiesh").await;
+
+        // Assert
+        assert_eq!(replayed_realm.unwrap(), recorded_realm.unwrap());
+        assert_eq!(
+            replayed_missing_realm.unwrap(),
+            recorded_missing_realm.unwrap()
+        );
+        assert_eq!(server.received_requests().len(), sent_requests);
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_replayed_request_not_recorded() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let path = get_cassette_path("returns_error_when_replayed_request_not_recorded");
+        let recording_client = get_cassette_client(&server, CassetteMode::Record(path.clone()));
+        recording_client
+            .classic()
+            .get_realm("atiesh")
+            .await
+            .unwrap();
+        drop(recording_client);
+        let replaying_client = get_cassette_client(&server, CassetteMode::Replay(path));
+
+        // Act
+        let realms = replaying_client.classic().get_realms().await;
+
+        // Assert
+        assert!(matches!(
+            realms,
+            Err(BubbleHearthError::CassetteInteractionNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn saves_recorded_interactions_once_requested() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let path = get_cassette_path("saves_recorded_interactions_once_requested");
+        let client = get_cassette_client(&server, CassetteMode::Record(path.clone()));
+        client.classic().get_realm("atiesh").await.unwrap();
+        let saved_before_request = path.exists();
+
+        // Act
+        client.save_cassette().unwrap();
+        let cassette = Cassette::load(&path).unwrap();
+
+        // Assert
+        assert!(!saved_before_request);
+        assert_eq!(cassette.interactions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn saves_recorded_interactions_when_client_dropped() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let path = get_cassette_path("saves_recorded_interactions_when_client_dropped");
+        let client = get_cassette_client(&server, CassetteMode::Record(path.clone()));
+        client.classic().get_realm("atiesh").await.unwrap();
+
+        // Act
+        drop(client);
+        let cassette = Cassette::load(&path).unwrap();
+
+        // Assert
+        assert_eq!(cassette.interactions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_replay_cassette_missing() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let path = get_cassette_path("returns_error_when_replay_cassette_missing");
+
+        // Act
+        let client = server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_cassette(CassetteMode::Replay(path))
+            .build();
+
+        // Assert
+        assert!(matches!(client, Err(BubbleHearthError::CassetteFailed(_))));
+    }
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "classic")]
+
+mod classic_auction_tests {
+    use futures::TryStreamExt;
+
+    use bubblehearth::classic::auctions::{AuctionHouseFaction, TimeLeft};
+    use bubblehearth::localization::{Locale, StringOrStructLocale};
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const AUCTION_HOUSES_INDEX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/connected-realm/4372/auctions/index?namespace=dynamic-classic-us"
+            }
+          },
+          "auctions": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/connected-realm/4372/auctions/2?namespace=dynamic-classic-us"
+              },
+              "name": "Alliance Auction House",
+              "id": 2
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/connected-realm/4372/auctions/6?namespace=dynamic-classic-us"
+              },
+              "name": "Horde Auction House",
+              "id": 6
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/connected-realm/4372/auctions/7?namespace=dynamic-classic-us"
+              },
+              "name": "Blackwater Auction House",
+              "id": 7
+            }
+          ]
+        }
+    "#;
+
+    const HORDE_AUCTIONS: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/connected-realm/4372/auctions/6?namespace=dynamic-classic-us"
+            }
+          },
+          "connected_realm": {
+            "href": "https://us.api.blizzard.com/data/wow/connected-realm/4372?namespace=dynamic-classic-us"
+          },
+          "auctions": [
+            {
+              "id": 1880389245,
+              "item": { "id": 2589 },
+              "bid": 1500,
+              "buyout": 2000,
+              "quantity": 20,
+              "time_left": "LONG"
+            },
+            {
+              "id": 1880389246,
+              "item": { "id": 15215, "rand": 1020, "seed": 1484103040 },
+              "buyout": 410000,
+              "quantity": 1,
+              "time_left": "SHORT"
+            }
+          ],
+          "name": "Horde Auction House",
+          "id": 6
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_auction_houses_for_connected_realm() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        server.register_fixture(
+            "/data/wow/connected-realm/4372/auctions/index",
+            AUCTION_HOUSES_INDEX,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let connected_realm = client
+            .classic()
+            .get_connected_realm(4372)
+            .await
+            .unwrap()
+            .unwrap();
+        let index = client
+            .classic()
+            .get_auction_houses(connected_realm.id)
+            .await
+            .unwrap()
+            .unwrap();
+        let ids: Vec<_> = index.auctions.iter().map(|house| house.id).collect();
+
+        // Assert
+        assert_eq!(ids, vec![2, 6, 7]);
+        assert_eq!(
+            index.auctions[0].name,
+            StringOrStructLocale::StringLocale("Alliance Auction House".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_auctions_for_faction_auction_house() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        server.register_fixture("/data/wow/connected-realm/4372/auctions/6", HORDE_AUCTIONS);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let horde = client
+            .classic()
+            .get_auctions(4372, AuctionHouseFaction::Horde)
+            .await
+            .unwrap()
+            .unwrap();
+        let alliance = client
+            .classic()
+            .get_auctions(4372, AuctionHouseFaction::Alliance)
+            .await;
+
+        // Assert
+        assert_eq!(horde.id, Some(6));
+        assert_eq!(horde.auctions.len(), 2);
+        assert_eq!(horde.auctions[0].bid, Some(1500));
+        assert_eq!(horde.auctions[0].time_left, TimeLeft::Long);
+        assert_eq!(horde.auctions[1].item.seed, Some(1484103040));
+        assert_eq!(horde.auctions[1].bid, None);
+        assert!(alliance.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_streamed_auctions_for_faction_auction_house() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        server.register_fixture("/data/wow/connected-realm/4372/auctions/6", HORDE_AUCTIONS);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let horde: Vec<_> = client
+            .classic()
+            .stream_auctions(4372, AuctionHouseFaction::Horde)
+            .await
+            .unwrap()
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let alliance = client
+            .classic()
+            .stream_auctions(4372, AuctionHouseFaction::Alliance)
+            .await;
+
+        // Assert
+        assert_eq!(horde.len(), 2);
+        assert_eq!(horde[0].bid, Some(1500));
+        assert_eq!(horde[1].item.seed, Some(1484103040));
+        assert!(alliance.unwrap().is_none());
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "classic")]
+
+mod classic_bulk_tests {
+    use http::{Method, StatusCode};
+
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::{FakeBlizzardServer, FAKE_TOKEN_PATH};
+
+    #[tokio::test]
+    async fn returns_realms_in_input_order_when_some_not_found() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let realms = client
+            .classic()
+            .get_realms_by_slugs(&["atiesh", "not-atiesh", "atiesh"])
+            .await;
+
+        // Assert
+        assert_eq!(realms.len(), 3);
+        assert_eq!(realms[0].as_ref().unwrap().as_ref().unwrap().slug, "atiesh");
+        assert!(realms[1].as_ref().unwrap().is_none());
+        assert_eq!(realms[2].as_ref().unwrap().as_ref().unwrap().slug, "atiesh");
+    }
+
+    #[tokio::test]
+    async fn returns_regions_in_input_order_when_some_failed() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        server.register_fixture_with_status(
+            "/data/wow/region/43",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Internal server error",
+        );
+        let client = server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_bulk_concurrency(2)
+            .build()
+            .unwrap();
+
+        // Act
+        let regions = client.classic().get_regions_by_ids(&[41, 43, 420]).await;
+
+        // Assert
+        assert_eq!(regions.len(), 3);
+        assert_eq!(regions[0].as_ref().unwrap().as_ref().unwrap().id, Some(41));
+        assert!(regions[1].is_err());
+        assert!(regions[2].as_ref().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn requests_single_access_token_for_bulk_fetch() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let realms = client
+            .classic()
+            .get_realms_by_slugs(&["atiesh", "atiesh", "atiesh", "atiesh"])
+            .await;
+        let token_requests = server
+            .received_requests()
+            .into_iter()
+            .filter(|request| request.method == Method::POST && request.path == FAKE_TOKEN_PATH)
+            .count();
+
+        // Assert
+        assert!(realms.iter().all(|realm| realm.is_ok()));
+        assert_eq!(token_requests, 1);
+    }
+}
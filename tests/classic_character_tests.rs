@@ -0,0 +1,346 @@
+#![cfg(feature = "classic")]
+
+mod classic_character_tests {
+    use bubblehearth::classic::items::ItemQuality;
+    use bubblehearth::classic::profile::characters::Gender;
+    use bubblehearth::factions::Faction;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const CHARACTER_PROFILE: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/profile/wow/character/atiesh/thrall?namespace=profile-classic1x-us"
+            }
+          },
+          "id": 1337,
+          "name": "Thrall",
+          "gender": { "type": "MALE", "name": "Male" },
+          "faction": { "type": "HORDE", "name": "Horde" },
+          "race": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/playable-race/2?namespace=static-classic1x-us"
+            },
+            "name": "Orc",
+            "id": 2
+          },
+          "character_class": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/playable-class/3?namespace=static-classic1x-us"
+            },
+            "name": "Hunter",
+            "id": 3
+          },
+          "realm": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/realm/4372?namespace=dynamic-classic1x-us"
+            },
+            "name": "Atiesh",
+            "id": 4372,
+            "slug": "atiesh"
+          },
+          "guild": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/guild/atiesh/the-horde?namespace=profile-classic1x-us"
+            },
+            "name": "The Horde",
+            "id": 42,
+            "realm": {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/realm/4372?namespace=dynamic-classic1x-us"
+              },
+              "name": "Atiesh",
+              "id": 4372,
+              "slug": "atiesh"
+            },
+            "faction": { "type": "HORDE", "name": "Horde" }
+          },
+          "level": 60,
+          "experience": 0,
+          "last_login_timestamp": 1700000000000,
+          "average_item_level": 62,
+          "equipped_item_level": 63,
+          "is_ghost": false,
+          "is_self_found": true,
+          "equipment": {
+            "href": "https://us.api.blizzard.com/profile/wow/character/atiesh/thrall/equipment?namespace=profile-classic1x-us"
+          },
+          "hunter_pets": {
+            "href": "https://us.api.blizzard.com/profile/wow/character/atiesh/thrall/hunter-pets?namespace=profile-classic1x-us"
+          }
+        }
+    "#;
+
+    const CHARACTER_EQUIPMENT: &str = r#"
+        {
+          "character": {
+            "key": {
+              "href": "https://us.api.blizzard.com/profile/wow/character/atiesh/thrall?namespace=profile-classic-us"
+            },
+            "name": "Thrall",
+            "id": 1337,
+            "realm": { "name": "Atiesh", "id": 4372, "slug": "atiesh" }
+          },
+          "equipped_items": [
+            {
+              "item": {
+                "key": {
+                  "href": "https://us.api.blizzard.com/data/wow/item/19019?namespace=static-classic-us"
+                },
+                "id": 19019
+              },
+              "slot": { "type": "MAIN_HAND", "name": "Main Hand" },
+              "quantity": 1,
+              "name": "Thunderfury, Blessed Blade of the Windseeker",
+              "quality": { "type": "LEGENDARY", "name": "Legendary" },
+              "inventory_type": { "type": "WEAPON", "name": "One-Hand" },
+              "binding": { "type": "ON_ACQUIRE", "name": "Binds when picked up" },
+              "stats": [
+                { "type": { "type": "AGILITY", "name": "Agility" }, "value": 5 }
+              ],
+              "enchantments": [
+                {
+                  "display_string": "Enchanted: Crusader",
+                  "enchantment_id": 1900,
+                  "enchantment_slot": { "id": 0, "type": "PERMANENT" }
+                }
+              ],
+              "durability": { "value": 125, "display_string": "Durability 125 / 125" }
+            }
+          ]
+        }
+    "#;
+
+    const CHARACTER_HUNTER_PETS: &str = r#"
+        {
+          "character": { "name": "Thrall", "id": 1337 },
+          "hunter_pets": [
+            {
+              "name": "Wolfie",
+              "level": 60,
+              "creature": {
+                "key": {
+                  "href": "https://us.api.blizzard.com/data/wow/creature/69?namespace=static-classic-us"
+                },
+                "name": "Timber Wolf",
+                "id": 69
+              },
+              "slot": 0,
+              "is_active": true,
+              "is_summoned": true,
+              "creature_display": {
+                "key": {
+                  "href": "https://us.api.blizzard.com/data/wow/media/creature-display/903?namespace=static-classic-us"
+                },
+                "id": 903
+              }
+            },
+            { "name": "Kitty", "level": 58, "slot": 1 }
+          ]
+        }
+    "#;
+
+    const CHARACTER_SPECIALIZATIONS: &str = r#"
+        {
+          "character": { "name": "Thrall", "id": 1337 },
+          "specialization_groups": [
+            {
+              "specializations": [
+                {
+                  "specialization_name": "Beast Mastery",
+                  "spent_points": 31,
+                  "talents": [
+                    {
+                      "talent": { "id": 1386 },
+                      "spell_tooltip": {
+                        "spell": { "name": "Bestial Wrath", "id": 19574 },
+                        "description": "Send your pet into a rage.",
+                        "cast_time": "Instant",
+                        "cooldown": "2 min cooldown"
+                      },
+                      "talent_rank": 1
+                    }
+                  ]
+                }
+              ]
+            },
+            {
+              "is_active": true,
+              "specializations": [
+                { "specialization_name": "Marksmanship", "spent_points": 31, "talents": [] }
+              ]
+            }
+          ]
+        }
+    "#;
+
+    const CHARACTER_STATISTICS: &str = r#"
+        {
+          "character": { "name": "Thrall", "id": 1337 },
+          "health": 4200,
+          "power": 5100,
+          "power_type": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/power-type/0?namespace=static-classic-us"
+            },
+            "name": "Mana",
+            "id": 0
+          },
+          "strength": { "base": 65, "effective": 90 },
+          "agility": { "base": 120, "effective": 380 },
+          "melee_crit": { "rating_bonus": 0.0, "value": 12.5 },
+          "armor": { "base": 3100, "effective": 3400 },
+          "main_hand_dps": 54.1,
+          "fire_resistance": { "base": 0, "effective": 15 }
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_character_profile_from_era_profile_namespace() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/profile/wow/character/atiesh/thrall", CHARACTER_PROFILE);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let character = client
+            .classic_era()
+            .get_character_profile("atiesh", "Thrall")
+            .await
+            .unwrap()
+            .unwrap();
+        let character_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert_eq!(character.name, "Thrall");
+        assert_eq!(character.level, Some(60));
+        assert_eq!(character.gender.as_ref().unwrap().gender_type, Gender::Male);
+        assert_eq!(
+            character.faction.as_ref().unwrap().faction_type,
+            Faction::Horde
+        );
+        assert_eq!(character.realm.as_ref().unwrap().slug, "atiesh");
+        assert_eq!(character.guild.as_ref().unwrap().name, "The Horde");
+        assert_eq!(character.is_self_found, Some(true));
+        assert!(character.hunter_pets.is_some());
+        assert_eq!(
+            character.get_last_login().unwrap().unix_timestamp(),
+            1700000000
+        );
+        assert_eq!(
+            character_request.query,
+            Some("namespace=profile-classic1x-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_character_not_found() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let character = client
+            .classic()
+            .get_character_profile("atiesh", "not-thrall")
+            .await;
+        let statistics = client
+            .classic()
+            .get_character_statistics("atiesh", "not-thrall")
+            .await;
+
+        // Assert
+        assert!(character.unwrap().is_none());
+        assert!(statistics.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_equipped_items_with_typed_quality_and_enchantments() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/profile/wow/character/atiesh/thrall/equipment",
+            CHARACTER_EQUIPMENT,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let equipment = client
+            .classic()
+            .get_character_equipment("atiesh", "Thrall")
+            .await
+            .unwrap()
+            .unwrap();
+        let main_hand = &equipment.equipped_items[0];
+
+        // Assert
+        assert_eq!(equipment.character.id, 1337);
+        assert_eq!(main_hand.item.id, 19019);
+        assert_eq!(main_hand.slot.slot_type, "MAIN_HAND");
+        assert_eq!(
+            main_hand.quality.as_ref().unwrap().quality_type,
+            ItemQuality::Legendary
+        );
+        assert_eq!(main_hand.stats[0].value, 5);
+        assert_eq!(main_hand.enchantments[0].enchantment_id, Some(1900));
+        assert_eq!(main_hand.durability.as_ref().unwrap().value, 125);
+    }
+
+    #[tokio::test]
+    async fn returns_hunter_pets_talents_and_statistics() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/profile/wow/character/atiesh/thrall/hunter-pets",
+            CHARACTER_HUNTER_PETS,
+        );
+        server.register_fixture(
+            "/profile/wow/character/atiesh/thrall/specializations",
+            CHARACTER_SPECIALIZATIONS,
+        );
+        server.register_fixture(
+            "/profile/wow/character/atiesh/thrall/statistics",
+            CHARACTER_STATISTICS,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let (hunter_pets, specializations, statistics) = tokio::join!(
+            classic.get_character_hunter_pets("atiesh", "thrall"),
+            classic.get_character_specializations("atiesh", "thrall"),
+            classic.get_character_statistics("atiesh", "thrall")
+        );
+        let hunter_pets = hunter_pets.unwrap().unwrap();
+        let specializations = specializations.unwrap().unwrap();
+        let statistics = statistics.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(hunter_pets.hunter_pets.len(), 2);
+        assert!(hunter_pets.hunter_pets[0].is_active);
+        assert!(!hunter_pets.hunter_pets[1].is_summoned);
+        assert_eq!(
+            hunter_pets.hunter_pets[0]
+                .creature_display
+                .as_ref()
+                .unwrap()
+                .id,
+            903
+        );
+        assert_eq!(
+            specializations.specialization_groups[0].specializations[0].talents[0].talent_rank,
+            Some(1)
+        );
+        assert_eq!(
+            specializations.get_active_group().unwrap().specializations[0].spent_points,
+            31
+        );
+        assert_eq!(statistics.health, Some(4200));
+        assert_eq!(statistics.agility.unwrap().effective, 380);
+        assert_eq!(statistics.melee_crit.unwrap().value, 12.5);
+        assert_eq!(statistics.fire_resistance.unwrap().effective, 15);
+        assert_eq!(statistics.power_type.unwrap().id, 0);
+    }
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "classic")]
+
+mod classic_connected_realm_tests {
+    use bubblehearth::classic::connected_realms::{
+        ConnectedRealmSearchField, RealmPopulation, RealmStatus,
+    };
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::SearchQuery;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    #[tokio::test]
+    async fn returns_connected_realm_keys_from_index() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let index = client.classic().get_connected_realms().await.unwrap();
+        let ids: Vec<_> = index
+            .connected_realms
+            .iter()
+            .map(|key| key.parse_reference().unwrap().id)
+            .collect();
+
+        // Assert
+        assert_eq!(ids, vec![Some(4372), Some(4384)]);
+    }
+
+    #[tokio::test]
+    async fn returns_connected_realm_with_typed_status_and_member_realms() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let connected_realm = client
+            .classic()
+            .get_connected_realm(4372)
+            .await
+            .unwrap()
+            .unwrap();
+        let member_key = connected_realm.realms[0].connected_realm.as_ref().unwrap();
+
+        // Assert
+        assert!(connected_realm.is_up());
+        assert!(connected_realm.is_full());
+        assert_eq!(connected_realm.has_queue, Some(false));
+        assert_eq!(connected_realm.realms.len(), 1);
+        assert_eq!(connected_realm.realms[0].slug, "atiesh");
+        assert_eq!(member_key.parse_reference().unwrap().id, Some(4372));
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_connected_realm_not_found() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let connected_realm = client.classic().get_connected_realm(420).await;
+
+        // Assert
+        assert!(connected_realm.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_connected_realm_search_with_unknown_population_fallback() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = SearchQuery::new().filter(ConnectedRealmSearchField::RealmLocale, "enUS");
+
+        // Act
+        let search = client
+            .classic()
+            .search_connected_realms(&query, None)
+            .await
+            .unwrap();
+        let mankrik = &search.results[1].data;
+        let status = mankrik.status.as_ref().unwrap();
+
+        // Assert
+        assert_eq!(search.results.len(), 2);
+        assert_eq!(status.status_type, RealmStatus::Down);
+        assert!(status.name.is_some());
+        assert_eq!(
+            mankrik.population.as_ref().unwrap().population_type,
+            RealmPopulation::Unknown
+        );
+        assert!(!mankrik.is_up());
+    }
+
+    #[tokio::test]
+    async fn sends_typed_search_fields_with_connected_realm_search() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = SearchQuery::new()
+            .filter(ConnectedRealmSearchField::StatusType, "UP")
+            .filter_localized(
+                ConnectedRealmSearchField::RealmName,
+                Locale::EnglishUS,
+                "Atiesh",
+            );
+
+        // Act
+        let search = client.classic().search_connected_realms(&query, None).await;
+        let search_query = server
+            .received_requests()
+            .into_iter()
+            .filter(|r| r.path == "/data/wow/search/connected-realm")
+            .find_map(|r| r.query);
+
+        // Assert
+        assert!(search.is_ok());
+        assert_eq!(
+            search_query,
+            Some(
+                "namespace=dynamic-classic-us&status.type=UP&realms.name.en_US=Atiesh&_page=1"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,206 @@
+#![cfg(feature = "classic")]
+
+mod classic_creature_tests {
+    use bubblehearth::classic::creatures::CreatureSearchField;
+    use bubblehearth::localization::{Locale, StringOrStructLocale};
+    use bubblehearth::media::MediaAssetKey;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::SearchQuery;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const CREATURE_FAMILIES_INDEX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/creature-family/?namespace=static-classic-us"
+            }
+          },
+          "creature_families": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/creature-family/1?namespace=static-classic-us"
+              },
+              "name": "Wolf",
+              "id": 1
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/creature-family/2?namespace=static-classic-us"
+              },
+              "name": "Cat",
+              "id": 2
+            }
+          ]
+        }
+    "#;
+
+    const CREATURE: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/creature/69?namespace=static-classic-us"
+            }
+          },
+          "id": 69,
+          "name": "Timber Wolf",
+          "type": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/creature-type/1?namespace=static-classic-us"
+            },
+            "name": "Beast",
+            "id": 1
+          },
+          "family": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/creature-family/1?namespace=static-classic-us"
+            },
+            "name": "Wolf",
+            "id": 1
+          },
+          "creature_displays": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/media/creature-display/903?namespace=static-classic-us"
+              },
+              "id": 903
+            }
+          ],
+          "is_tameable": true
+        }
+    "#;
+
+    const CREATURE_DISPLAY_MEDIA: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/media/creature-display/903?namespace=static-classic-us"
+            }
+          },
+          "assets": [
+            {
+              "key": "zoom",
+              "value": "https://render.worldofwarcraft.com/classic-us/npcs/zoom/creature-display-903.jpg"
+            }
+          ],
+          "id": 903
+        }
+    "#;
+
+    const CREATURE_SEARCH: &str = r#"
+        {
+          "page": 1,
+          "pageSize": 1,
+          "maxPageSize": 100,
+          "pageCount": 1,
+          "results": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/creature/69?namespace=static-classic-us"
+              },
+              "data": {
+                "creature_displays": [{ "id": 903 }],
+                "is_tameable": true,
+                "name": "Timber Wolf",
+                "id": 69,
+                "type": { "name": "Beast", "id": 1 },
+                "family": { "name": "Wolf", "id": 1 }
+              }
+            }
+          ]
+        }
+    "#;
+
+    async fn get_creature_server() -> FakeBlizzardServer {
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/creature-family/index?namespace=static-classic-us",
+            CREATURE_FAMILIES_INDEX,
+        );
+        server.register_fixture(
+            "/data/wow/creature/69?namespace=static-classic-us",
+            CREATURE,
+        );
+        server.register_fixture(
+            "/data/wow/media/creature-display/903?namespace=static-classic-us",
+            CREATURE_DISPLAY_MEDIA,
+        );
+        server.register_fixture(
+            "/data/wow/search/creature?namespace=static-classic-us",
+            CREATURE_SEARCH,
+        );
+        server
+    }
+
+    #[tokio::test]
+    async fn returns_creature_families_from_static_namespace() {
+        // Arrange
+        let server = get_creature_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let index = client.classic().get_creature_families().await.unwrap();
+
+        // Assert
+        assert_eq!(index.creature_families.len(), 2);
+        assert_eq!(index.creature_families[1].id, 2);
+        assert_eq!(
+            index.creature_families[1].name,
+            Some(StringOrStructLocale::StringLocale("Cat".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_creature_with_type_family_and_display_media() {
+        // Arrange
+        let server = get_creature_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let creature = classic.get_creature(69).await.unwrap().unwrap();
+        let display = creature.creature_displays[0].key.as_ref().unwrap();
+        let media = client.resolve(display).await.unwrap().unwrap();
+        let missing_creature = classic.get_creature(70).await;
+
+        // Assert
+        assert_eq!(creature.creature_type.unwrap().id, 1);
+        assert_eq!(creature.family.unwrap().id, 1);
+        assert_eq!(creature.is_tameable, Some(true));
+        assert_eq!(media.assets[0].key, MediaAssetKey::Zoom);
+        assert_eq!(media.assets[0].file_data_id, None);
+        assert!(missing_creature.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_creature_search_with_static_namespace_and_filters() {
+        // Arrange
+        let server = get_creature_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = SearchQuery::new()
+            .filter_localized(CreatureSearchField::FamilyName, Locale::EnglishUS, "Wolf")
+            .filter(CreatureSearchField::IsTameable, true);
+
+        // Act
+        let search = client
+            .classic()
+            .search_creatures(&query, None)
+            .await
+            .unwrap();
+        let search_query = server
+            .received_requests()
+            .into_iter()
+            .filter(|r| r.path == "/data/wow/search/creature")
+            .find_map(|r| r.query);
+
+        // Assert
+        assert_eq!(search.results[0].data.id, 69);
+        assert_eq!(search.results[0].data.creature_displays[0].id, 903);
+        assert_eq!(
+            search_query,
+            Some(
+                "namespace=static-classic-us&family.name.en_US=Wolf&is_tameable=true&_page=1"
+                    .to_string()
+            )
+        );
+    }
+}
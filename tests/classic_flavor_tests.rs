@@ -0,0 +1,119 @@
+#![cfg(feature = "classic")]
+
+mod classic_flavor_tests {
+    use futures::TryStreamExt;
+
+    use bubblehearth::classic::realms::RealmSearchField;
+    use bubblehearth::classic::ClassicFlavor;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::SearchQuery;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const POWER_TYPES_INDEX: &str = r#"{
+        "_links": { "self": { "href": "https://us.api.blizzard.com/data/wow/power-type/?namespace=static-classic1x-us" } },
+        "power_types": [
+            { "key": { "href": "https://us.api.blizzard.com/data/wow/power-type/0?namespace=static-classic1x-us" }, "name": "Mana", "id": 0 }
+        ]
+    }"#;
+
+    #[tokio::test]
+    async fn sends_era_dynamic_namespace_and_tags_realms() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::EU, Locale::EnglishGB);
+
+        // Act
+        let realm = client.classic_era().get_realm("atiesh").await;
+        let realm_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert_eq!(realm.unwrap().unwrap().flavor, Some(ClassicFlavor::Era));
+        assert_eq!(
+            realm_request.query,
+            Some("namespace=dynamic-classic1x-eu&locale=en_GB".to_string())
+        );
+        assert_eq!(
+            realm_request.headers.get("Battlenet-Namespace").unwrap(),
+            "dynamic-classic1x-eu"
+        );
+    }
+
+    #[tokio::test]
+    async fn sends_era_static_namespace_for_game_data() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/power-type/index", POWER_TYPES_INDEX);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let power_types = client
+            .classic_with_flavor(ClassicFlavor::Era)
+            .get_power_types()
+            .await;
+        let power_types_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert!(power_types.is_ok());
+        assert_eq!(
+            power_types_request.query,
+            Some("namespace=static-classic1x-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn tags_realms_from_index_and_connected_realms_with_progression_flavor() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let (index, connected_realm) =
+            tokio::join!(classic.get_realms(), classic.get_connected_realm(4372));
+
+        // Assert
+        assert!(index
+            .unwrap()
+            .realms
+            .iter()
+            .all(|realm| realm.flavor == Some(ClassicFlavor::Progression)));
+        assert!(connected_realm
+            .unwrap()
+            .unwrap()
+            .realms
+            .iter()
+            .all(|realm| realm.flavor == Some(ClassicFlavor::Progression)));
+    }
+
+    #[tokio::test]
+    async fn tags_paginated_realm_search_results_with_era_flavor() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = SearchQuery::new().filter(RealmSearchField::Timezone, "America/New_York");
+
+        // Act
+        let realms: Vec<_> = client
+            .classic_era()
+            .search_realms_paginator(&query)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        let search_query = server
+            .received_requests()
+            .into_iter()
+            .filter(|r| r.path == "/data/wow/search/realm")
+            .find_map(|r| r.query);
+
+        // Assert
+        assert!(!realms.is_empty());
+        assert!(realms
+            .iter()
+            .all(|realm| realm.data.flavor == Some(ClassicFlavor::Era)));
+        assert!(search_query
+            .unwrap()
+            .starts_with("namespace=dynamic-classic1x-us&"));
+    }
+}
@@ -0,0 +1,205 @@
+#![cfg(feature = "classic")]
+
+mod classic_guild_crest_tests {
+    use bubblehearth::localization::Locale;
+    use bubblehearth::media::MediaAssetKey;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const GUILD_CREST_INDEX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/guild-crest/?namespace=static-classic-us"
+            }
+          },
+          "emblems": [
+            {
+              "id": 126,
+              "media": {
+                "key": {
+                  "href": "https://us.api.blizzard.com/data/wow/media/guild-crest/emblem/126?namespace=static-classic-us"
+                },
+                "id": 126
+              }
+            }
+          ],
+          "borders": [
+            {
+              "id": 0,
+              "media": {
+                "key": {
+                  "href": "https://us.api.blizzard.com/data/wow/media/guild-crest/border/0?namespace=static-classic-us"
+                },
+                "id": 0
+              }
+            }
+          ],
+          "colors": {
+            "emblems": [{ "id": 14, "rgba": { "r": 177, "g": 184, "b": 177, "a": 1.0 } }],
+            "borders": [{ "id": 15, "rgba": { "r": 58, "g": 63, "b": 64, "a": 1.0 } }],
+            "backgrounds": [{ "id": 45, "rgba": { "r": 103, "g": 0, "b": 0, "a": 1.0 } }]
+          }
+        }
+    "#;
+
+    const GUILD_CREST_BORDER_MEDIA: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/media/guild-crest/border/0?namespace=static-classic-us"
+            }
+          },
+          "assets": [
+            {
+              "key": "image",
+              "value": "https://render.worldofwarcraft.com/classic-us/guild/tabards/border_00.png"
+            }
+          ],
+          "id": 0
+        }
+    "#;
+
+    async fn get_guild_crest_server() -> FakeBlizzardServer {
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/guild-crest/index?namespace=static-classic-us",
+            GUILD_CREST_INDEX,
+        );
+        server.register_fixture(
+            "/data/wow/media/guild-crest/border/0?namespace=static-classic-us",
+            GUILD_CREST_BORDER_MEDIA,
+        );
+        server
+    }
+
+    #[tokio::test]
+    async fn returns_guild_crest_components_with_colors() {
+        // Arrange
+        let server = get_guild_crest_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let components = client.classic().get_guild_crest_components().await.unwrap();
+
+        // Assert
+        assert_eq!(components.emblems[0].id, 126);
+        assert_eq!(components.borders[0].media.as_ref().unwrap().id, 0);
+        assert_eq!(components.colors.emblems[0].rgba.r, 177);
+        assert_eq!(components.colors.backgrounds[0].id, 45);
+    }
+
+    #[tokio::test]
+    async fn returns_border_media_and_none_for_unknown_emblem() {
+        // Arrange
+        let server = get_guild_crest_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let border_media = classic.get_guild_crest_border_media(0).await.unwrap();
+        let emblem_media = classic.get_guild_crest_emblem_media(999).await.unwrap();
+
+        // Assert
+        assert_eq!(border_media.unwrap().assets[0].key, MediaAssetKey::Image);
+        assert!(emblem_media.is_none());
+    }
+
+    #[cfg(feature = "crest")]
+    #[tokio::test]
+    async fn returns_error_when_crest_layer_media_not_found() {
+        use bubblehearth::classic::crest::GuildCrestSelection;
+        use bubblehearth::errors::BubbleHearthError;
+
+        // Arrange
+        let server = get_guild_crest_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let selection = GuildCrestSelection {
+            emblem_id: 999,
+            emblem_color_id: 14,
+            border_id: 0,
+            border_color_id: 15,
+            background_color_id: 45,
+        };
+
+        // Act
+        let layers = client.classic().get_guild_crest_layers(&selection).await;
+
+        // Assert
+        assert!(matches!(
+            layers,
+            Err(BubbleHearthError::GuildCrestRenderFailed(_))
+        ));
+    }
+
+    #[cfg(feature = "crest")]
+    #[tokio::test]
+    async fn replays_downloaded_layers_from_cassette() {
+        use bubblehearth::cassette::{BodyEncoding, Cassette, CassetteMode};
+        use bubblehearth::classic::crest::GuildCrestSelection;
+        use bubblehearth::testing::FAKE_ASSETS_PATH;
+
+        // Arrange
+        let server = get_guild_crest_server().await;
+        for layer in ["emblem/126", "border/0"] {
+            server.register_fixture(
+                &format!(
+                    "/data/wow/media/guild-crest/{}?namespace=static-classic-us",
+                    layer
+                ),
+                format!(
+                    r#"{{ "assets": [{{ "key": "image", "value": "{}{}{}.png" }}], "id": 0 }}"#,
+                    server.url(),
+                    FAKE_ASSETS_PATH,
+                    layer
+                ),
+            );
+            server.register_fixture(&format!("{}{}.png", FAKE_ASSETS_PATH, layer), layer);
+        }
+        let path = std::env::temp_dir()
+            .join(format!("bubblehearth-cassettes-{}", std::process::id()))
+            .join("replays_downloaded_layers_from_cassette.json");
+        let get_client = |mode| {
+            server
+                .client_builder()
+                .with_region(AccountRegion::US)
+                .with_locale(Locale::EnglishUS)
+                .with_cassette(mode)
+                .build()
+                .unwrap()
+        };
+        let selection = GuildCrestSelection {
+            emblem_id: 126,
+            emblem_color_id: 14,
+            border_id: 0,
+            border_color_id: 15,
+            background_color_id: 45,
+        };
+        let recording_client = get_client(CassetteMode::Record(path.clone()));
+        let recorded_layers = recording_client
+            .classic()
+            .get_guild_crest_layers(&selection)
+            .await
+            .unwrap();
+        recording_client.save_cassette().unwrap();
+        let sent_requests = server.received_requests().len();
+        let replaying_client = get_client(CassetteMode::Replay(path.clone()));
+
+        // Act
+        let replayed_layers = replaying_client
+            .classic()
+            .get_guild_crest_layers(&selection)
+            .await
+            .unwrap();
+        let cassette = Cassette::load(&path).unwrap();
+
+        // Assert
+        assert_eq!(replayed_layers, recorded_layers);
+        assert_eq!(replayed_layers.emblem, b"emblem/126".to_vec());
+        assert_eq!(server.received_requests().len(), sent_requests);
+        assert!(cassette
+            .interactions
+            .iter()
+            .any(|interaction| interaction.response.encoding == BodyEncoding::Base64));
+    }
+}
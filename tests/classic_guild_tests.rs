@@ -0,0 +1,269 @@
+#![cfg(feature = "classic")]
+
+mod classic_guild_tests {
+    use bubblehearth::factions::Faction;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const GUILD: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/guild/atiesh/bobs-raiding-team?namespace=profile-classic-us"
+            }
+          },
+          "id": 42,
+          "name": "Bob's Raiding Team",
+          "faction": { "type": "ALLIANCE", "name": "Alliance" },
+          "achievement_points": 1250,
+          "member_count": 3,
+          "realm": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/realm/4372?namespace=dynamic-classic-us"
+            },
+            "name": "Atiesh",
+            "id": 4372,
+            "slug": "atiesh"
+          },
+          "crest": {
+            "emblem": {
+              "id": 126,
+              "media": {
+                "key": {
+                  "href": "https://us.api.blizzard.com/data/wow/media/guild-crest/emblem/126?namespace=static-classic-us"
+                },
+                "id": 126
+              },
+              "color": { "id": 15, "rgba": { "r": 255, "g": 255, "b": 255, "a": 1.0 } }
+            },
+            "border": {
+              "id": 0,
+              "media": {
+                "key": {
+                  "href": "https://us.api.blizzard.com/data/wow/media/guild-crest/border/0?namespace=static-classic-us"
+                },
+                "id": 0
+              },
+              "color": { "id": 2, "rgba": { "r": 103, "g": 76, "b": 20, "a": 1.0 } }
+            },
+            "background": {
+              "color": { "id": 45, "rgba": { "r": 0, "g": 59, "b": 79, "a": 1.0 } }
+            }
+          },
+          "created_timestamp": 1566267600000,
+          "roster": {
+            "href": "https://us.api.blizzard.com/data/wow/guild/atiesh/bobs-raiding-team/roster?namespace=profile-classic-us"
+          },
+          "achievements": {
+            "href": "https://us.api.blizzard.com/data/wow/guild/atiesh/bobs-raiding-team/achievements?namespace=profile-classic-us"
+          }
+        }
+    "#;
+
+    const GUILD_ROSTER: &str = r#"
+        {
+          "guild": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/guild/atiesh/bobs-raiding-team?namespace=profile-classic-us"
+            },
+            "name": "Bob's Raiding Team",
+            "id": 42,
+            "realm": { "name": "Atiesh", "id": 4372, "slug": "atiesh" },
+            "faction": { "type": "ALLIANCE", "name": "Alliance" }
+          },
+          "members": [
+            {
+              "character": {
+                "key": {
+                  "href": "https://us.api.blizzard.com/profile/wow/character/atiesh/bob?namespace=profile-classic-us"
+                },
+                "name": "Bob",
+                "id": 1,
+                "realm": { "id": 4372, "slug": "atiesh" },
+                "level": 60,
+                "playable_class": {
+                  "key": {
+                    "href": "https://us.api.blizzard.com/data/wow/playable-class/1?namespace=static-classic-us"
+                  },
+                  "id": 1
+                },
+                "playable_race": {
+                  "key": {
+                    "href": "https://us.api.blizzard.com/data/wow/playable-race/1?namespace=static-classic-us"
+                  },
+                  "id": 1
+                }
+              },
+              "rank": 0
+            },
+            {
+              "character": {
+                "name": "Alice",
+                "id": 2,
+                "realm": { "id": 4372, "slug": "atiesh" },
+                "level": 58,
+                "playable_class": { "id": 8 },
+                "playable_race": { "id": 7 }
+              },
+              "rank": 4
+            },
+            {
+              "character": {
+                "name": "Eve",
+                "id": 3,
+                "realm": { "id": 4372, "slug": "atiesh" },
+                "level": 60,
+                "playable_class": { "id": 5 },
+                "playable_race": { "id": 3 }
+              },
+              "rank": 4
+            }
+          ]
+        }
+    "#;
+
+    const GUILD_ACHIEVEMENTS: &str = r#"
+        {
+          "guild": { "name": "Bob's Raiding Team", "id": 42 },
+          "total_quantity": 1,
+          "total_points": 10,
+          "achievements": [
+            {
+              "id": 4912,
+              "achievement": { "name": "Guild Level 25", "id": 4912 },
+              "criteria": { "id": 13777, "is_completed": true },
+              "completed_timestamp": 1600000000000
+            },
+            {
+              "id": 5179,
+              "achievement": { "name": "Guild Glory of the Raider", "id": 5179 },
+              "criteria": {
+                "id": 14563,
+                "is_completed": false,
+                "child_criteria": [{ "id": 14564, "amount": 3, "is_completed": false }]
+              }
+            }
+          ]
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_guild_with_slugified_name_from_profile_namespace() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/guild/atiesh/bobs-raiding-team", GUILD);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let guild = client
+            .classic()
+            .get_guild("atiesh", "Bob's Raiding Team")
+            .await
+            .unwrap()
+            .unwrap();
+        let guild_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert_eq!(guild.name, "Bob's Raiding Team");
+        assert_eq!(guild.member_count, Some(3));
+        assert_eq!(guild.faction.unwrap().faction_type, Faction::Alliance);
+        assert_eq!(guild.crest.unwrap().background.color.id, 45);
+        assert!(guild.roster.is_some());
+        assert_eq!(
+            guild_request.query,
+            Some("namespace=profile-classic-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_guild_not_found() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let guild = client
+            .classic_era()
+            .get_guild("atiesh", "Not A Guild")
+            .await;
+        let guild_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert!(guild.unwrap().is_none());
+        assert_eq!(guild_request.path, "/data/wow/guild/atiesh/not-a-guild");
+        assert_eq!(
+            guild_request.query,
+            Some("namespace=profile-classic1x-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_roster_members_with_rank_level_and_class() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/guild/atiesh/bobs-raiding-team/roster",
+            GUILD_ROSTER,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let roster = client
+            .classic()
+            .get_guild_roster("atiesh", "bob’s raiding team")
+            .await
+            .unwrap()
+            .unwrap();
+        let guild_master = roster.get_guild_master().unwrap();
+        let members: Vec<_> = roster
+            .get_members_by_rank(4)
+            .iter()
+            .map(|member| member.character.name.as_str())
+            .collect();
+
+        // Assert
+        assert_eq!(roster.members.len(), 3);
+        assert_eq!(guild_master.character.name, "Bob");
+        assert_eq!(guild_master.character.level, Some(60));
+        assert_eq!(
+            guild_master.character.playable_class.as_ref().unwrap().id,
+            1
+        );
+        assert_eq!(members, vec!["Alice", "Eve"]);
+    }
+
+    #[tokio::test]
+    async fn returns_guild_achievements_with_progress() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/guild/atiesh/bobs-raiding-team/achievements",
+            GUILD_ACHIEVEMENTS,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let achievements = client
+            .classic()
+            .get_guild_achievements("atiesh", "bobs-raiding-team")
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(achievements.guild.id, 42);
+        assert_eq!(achievements.total_points, Some(10));
+        assert!(achievements.achievements[0].is_completed());
+        assert!(!achievements.achievements[1].is_completed());
+        assert_eq!(
+            achievements.achievements[1]
+                .criteria
+                .as_ref()
+                .unwrap()
+                .child_criteria[0]
+                .amount,
+            Some(3.0)
+        );
+    }
+}
@@ -0,0 +1,301 @@
+#![cfg(feature = "classic")]
+
+mod classic_item_tests {
+    use bubblehearth::classic::items::{InventoryType, ItemBinding, ItemQuality, ItemSearchField};
+    use bubblehearth::localization::{Locale, StringOrStructLocale};
+    use bubblehearth::media::MediaAssetKey;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::SearchQuery;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const ITEM_CLASS: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/item-class/2?namespace=static-classic-us"
+            }
+          },
+          "class_id": 2,
+          "name": "Weapon",
+          "item_subclasses": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/item-class/2/item-subclass/7?namespace=static-classic-us"
+              },
+              "name": "One-Handed Swords",
+              "id": 7
+            }
+          ]
+        }
+    "#;
+
+    const ITEM: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/item/19019?namespace=static-classic-us"
+            }
+          },
+          "id": 19019,
+          "name": "Thunderfury, Blessed Blade of the Windseeker",
+          "quality": { "type": "LEGENDARY", "name": "Legendary" },
+          "level": 80,
+          "required_level": 60,
+          "media": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/media/item/19019?namespace=static-classic-us"
+            },
+            "id": 19019
+          },
+          "item_class": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/item-class/2?namespace=static-classic-us"
+            },
+            "name": "Weapon",
+            "id": 2
+          },
+          "item_subclass": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/item-class/2/item-subclass/7?namespace=static-classic-us"
+            },
+            "name": "One-Handed Swords",
+            "id": 7
+          },
+          "inventory_type": { "type": "WEAPON", "name": "One-Hand" },
+          "purchase_price": 1529564,
+          "sell_price": 305912,
+          "max_count": 1,
+          "is_equippable": true,
+          "is_stackable": false,
+          "preview_item": {
+            "item": {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/item/19019?namespace=static-classic-us"
+              },
+              "id": 19019
+            },
+            "quality": { "type": "LEGENDARY", "name": "Legendary" },
+            "name": "Thunderfury, Blessed Blade of the Windseeker",
+            "binding": { "type": "ON_ACQUIRE", "name": "Binds when picked up" },
+            "unique_equipped": "Unique",
+            "weapon": {
+              "damage": {
+                "min_value": 44,
+                "max_value": 115,
+                "display_string": "44 - 115 Damage",
+                "damage_class": { "type": "PHYSICAL", "name": "Physical" }
+              },
+              "attack_speed": { "value": 1900, "display_string": "Speed 1.90" },
+              "dps": { "value": 53.94737, "display_string": "(53.9 damage per second)" }
+            },
+            "stats": [
+              {
+                "type": { "type": "AGILITY", "name": "Agility" },
+                "value": 5,
+                "display": {
+                  "display_string": "+5 Agility",
+                  "color": { "r": 255, "g": 255, "b": 255, "a": 1.0 }
+                }
+              },
+              {
+                "type": { "type": "NATURE_RESISTANCE", "name": "Nature Resistance" },
+                "value": 9,
+                "display": {
+                  "display_string": "+9 Nature Resistance",
+                  "color": { "r": 255, "g": 255, "b": 255, "a": 1.0 }
+                }
+              }
+            ],
+            "spells": [
+              {
+                "spell": {
+                  "key": {
+                    "href": "https://us.api.blizzard.com/data/wow/spell/21992?namespace=static-classic-us"
+                  },
+                  "name": "Thunderfury",
+                  "id": 21992
+                },
+                "description": "Chance on hit: Blasts your enemy with lightning."
+              }
+            ],
+            "sell_price": {
+              "value": 305912,
+              "display_strings": {
+                "header": "Sell Price:",
+                "gold": "30",
+                "silver": "59",
+                "copper": "12"
+              }
+            },
+            "requirements": {
+              "level": { "value": 60, "display_string": "Requires Level 60" }
+            },
+            "level": { "value": 80, "display_string": "Item Level 80" },
+            "durability": { "value": 125, "display_string": "Durability 125 / 125" }
+          }
+        }
+    "#;
+
+    const ITEM_MEDIA: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/media/item/19019?namespace=static-classic-us"
+            }
+          },
+          "assets": [
+            {
+              "key": "icon",
+              "value": "https://render.worldofwarcraft.com/classic-us/icons/56/inv_sword_39.jpg",
+              "file_data_id": 135349
+            }
+          ],
+          "id": 19019
+        }
+    "#;
+
+    const ITEM_SEARCH: &str = r#"
+        {
+          "page": 1,
+          "pageSize": 1,
+          "maxPageSize": 100,
+          "pageCount": 1,
+          "results": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/item/19019?namespace=static-classic-us"
+              },
+              "data": {
+                "level": 80,
+                "required_level": 60,
+                "sell_price": 305912,
+                "item_subclass": { "name": "One-Handed Swords", "id": 7 },
+                "is_equippable": true,
+                "purchase_quantity": 1,
+                "media": { "id": 19019 },
+                "item_class": { "name": "Weapon", "id": 2 },
+                "quality": { "name": "Legendary", "type": "LEGENDARY" },
+                "max_count": 1,
+                "is_stackable": false,
+                "name": "Thunderfury, Blessed Blade of the Windseeker",
+                "purchase_price": 1529564,
+                "id": 19019,
+                "inventory_type": { "name": "One-Hand", "type": "WEAPON" }
+              }
+            }
+          ]
+        }
+    "#;
+
+    async fn get_item_server() -> FakeBlizzardServer {
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/item-class/2?namespace=static-classic-us",
+            ITEM_CLASS,
+        );
+        server.register_fixture("/data/wow/item/19019?namespace=static-classic-us", ITEM);
+        server.register_fixture(
+            "/data/wow/media/item/19019?namespace=static-classic-us",
+            ITEM_MEDIA,
+        );
+        server.register_fixture(
+            "/data/wow/search/item?namespace=static-classic-us&quality.type=LEGENDARY",
+            ITEM_SEARCH,
+        );
+        server
+    }
+
+    #[tokio::test]
+    async fn returns_item_class_with_subclasses() {
+        // Arrange
+        let server = get_item_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let item_class = client.classic().get_item_class(2).await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(item_class.class_id, 2);
+        assert_eq!(item_class.item_subclasses[0].id, 7);
+        assert_eq!(
+            item_class.item_subclasses[0].name,
+            Some(StringOrStructLocale::StringLocale(
+                "One-Handed Swords".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_item_with_typed_quality_and_tooltip_preview() {
+        // Arrange
+        let server = get_item_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let item = client.classic().get_item(19019).await.unwrap().unwrap();
+        let preview = item.preview_item.unwrap();
+        let weapon = preview.weapon.unwrap();
+
+        // Assert
+        assert_eq!(item.quality.unwrap().quality_type, ItemQuality::Legendary);
+        assert_eq!(
+            item.inventory_type.unwrap().inventory_type,
+            InventoryType::Weapon
+        );
+        assert_eq!(item.sell_price, Some(305912));
+        assert_eq!(
+            preview.binding.unwrap().binding_type,
+            ItemBinding::OnAcquire
+        );
+        assert_eq!(weapon.damage.unwrap().max_value, 115);
+        assert_eq!(weapon.attack_speed.unwrap().value, 1900);
+        assert_eq!(preview.stats.len(), 2);
+        assert_eq!(preview.stats[1].stat_type.stat_type, "NATURE_RESISTANCE");
+        assert_eq!(preview.spells[0].spell.as_ref().unwrap().id, 21992);
+        assert_eq!(preview.sell_price.unwrap().value, 305912);
+        assert_eq!(preview.requirements.unwrap().level.unwrap().value, 60);
+        assert_eq!(preview.durability.unwrap().value, 125);
+    }
+
+    #[tokio::test]
+    async fn returns_item_media_and_none_when_item_not_found() {
+        // Arrange
+        let server = get_item_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let media = classic.get_item_media(19019).await.unwrap().unwrap();
+        let items = classic.get_items_by_ids(&[19019, 19020]).await;
+
+        // Assert
+        assert_eq!(media.assets[0].key, MediaAssetKey::Icon);
+        assert_eq!(media.assets[0].file_data_id, Some(135349));
+        assert!(items[0].as_ref().unwrap().is_some());
+        assert!(items[1].as_ref().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_item_search_filtered_by_quality() {
+        // Arrange
+        let server = get_item_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = SearchQuery::new()
+            .filter(ItemSearchField::QualityType, "LEGENDARY")
+            .filter_range(ItemSearchField::RequiredLevel, 50..=60);
+
+        // Act
+        let search = client.classic().search_items(&query, None).await.unwrap();
+        let item = &search.results[0].data;
+
+        // Assert
+        assert_eq!(search.results.len(), 1);
+        assert_eq!(item.id, 19019);
+        assert_eq!(
+            item.quality.as_ref().unwrap().quality_type,
+            ItemQuality::Legendary
+        );
+        assert_eq!(item.media.as_ref().unwrap().id, 19019);
+        assert!(item.preview_item.is_none());
+    }
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "classic")]
+
+mod classic_media_tests {
+    use futures::TryStreamExt;
+
+    use bubblehearth::classic::media::{MediaSearchField, MediaTag};
+    use bubblehearth::localization::Locale;
+    use bubblehearth::media::MediaAssetKey;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::{SearchQuery, SortOrder};
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const MEDIA_SEARCH: &str = r#"
+        {
+          "page": 1,
+          "pageSize": 2,
+          "maxPageSize": 100,
+          "pageCount": 1,
+          "results": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/media/item/19019?namespace=static-classic-us"
+              },
+              "data": {
+                "assets": [
+                  {
+                    "key": "icon",
+                    "value": "https://render.worldofwarcraft.com/classic-us/icons/56/inv_sword_39.jpg",
+                    "file_data_id": 135349
+                  }
+                ],
+                "id": 19019
+              }
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/media/item/19364?namespace=static-classic-us"
+              },
+              "data": {
+                "assets": [
+                  {
+                    "key": "icon",
+                    "value": "https://render.worldofwarcraft.com/classic-us/icons/56/inv_axe_09.jpg",
+                    "file_data_id": 135424
+                  }
+                ],
+                "id": 19364
+              }
+            }
+          ]
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_media_with_typed_asset_keys_when_searched_by_tag() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/search/media", MEDIA_SEARCH);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = SearchQuery::new()
+            .filter(MediaSearchField::Tags, MediaTag::Item)
+            .order_by(MediaSearchField::Id, SortOrder::Ascending);
+
+        // Act
+        let search = client.classic().search_media(&query, None).await.unwrap();
+        let search_query = server.received_requests().pop().unwrap().query;
+        let icon = search.results[0]
+            .data
+            .get_asset(MediaAssetKey::Icon)
+            .unwrap();
+
+        // Assert
+        assert_eq!(search.results.len(), 2);
+        assert_eq!(search.results[1].data.id, Some(19364));
+        assert_eq!(icon.file_data_id, Some(135349));
+        assert!(icon.value.ends_with("inv_sword_39.jpg"));
+        assert_eq!(
+            search_query,
+            Some("namespace=static-classic-us&tags=item&orderby=id:asc&_page=1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_all_media_from_paginator_in_era_namespace() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/search/media", MEDIA_SEARCH);
+        let client = server.client(AccountRegion::EU, Locale::EnglishGB);
+        let query = SearchQuery::new().filter_any(
+            MediaSearchField::Tags,
+            [MediaTag::Creature, MediaTag::Spell],
+        );
+
+        // Act
+        let media: Vec<_> = client
+            .classic_era()
+            .search_media_paginator(&query)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        let search_query = server.received_requests().pop().unwrap().query;
+
+        // Assert
+        assert_eq!(media.len(), 2);
+        assert!(media
+            .iter()
+            .all(|item| item.data.assets[0].key == MediaAssetKey::Icon));
+        assert_eq!(
+            search_query,
+            Some("namespace=static-classic1x-eu&tags=creature||spell&_page=1".to_string())
+        );
+    }
+}
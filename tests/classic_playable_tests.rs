@@ -0,0 +1,211 @@
+#![cfg(feature = "classic")]
+
+mod classic_playable_tests {
+    use bubblehearth::factions::Faction;
+    use bubblehearth::localization::{Locale, StringOrStructLocale};
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const PLAYABLE_CLASSES_INDEX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/playable-class/?namespace=static-classic-us"
+            }
+          },
+          "classes": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/playable-class/1?namespace=static-classic-us"
+              },
+              "name": "Warrior",
+              "id": 1
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/playable-class/11?namespace=static-classic-us"
+              },
+              "name": "Druid",
+              "id": 11
+            }
+          ]
+        }
+    "#;
+
+    const PLAYABLE_CLASS: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/playable-class/1?namespace=static-classic-us"
+            }
+          },
+          "id": 1,
+          "name": "Warrior",
+          "gender_name": { "male": "Warrior", "female": "Warrior" },
+          "power_type": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/power-type/1?namespace=static-classic-us"
+            },
+            "name": "Rage",
+            "id": 1
+          },
+          "media": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/media/playable-class/1?namespace=static-classic-us"
+            },
+            "id": 1
+          }
+        }
+    "#;
+
+    const PLAYABLE_RACE: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/playable-race/2?namespace=static-classic-us"
+            }
+          },
+          "id": 2,
+          "name": "Orc",
+          "gender_name": { "male_name": "Orc", "female_name": "Orc" },
+          "faction": { "type": "HORDE", "name": "Horde" },
+          "is_selectable": true,
+          "is_allied_race": false,
+          "playable_classes": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/playable-class/1?namespace=static-classic-us"
+              },
+              "name": "Warrior",
+              "id": 1
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/playable-class/3?namespace=static-classic-us"
+              },
+              "name": "Hunter",
+              "id": 3
+            }
+          ]
+        }
+    "#;
+
+    const POWER_TYPE: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/power-type/1?namespace=static-classic-us"
+            }
+          },
+          "id": 1,
+          "name": {
+            "en_US": "Rage",
+            "es_MX": "Ira",
+            "pt_BR": "Raiva",
+            "de_DE": "Wut",
+            "en_GB": "Rage",
+            "es_ES": "Ira",
+            "fr_FR": "Rage",
+            "it_IT": "Ira",
+            "ru_RU": "Ярость",
+            "ko_KR": "분노",
+            "zh_TW": "怒氣",
+            "zh_CN": "怒气"
+          }
+        }
+    "#;
+
+    async fn get_playable_server() -> FakeBlizzardServer {
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/playable-class/index?namespace=static-classic-us",
+            PLAYABLE_CLASSES_INDEX,
+        );
+        server.register_fixture(
+            "/data/wow/playable-class/1?namespace=static-classic-us",
+            PLAYABLE_CLASS,
+        );
+        server.register_fixture(
+            "/data/wow/playable-race/2?namespace=static-classic-us",
+            PLAYABLE_RACE,
+        );
+        server.register_fixture(
+            "/data/wow/power-type/1?namespace=static-classic-us",
+            POWER_TYPE,
+        );
+        server
+    }
+
+    #[tokio::test]
+    async fn returns_playable_classes_and_class_with_power_type() {
+        // Arrange
+        let server = get_playable_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let index = classic.get_playable_classes().await.unwrap();
+        let warrior = classic.get_playable_class(1).await.unwrap().unwrap();
+        let power_type = client
+            .resolve(warrior.power_type.as_ref().unwrap().key.as_ref().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(index.classes.len(), 2);
+        assert_eq!(
+            warrior.gender_name.unwrap().female,
+            StringOrStructLocale::StringLocale("Warrior".to_string())
+        );
+        assert_eq!(warrior.media.unwrap().id, 1);
+        assert!(matches!(
+            power_type.name,
+            StringOrStructLocale::StructLocale(ref name) if name.de_de == "Wut"
+        ));
+    }
+
+    #[tokio::test]
+    async fn returns_playable_race_with_faction_and_classes() {
+        // Arrange
+        let server = get_playable_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let orc = client
+            .classic()
+            .get_playable_race(2)
+            .await
+            .unwrap()
+            .unwrap();
+        let class_ids: Vec<_> = orc.playable_classes.iter().map(|c| c.id).collect();
+
+        // Assert
+        assert_eq!(orc.faction.unwrap().faction_type, Faction::Horde);
+        assert_eq!(
+            orc.gender_name.unwrap().male,
+            StringOrStructLocale::StringLocale("Orc".to_string())
+        );
+        assert_eq!(class_ids, vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_playable_data_not_found() {
+        // Arrange
+        let server = get_playable_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let playable_class = classic.get_playable_class(12).await;
+        let playable_race = classic.get_playable_race(52).await;
+        let power_type = classic.get_power_type(42).await;
+        let media = classic.get_playable_class_media(12).await;
+
+        // Assert
+        assert!(playable_class.unwrap().is_none());
+        assert!(playable_race.unwrap().is_none());
+        assert!(power_type.unwrap().is_none());
+        assert!(media.unwrap().is_none());
+    }
+}
@@ -0,0 +1,233 @@
+#![cfg(feature = "classic")]
+
+mod classic_pvp_tests {
+    use bubblehearth::classic::pvp::PvpBracket;
+    use bubblehearth::errors::BubbleHearthError;
+    use bubblehearth::factions::Faction;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const PVP_SEASONS_INDEX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/pvp-season/?namespace=dynamic-classic-us"
+            }
+          },
+          "seasons": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/pvp-season/5?namespace=dynamic-classic-us"
+              },
+              "id": 5
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/pvp-season/6?namespace=dynamic-classic-us"
+              },
+              "id": 6
+            }
+          ],
+          "current_season": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/pvp-season/6?namespace=dynamic-classic-us"
+            },
+            "id": 6
+          }
+        }
+    "#;
+
+    const PVP_SEASON: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/pvp-region/1/pvp-season/6?namespace=dynamic-classic-us"
+            }
+          },
+          "id": 6,
+          "leaderboards": {
+            "href": "https://us.api.blizzard.com/data/wow/pvp-region/1/pvp-season/6/pvp-leaderboard/?namespace=dynamic-classic-us"
+          },
+          "season_start_timestamp": 1700000000000
+        }
+    "#;
+
+    const PVP_REGIONS_INDEX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/pvp-region/?namespace=dynamic-classic-us"
+            }
+          },
+          "pvp_regions": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/pvp-region/1/pvp-season/?namespace=dynamic-classic-us"
+              },
+              "name": "North America",
+              "id": 1
+            }
+          ]
+        }
+    "#;
+
+    const PVP_LEADERBOARD: &str = r#"
+        {
+          "season": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/pvp-region/1/pvp-season/6?namespace=dynamic-classic-us"
+            },
+            "id": 6
+          },
+          "name": "3v3",
+          "bracket": { "id": 1, "type": "ARENA_3v3" },
+          "entries": [
+            {
+              "character": {
+                "name": "Thrall",
+                "id": 1337,
+                "realm": {
+                  "key": {
+                    "href": "https://us.api.blizzard.com/data/wow/realm/4372?namespace=dynamic-classic-us"
+                  },
+                  "id": 4372,
+                  "slug": "atiesh"
+                }
+              },
+              "faction": { "type": "HORDE" },
+              "rank": 1,
+              "rating": 2650,
+              "season_match_statistics": { "played": 120, "won": 100, "lost": 20 }
+            },
+            {
+              "character": {
+                "name": "Jaina",
+                "id": 4242,
+                "realm": { "id": 4372, "slug": "atiesh" }
+              },
+              "faction": { "type": "ALLIANCE" },
+              "rank": 2,
+              "rating": 2580,
+              "season_match_statistics": { "played": 90, "won": 70, "lost": 20 }
+            }
+          ]
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_pvp_seasons_with_current_season() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/pvp-season/index", PVP_SEASONS_INDEX);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let seasons = client.classic().get_pvp_seasons().await.unwrap();
+        let seasons_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert_eq!(seasons.seasons.len(), 2);
+        assert_eq!(seasons.current_season.unwrap().id, 6);
+        assert_eq!(
+            seasons_request.query,
+            Some("namespace=dynamic-classic-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_pvp_regions_and_region_season() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/pvp-region/index", PVP_REGIONS_INDEX);
+        server.register_fixture("/data/wow/pvp-region/1/pvp-season/6", PVP_SEASON);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let (regions, season, missing_season) = tokio::join!(
+            classic.get_pvp_regions(),
+            classic.get_pvp_region_season(1, 6),
+            classic.get_pvp_region_season(1, 99)
+        );
+        let season = season.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(regions.unwrap().pvp_regions[0].id, 1);
+        assert_eq!(season.id, 6);
+        assert!(season.leaderboards.is_some());
+        assert_eq!(
+            season.get_season_start().unwrap().unix_timestamp(),
+            1700000000
+        );
+        assert!(missing_season.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_region_leaderboard_entries_for_bracket() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/pvp-region/1/pvp-season/6/pvp-leaderboard/3v3",
+            PVP_LEADERBOARD,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let leaderboard = client
+            .classic()
+            .get_pvp_region_leaderboard(1, 6, PvpBracket::Arena3v3)
+            .await
+            .unwrap()
+            .unwrap();
+        let leader = &leaderboard.entries[0];
+
+        // Assert
+        assert_eq!(
+            leaderboard.bracket.unwrap().bracket_type,
+            PvpBracket::Arena3v3
+        );
+        assert_eq!(leaderboard.entries.len(), 2);
+        assert_eq!(leader.character.name, "Thrall");
+        assert_eq!(leader.character.realm.as_ref().unwrap().slug, "atiesh");
+        assert_eq!(
+            leader.faction.as_ref().unwrap().faction_type,
+            Faction::Horde
+        );
+        assert_eq!(leader.rating, 2650);
+        assert_eq!(leader.season_match_statistics.unwrap().won, 100);
+    }
+
+    #[tokio::test]
+    async fn returns_season_leaderboard_for_bracket_and_error_for_unknown_bracket() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/pvp-season/6/pvp-leaderboard/3v3",
+            PVP_LEADERBOARD,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let classic = client.classic();
+
+        // Act
+        let leaderboard = classic.get_pvp_leaderboard(6, PvpBracket::Arena3v3).await;
+        let unknown_leaderboard = classic.get_pvp_leaderboard(6, PvpBracket::Unknown).await;
+        let leaderboard_requests: Vec<_> = server
+            .received_requests()
+            .into_iter()
+            .filter(|r| r.path.contains("pvp-leaderboard"))
+            .map(|r| r.path)
+            .collect();
+
+        // Assert
+        assert_eq!(leaderboard.unwrap().unwrap().entries[1].rank, 2);
+        assert!(matches!(
+            unknown_leaderboard,
+            Err(BubbleHearthError::PvpBracketUnknown)
+        ));
+        assert_eq!(
+            leaderboard_requests,
+            vec!["/data/wow/pvp-season/6/pvp-leaderboard/3v3".to_string()]
+        );
+    }
+}
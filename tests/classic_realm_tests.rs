@@ -1,7 +1,11 @@
+#![cfg(feature = "classic")]
+
 mod classic_realm_tests {
+    use bubblehearth::classic::realms::RealmSearchField;
     use bubblehearth::client::BubbleHearthClient;
     use bubblehearth::localization::{Locale, StringOrStructLocale};
     use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::SearchQuery;
     use bubblehearth::timezone::Timezone;
 
     pub fn get_default_client() -> BubbleHearthClient {
@@ -79,7 +83,10 @@ mod classic_realm_tests {
         let client = get_default_client();
 
         // Act
-        let realm_search = client.classic().search_realms(None, None, None).await;
+        let realm_search = client
+            .classic()
+            .search_realms(&SearchQuery::new(), None)
+            .await;
         let is_ok = realm_search.is_ok();
         let realm_data = realm_search.unwrap();
 
@@ -99,7 +106,10 @@ mod classic_realm_tests {
         let client = get_default_client();
 
         // Act
-        let realm_search = client.classic().search_realms(None, None, Some(12)).await;
+        let realm_search = client
+            .classic()
+            .search_realms(&SearchQuery::new(), Some(12))
+            .await;
         let is_ok = realm_search.is_ok();
         let realm_data = realm_search.unwrap();
 
@@ -120,9 +130,13 @@ mod classic_realm_tests {
 
         // Act
         let classic = client.classic();
+        let us_west_query =
+            SearchQuery::new().filter(RealmSearchField::Timezone, Timezone::AmericaLosAngeles);
+        let us_east_query =
+            SearchQuery::new().filter(RealmSearchField::Timezone, Timezone::AmericaNewYork);
         let (us_west_realms, us_east_realms) = tokio::join!(
-            classic.search_realms(Some(Timezone::AmericaLosAngeles), None, None),
-            classic.search_realms(Some(Timezone::AmericaNewYork), None, None)
+            classic.search_realms(&us_west_query, None),
+            classic.search_realms(&us_east_query, None)
         );
         let us_west_realms_ok = us_west_realms.is_ok();
         let us_east_realms_ok = us_west_realms.is_ok();
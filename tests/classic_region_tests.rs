@@ -1,3 +1,5 @@
+#![cfg(feature = "classic")]
+
 mod classic_realm_tests {
     use bubblehearth::client::BubbleHearthClient;
     use bubblehearth::localization::Locale;
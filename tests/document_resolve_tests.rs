@@ -0,0 +1,166 @@
+#![cfg(feature = "classic")]
+
+mod document_resolve_tests {
+    use bubblehearth::builder::BubbleHearthClientBuilder;
+    use bubblehearth::classic::realms::{Realm, RealmsIndex};
+    use bubblehearth::documents::DocumentKey;
+    use bubblehearth::errors::BubbleHearthError;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::SearchQuery;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    #[tokio::test]
+    async fn resolves_typed_key_respecting_href_namespace() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let realm = client.classic().get_realm("atiesh").await.unwrap().unwrap();
+        let region_key = realm.region.unwrap().key.unwrap();
+
+        // Act
+        let region = client.resolve(&region_key).await;
+        let region_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert_eq!(region.unwrap().unwrap().id, Some(41));
+        assert_eq!(region_request.path, "/data/wow/region/41");
+        assert_eq!(
+            region_request.query,
+            Some("namespace=dynamic-classic-us&locale=en_US".to_string())
+        );
+        assert_eq!(
+            region_request.headers.get("Battlenet-Namespace").unwrap(),
+            "dynamic-classic-us"
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_search_result_item_key_into_item_model() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        server.register_fixture(
+            "/data/wow/realm/4372",
+            include_str!("../src/testing/fixtures/realm_atiesh.json"),
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let search = client
+            .classic()
+            .search_realms(&SearchQuery::new(), None)
+            .await
+            .unwrap();
+
+        // Act
+        let atiesh = client.resolve(&search.results[0].key).await;
+        let unknown_realm = client.resolve(&search.results[1].key).await;
+
+        // Assert
+        assert_eq!(atiesh.unwrap().unwrap().slug, "atiesh");
+        assert!(unknown_realm.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn resolves_links_when_cast_to_model() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        server.register_fixture(
+            "/data/wow/realm/?namespace=dynamic-classic-us",
+            include_str!("../src/testing/fixtures/realm_index.json"),
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let realms_index = client.classic().get_realms().await.unwrap();
+
+        // Act
+        let resolved_index = client
+            .resolve(&realms_index.links.self_ref.cast::<RealmsIndex>())
+            .await;
+
+        // Assert
+        let resolved_index = resolved_index.unwrap().unwrap();
+        assert_eq!(resolved_index.links, realms_index.links);
+        assert_eq!(resolved_index.realms.len(), realms_index.realms.len());
+        for (resolved_realm, realm) in resolved_index.realms.iter().zip(realms_index.realms) {
+            // Flavors are only known to connectors, so resolved realms are left untagged
+            assert_eq!(resolved_realm.flavor, None);
+            assert_eq!(
+                *resolved_realm,
+                Realm {
+                    flavor: None,
+                    ..realm
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_href_invalid() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let key: DocumentKey = DocumentKey::new("not a url");
+
+        // Act
+        let document = client.resolve(&key).await;
+
+        // Assert
+        assert!(matches!(
+            document,
+            Err(BubbleHearthError::InvalidDocumentHref(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn returns_error_without_sending_token_when_href_not_blizzard_host() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = BubbleHearthClientBuilder::new()
+            .with_client_id("client_id".to_string())
+            .with_client_secret("client_secret".to_string())
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_token_url(server.token_url())
+            .build()
+            .unwrap();
+        let key = DocumentKey::<Realm>::new(format!(
+            "{}/data/wow/realm/4372?namespace=dynamic-classic-us",
+            server.url()
+        ));
+
+        // Act
+        let realm = client.resolve(&key).await;
+
+        // Assert
+        assert!(matches!(
+            realm,
+            Err(BubbleHearthError::UntrustedDocumentHost(_))
+        ));
+        assert!(server.received_requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn returns_error_without_sending_token_when_href_not_https() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = BubbleHearthClientBuilder::new()
+            .with_client_id("client_id".to_string())
+            .with_client_secret("client_secret".to_string())
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_token_url(server.token_url())
+            .build()
+            .unwrap();
+        let key = DocumentKey::<Realm>::new(
+            "http://us.api.blizzard.com/data/wow/realm/4372?namespace=dynamic-classic-us",
+        );
+
+        // Act
+        let realm = client.resolve(&key).await;
+
+        // Assert
+        assert!(matches!(
+            realm,
+            Err(BubbleHearthError::InsecureDocumentHref(_))
+        ));
+        assert!(server.received_requests().is_empty());
+    }
+}
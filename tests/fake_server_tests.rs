@@ -0,0 +1,163 @@
+#![cfg(all(feature = "classic", feature = "hearthstone"))]
+
+mod fake_server_tests {
+    use http::{Method, StatusCode};
+
+    use bubblehearth::classic::realms::RealmSearchField;
+    use bubblehearth::client::BubbleHearthClient;
+    use bubblehearth::localization::{Locale, StringOrStructLocale};
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::SearchQuery;
+    use bubblehearth::testing::{FakeBlizzardServer, FAKE_ACCESS_TOKEN, FAKE_TOKEN_PATH};
+    use bubblehearth::timezone::Timezone;
+
+    fn get_default_client(server: &FakeBlizzardServer) -> BubbleHearthClient {
+        server.client(AccountRegion::US, Locale::EnglishUS)
+    }
+
+    #[tokio::test]
+    async fn returns_access_token_from_fake_token_endpoint() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = get_default_client(&server);
+
+        // Act
+        let token = client.get_access_token().await;
+        let token_requests = server
+            .received_requests()
+            .into_iter()
+            .filter(|r| r.method == Method::POST && r.path == FAKE_TOKEN_PATH)
+            .count();
+
+        // Assert
+        assert!(token.is_ok());
+        assert_eq!(token.unwrap(), FAKE_ACCESS_TOKEN);
+        assert_eq!(token_requests, 1);
+    }
+
+    #[tokio::test]
+    async fn returns_canned_realms_and_regions() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = get_default_client(&server);
+
+        // Act
+        let classic = client.classic();
+        let realms_index = classic.get_realms().await.unwrap();
+        let realm = classic.get_realm("atiesh").await.unwrap().unwrap();
+        let regions_index = classic.get_regions().await.unwrap();
+        let region = classic.get_region(41).await.unwrap().unwrap();
+
+        // Assert
+        assert!(!realms_index.realms.is_empty());
+        assert_eq!(
+            realm.name,
+            StringOrStructLocale::StringLocale("Atiesh".to_string())
+        );
+        assert_eq!(realm.timezone, Some(Timezone::AmericaLosAngeles));
+        assert!(!regions_index.regions.is_empty());
+        assert_eq!(region.tag, Some("US".to_string()));
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_no_fixture_registered() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = get_default_client(&server);
+
+        // Act
+        let realm = client.classic().get_realm("not atiesh").await;
+        let region = client.classic().get_region(420).await;
+
+        // Assert
+        assert!(realm.unwrap().is_none());
+        assert!(region.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_canned_realm_search_and_cards() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = get_default_client(&server);
+
+        // Act
+        let realm_search = client
+            .classic()
+            .search_realms(&SearchQuery::new(), None)
+            .await;
+        let cards = client.hearthstone().search_cards(None).await;
+
+        // Assert
+        assert_eq!(realm_search.unwrap().results.len(), 3);
+        assert_eq!(cards.unwrap().cards.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn returns_custom_fixture_matching_query_parameters() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        server.register_fixture(
+            "/data/wow/search/realm?timezone=America/New_York",
+            r#"
+            {
+              "page": 1,
+              "pageSize": 0,
+              "maxPageSize": 100,
+              "pageCount": 1,
+              "results": []
+            }
+            "#,
+        );
+        let client = get_default_client(&server);
+
+        // Act
+        let classic = client.classic();
+        let us_west_query =
+            SearchQuery::new().filter(RealmSearchField::Timezone, Timezone::AmericaLosAngeles);
+        let us_east_query =
+            SearchQuery::new().filter(RealmSearchField::Timezone, Timezone::AmericaNewYork);
+        let (us_west_realms, us_east_realms) = tokio::join!(
+            classic.search_realms(&us_west_query, None),
+            classic.search_realms(&us_east_query, None)
+        );
+
+        // Assert
+        assert!(!us_west_realms.unwrap().results.is_empty());
+        assert!(us_east_realms.unwrap().results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn returns_most_recently_registered_fixture_for_the_same_path() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        server.register_fixture(
+            "/data/wow/region/41",
+            r#"{ "_links": { "self": { "href": "" } }, "id": 41, "name": "Overridden", "tag": "US" }"#,
+        );
+        let client = get_default_client(&server);
+
+        // Act
+        let region = client.classic().get_region(41).await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(region.name, Some("Overridden".to_string()));
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_fixture_registered_with_failure_status() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture_with_status(
+            "/data/wow/realm/index",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            r#"{ "code": 500, "detail": "Internal server error" }"#,
+        );
+        let client = get_default_client(&server);
+
+        // Act
+        let realms_index = client.classic().get_realms().await;
+
+        // Assert
+        assert!(realms_index.is_err());
+    }
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "hearthstone")]
+
+mod hearthstone_card_pages_tests {
+    use futures::StreamExt;
+    use http::StatusCode;
+
+    use bubblehearth::hearthstone::card_search::CardSearchQueryBuilder;
+    use bubblehearth::hearthstone::cards::CardSearchProgress;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    fn get_cards_page(page: u32, page_count: u32, card_count: u32, ids: &[u32]) -> String {
+        let cards: Vec<String> = ids
+            .iter()
+            .map(|id| {
+                format!(
+                    r#"{{ "id": {0}, "collectible": 1, "slug": "{0}-card", "classId": 4, "multiClassIds": [],
+                        "cardTypeId": 5, "cardSetId": 1635, "rarityId": 1, "artistName": "Artist",
+                        "manaCost": 2, "name": "Card {0}", "text": "", "image": "", "imageGold": "",
+                        "flavorText": "", "cropImage": "" }}"#,
+                    id
+                )
+            })
+            .collect();
+
+        format!(
+            r#"{{ "cards": [{}], "cardCount": {}, "pageCount": {}, "page": {} }}"#,
+            cards.join(","),
+            card_count,
+            page_count,
+            page
+        )
+    }
+
+    async fn get_paged_server() -> FakeBlizzardServer {
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/hearthstone/cards?class=mage&page=1&pageSize=500",
+            get_cards_page(1, 3, 5, &[1, 2]),
+        );
+        server.register_fixture(
+            "/hearthstone/cards?class=mage&page=2&pageSize=500",
+            get_cards_page(2, 3, 5, &[3, 4]),
+        );
+        server.register_fixture(
+            "/hearthstone/cards?class=mage&page=3&pageSize=500",
+            get_cards_page(3, 3, 5, &[5]),
+        );
+        server
+    }
+
+    #[tokio::test]
+    async fn returns_all_cards_with_progress_using_search_filters() {
+        // Arrange
+        let server = get_paged_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = CardSearchQueryBuilder::new()
+            .with_class("mage")
+            .build()
+            .unwrap();
+        let mut progress = Vec::new();
+
+        // Act
+        let cards = client
+            .hearthstone()
+            .collect_all_cards(Some(query), |p| progress.push(p))
+            .await
+            .unwrap();
+        let ids: Vec<_> = cards.into_iter().map(|c| c.id).collect();
+
+        // Assert
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+        assert_eq!(progress.len(), 3);
+        assert_eq!(
+            progress.last(),
+            Some(&CardSearchProgress {
+                pages_fetched: 3,
+                page_count: 3,
+                cards_fetched: 5,
+                card_count: 5,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_pages_in_order_when_fetched_concurrently() {
+        // Arrange
+        let server = get_paged_server().await;
+        let client = server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_bulk_concurrency(2)
+            .build()
+            .unwrap();
+        let query = CardSearchQueryBuilder::new()
+            .with_class("mage")
+            .build()
+            .unwrap();
+
+        // Act
+        let pages: Vec<_> = client
+            .hearthstone()
+            .search_cards_pages(Some(query))
+            .map(|page| page.unwrap().page)
+            .collect()
+            .await;
+
+        // Assert
+        assert_eq!(pages, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_any_page_fails() {
+        // Arrange
+        let server = get_paged_server().await;
+        server.register_fixture_with_status(
+            "/hearthstone/cards?class=mage&page=2&pageSize=500",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Internal server error",
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = CardSearchQueryBuilder::new()
+            .with_class("mage")
+            .build()
+            .unwrap();
+
+        // Act
+        let cards = client
+            .hearthstone()
+            .collect_all_cards(Some(query), |_| {})
+            .await;
+
+        // Assert
+        assert!(cards.is_err());
+    }
+
+    #[tokio::test]
+    async fn stops_paging_after_first_failed_page() {
+        // Arrange
+        let server = get_paged_server().await;
+        server.register_fixture_with_status(
+            "/hearthstone/cards?class=mage&page=2&pageSize=500",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Internal server error",
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = CardSearchQueryBuilder::new()
+            .with_class("mage")
+            .build()
+            .unwrap();
+
+        // Act
+        let pages: Vec<_> = client
+            .hearthstone()
+            .search_cards_pages(Some(query))
+            .collect()
+            .await;
+
+        // Assert
+        assert_eq!(pages.len(), 2);
+        assert!(pages[0].is_ok());
+        assert!(pages[1].is_err());
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "hearthstone")]
+
 mod hearthstone_cards_tests {
     use bubblehearth::client::BubbleHearthClient;
     use bubblehearth::localization::Locale;
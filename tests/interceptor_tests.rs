@@ -0,0 +1,166 @@
+#![cfg(feature = "classic")]
+
+mod interceptor_tests {
+    use std::sync::{Arc, Mutex};
+
+    use http::StatusCode;
+
+    use bubblehearth::errors::{BubbleHearthError, BubbleHearthResult};
+    use bubblehearth::interceptors::Interceptor;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::{FakeBlizzardServer, FAKE_TOKEN_PATH};
+
+    /// Captures the paths, namespaces, and statuses of all intercepted interactions.
+    #[derive(Debug, Default)]
+    struct AuditInterceptor {
+        namespaces: Arc<Mutex<Vec<Option<String>>>>,
+        paths: Arc<Mutex<Vec<String>>>,
+        statuses: Arc<Mutex<Vec<StatusCode>>>,
+    }
+
+    impl Interceptor for AuditInterceptor {
+        fn intercept_request(&self, request: &mut reqwest::Request) -> BubbleHearthResult<()> {
+            let namespace = request
+                .headers()
+                .get("Battlenet-Namespace")
+                .map(|n| n.to_str().unwrap().to_string());
+            self.namespaces.lock().unwrap().push(namespace);
+            self.paths
+                .lock()
+                .unwrap()
+                .push(request.url().path().to_string());
+            request
+                .headers_mut()
+                .insert("X-Correlation-Id", "correlation".parse().unwrap());
+            Ok(())
+        }
+
+        fn intercept_response(&self, response: &reqwest::Response) -> BubbleHearthResult<()> {
+            self.statuses.lock().unwrap().push(response.status());
+            Ok(())
+        }
+    }
+
+    /// Rewrites all requests to the configured gateway.
+    #[derive(Debug)]
+    struct GatewayInterceptor {
+        gateway: reqwest::Url,
+    }
+
+    impl Interceptor for GatewayInterceptor {
+        fn intercept_request(&self, request: &mut reqwest::Request) -> BubbleHearthResult<()> {
+            let url = request.url_mut();
+            url.set_host(self.gateway.host_str()).unwrap();
+            url.set_port(self.gateway.port()).unwrap();
+            Ok(())
+        }
+    }
+
+    /// Rejects all outgoing requests.
+    #[derive(Debug)]
+    struct RejectingInterceptor;
+
+    impl Interceptor for RejectingInterceptor {
+        fn intercept_request(&self, _request: &mut reqwest::Request) -> BubbleHearthResult<()> {
+            Err(BubbleHearthError::InterceptorFailed("rejected".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn intercepts_token_and_api_requests_and_responses() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let interceptor = AuditInterceptor::default();
+        let namespaces = interceptor.namespaces.clone();
+        let paths = interceptor.paths.clone();
+        let statuses = interceptor.statuses.clone();
+        let client = server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_interceptor(interceptor)
+            .build()
+            .unwrap();
+
+        // Act
+        let realm = client.classic().get_realm("atiesh").await;
+        let missing_realm = client.classic().get_realm("not-atiesh").await;
+        let correlated_requests = server
+            .received_requests()
+            .into_iter()
+            .filter(|r| r.headers.contains_key("X-Correlation-Id"))
+            .count();
+
+        // Assert
+        assert!(realm.unwrap().is_some());
+        assert!(missing_realm.unwrap().is_none());
+        assert_eq!(
+            *paths.lock().unwrap(),
+            vec![
+                FAKE_TOKEN_PATH.to_string(),
+                "/data/wow/realm/atiesh".to_string(),
+                "/data/wow/realm/not-atiesh".to_string()
+            ]
+        );
+        assert_eq!(
+            *namespaces.lock().unwrap(),
+            vec![
+                None,
+                Some("dynamic-classic-us".to_string()),
+                Some("dynamic-classic-us".to_string())
+            ]
+        );
+        assert_eq!(
+            *statuses.lock().unwrap(),
+            vec![StatusCode::OK, StatusCode::OK, StatusCode::NOT_FOUND]
+        );
+        assert_eq!(correlated_requests, 3);
+    }
+
+    #[tokio::test]
+    async fn sends_rewritten_requests_to_the_gateway() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_base_url("http://gateway.invalid".to_string())
+            .with_token_url("http://gateway.invalid/oauth/token".to_string())
+            .with_interceptor(GatewayInterceptor {
+                gateway: reqwest::Url::parse(&server.url()).unwrap(),
+            })
+            .build()
+            .unwrap();
+
+        // Act
+        let realm = client.classic().get_realm("atiesh").await;
+
+        // Assert
+        assert!(realm.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_interceptor_rejects_request() {
+        // Arrange
+        let server = FakeBlizzardServer::start().await;
+        let client = server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_interceptor(RejectingInterceptor)
+            .build()
+            .unwrap();
+
+        // Act
+        let realm = client.classic().get_realm("atiesh").await;
+
+        // Assert
+        assert!(matches!(
+            realm,
+            Err(BubbleHearthError::InterceptorFailed(_))
+        ));
+        assert!(server.received_requests().is_empty());
+    }
+}
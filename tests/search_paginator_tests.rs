@@ -0,0 +1,185 @@
+#![cfg(feature = "classic")]
+
+mod search_paginator_tests {
+    use futures::{StreamExt, TryStreamExt};
+    use http::StatusCode;
+
+    use bubblehearth::classic::realms::RealmSearchField;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::{SearchQuery, SortOrder};
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    fn get_realm_page(page: u32, page_count: u32, slugs: &[&str]) -> String {
+        let results: Vec<String> = slugs
+            .iter()
+            .enumerate()
+            .map(|(i, slug)| {
+                format!(
+                    r#"{{ "key": {{ "href": "" }}, "data": {{ "name": "{}", "id": {}, "slug": "{}" }} }}"#,
+                    slug, i, slug
+                )
+            })
+            .collect();
+
+        format!(
+            r#"{{ "page": {}, "pageSize": {}, "maxPageSize": 100, "pageCount": {}, "results": [{}] }}"#,
+            page,
+            slugs.len(),
+            page_count,
+            results.join(",")
+        )
+    }
+
+    async fn get_paged_server() -> FakeBlizzardServer {
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/search/realm?_page=1",
+            get_realm_page(1, 3, &["atiesh", "myzrael"]),
+        );
+        server.register_fixture(
+            "/data/wow/search/realm?_page=2",
+            get_realm_page(2, 3, &["mankrik", "pagle"]),
+        );
+        server.register_fixture(
+            "/data/wow/search/realm?_page=3",
+            get_realm_page(3, 3, &["whitemane"]),
+        );
+        server
+    }
+
+    #[tokio::test]
+    async fn returns_items_across_all_pages_in_order() {
+        // Arrange
+        let server = get_paged_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let realms: Vec<_> = client
+            .classic()
+            .search_realms_paginator(&SearchQuery::new())
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        let slugs: Vec<_> = realms.into_iter().map(|r| r.data.slug).collect();
+
+        // Assert
+        assert_eq!(
+            slugs,
+            vec!["atiesh", "myzrael", "mankrik", "pagle", "whitemane"]
+        );
+    }
+
+    #[tokio::test]
+    async fn sends_query_filters_ordering_and_page_size_with_each_page() {
+        // Arrange
+        let server = get_paged_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = SearchQuery::new()
+            .filter_localized(RealmSearchField::Category, Locale::EnglishUS, "US East")
+            .filter_not(RealmSearchField::IsTournament, true)
+            .order_by(RealmSearchField::Id, SortOrder::Descending)
+            .with_page_size(2);
+
+        // Act
+        let pages: Vec<_> = client
+            .classic()
+            .search_realms_paginator(&query)
+            .pages()
+            .try_collect()
+            .await
+            .unwrap();
+        let queries_sent: Vec<_> = server
+            .received_requests()
+            .into_iter()
+            .filter_map(|r| r.query)
+            .collect();
+
+        // Assert
+        assert_eq!(pages.len(), 3);
+        assert_eq!(
+            queries_sent,
+            vec![
+                "namespace=dynamic-classic-us&category.en_US=US%20East&is_tournament!=true&orderby=id:desc&_page=1&_pageSize=2",
+                "namespace=dynamic-classic-us&category.en_US=US%20East&is_tournament!=true&orderby=id:desc&_page=2&_pageSize=2",
+                "namespace=dynamic-classic-us&category.en_US=US%20East&is_tournament!=true&orderby=id:desc&_page=3&_pageSize=2"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_pages_up_to_max_pages_with_page_size() {
+        // Arrange
+        let server = get_paged_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let pages: Vec<_> = client
+            .classic()
+            .search_realms_paginator(&SearchQuery::new())
+            .with_max_pages(2)
+            .with_page_size(2)
+            .pages()
+            .try_collect()
+            .await
+            .unwrap();
+        let page_sizes_sent = server
+            .received_requests()
+            .into_iter()
+            .filter(|r| r.query.as_deref().unwrap_or("").contains("_pageSize=2"))
+            .count();
+
+        // Assert
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].page, 1);
+        assert_eq!(pages[1].page, 2);
+        assert_eq!(page_sizes_sent, 2);
+    }
+
+    #[tokio::test]
+    async fn returns_same_items_when_prefetching() {
+        // Arrange
+        let server = get_paged_server().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let realms: Vec<_> = client
+            .classic()
+            .search_realms_paginator(&SearchQuery::new())
+            .with_prefetch(true)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(realms.len(), 5);
+        assert_eq!(realms[4].data.slug, "whitemane");
+    }
+
+    #[tokio::test]
+    async fn returns_error_and_stops_when_page_fails() {
+        // Arrange
+        let server = get_paged_server().await;
+        server.register_fixture_with_status(
+            "/data/wow/search/realm?_page=2",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Internal server error",
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let pages: Vec<_> = client
+            .classic()
+            .search_realms_paginator(&SearchQuery::new())
+            .pages()
+            .collect()
+            .await;
+
+        // Assert
+        assert_eq!(pages.len(), 2);
+        assert!(pages[0].is_ok());
+        assert!(pages[1].is_err());
+    }
+}
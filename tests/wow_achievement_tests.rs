@@ -0,0 +1,301 @@
+#![cfg(feature = "wow")]
+
+mod wow_achievement_tests {
+    use futures::TryStreamExt;
+
+    use bubblehearth::factions::Faction;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::media::MediaAssetKey;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::search::{SearchQuery, SortOrder};
+    use bubblehearth::testing::FakeBlizzardServer;
+    use bubblehearth::wow::achievements::AchievementSearchField;
+
+    const ACHIEVEMENT_CATEGORIES_INDEX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/achievement-category/?namespace=static-us"
+            }
+          },
+          "categories": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/achievement-category/92?namespace=static-us"
+              },
+              "name": "General",
+              "id": 92
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/achievement-category/15088?namespace=static-us"
+              },
+              "name": "General",
+              "id": 15088
+            }
+          ],
+          "root_categories": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/achievement-category/92?namespace=static-us"
+              },
+              "name": "General",
+              "id": 92
+            }
+          ],
+          "guild_categories": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/achievement-category/15088?namespace=static-us"
+              },
+              "name": "General",
+              "id": 15088
+            }
+          ]
+        }
+    "#;
+
+    const ACHIEVEMENT_CATEGORY: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/achievement-category/92?namespace=static-us"
+            }
+          },
+          "id": 92,
+          "name": "General",
+          "achievements": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/achievement/6?namespace=static-us"
+              },
+              "name": "Level 10",
+              "id": 6
+            }
+          ],
+          "is_guild_category": false,
+          "aggregates_by_faction": {
+            "alliance": { "quantity": 120, "points": 1250 },
+            "horde": { "quantity": 119, "points": 1240 }
+          },
+          "display_order": 0
+        }
+    "#;
+
+    const ACHIEVEMENT: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/achievement/6?namespace=static-us"
+            }
+          },
+          "id": 6,
+          "category": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/achievement-category/92?namespace=static-us"
+            },
+            "name": "General",
+            "id": 92
+          },
+          "name": "Level 10",
+          "description": "Reach level 10.",
+          "points": 10,
+          "is_account_wide": false,
+          "criteria": {
+            "id": 6,
+            "description": "Reach level 10",
+            "amount": 10,
+            "operator": { "type": "AND", "name": "All" },
+            "child_criteria": [{ "id": 7, "description": "Level", "amount": 10 }]
+          },
+          "next_achievement": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/achievement/7?namespace=static-us"
+            },
+            "name": "Level 20",
+            "id": 7
+          },
+          "media": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/media/achievement/6?namespace=static-us"
+            },
+            "id": 6
+          },
+          "requirements": {
+            "faction": { "type": "HORDE", "name": "Horde" }
+          },
+          "display_order": 0
+        }
+    "#;
+
+    const ACHIEVEMENT_MEDIA: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/media/achievement/6?namespace=static-us"
+            }
+          },
+          "assets": [
+            {
+              "key": "icon",
+              "value": "https://render.worldofwarcraft.com/us/icons/56/achievement_level_10.jpg",
+              "file_data_id": 236206
+            }
+          ],
+          "id": 6
+        }
+    "#;
+
+    const ACHIEVEMENT_SEARCH: &str = r#"
+        {
+          "page": 1,
+          "pageSize": 1,
+          "maxPageSize": 100,
+          "pageCount": 1,
+          "results": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/achievement/6?namespace=static-us"
+              },
+              "data": {
+                "id": 6,
+                "name": "Level 10",
+                "points": 10,
+                "category": { "id": 92 }
+              }
+            }
+          ]
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_achievement_categories_from_static_namespace() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/achievement-category/index",
+            ACHIEVEMENT_CATEGORIES_INDEX,
+        );
+        server.register_fixture("/data/wow/achievement-category/92", ACHIEVEMENT_CATEGORY);
+        let client = server.client(AccountRegion::EU, Locale::German);
+        let wow = client.wow();
+
+        // Act
+        let (categories, category) = tokio::join!(
+            wow.get_achievement_categories(),
+            wow.get_achievement_category(92)
+        );
+        let category = category.unwrap().unwrap();
+        let queries: Vec<_> = server
+            .received_requests()
+            .into_iter()
+            .filter(|request| request.path.starts_with("/data/wow"))
+            .map(|request| request.query)
+            .collect();
+
+        // Assert
+        let categories = categories.unwrap();
+        assert_eq!(categories.categories.len(), 2);
+        assert_eq!(categories.root_categories[0].id, 92);
+        assert_eq!(categories.guild_categories[0].id, 15088);
+        assert_eq!(category.achievements[0].id, 6);
+        assert_eq!(category.aggregates_by_faction.unwrap().horde.points, 1240);
+        assert_eq!(queries.len(), 2);
+        assert!(queries
+            .iter()
+            .all(|query| query == &Some("namespace=static-eu&locale=de_DE".to_string())));
+    }
+
+    #[tokio::test]
+    async fn returns_achievement_with_criteria_and_requirements() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/achievement/6", ACHIEVEMENT);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let wow = client.wow();
+
+        // Act
+        let (achievement, missing_achievement) =
+            tokio::join!(wow.get_achievement(6), wow.get_achievement(99999));
+        let achievement = achievement.unwrap().unwrap();
+        let criteria = achievement.criteria.as_ref().unwrap();
+
+        // Assert
+        assert_eq!(achievement.points, Some(10));
+        assert_eq!(achievement.category.as_ref().unwrap().id, 92);
+        assert_eq!(achievement.next_achievement.as_ref().unwrap().id, 7);
+        assert_eq!(criteria.operator.as_ref().unwrap().operator_type, "AND");
+        assert_eq!(criteria.child_criteria[0].amount, Some(10.0));
+        assert_eq!(
+            achievement
+                .requirements
+                .unwrap()
+                .faction
+                .unwrap()
+                .faction_type,
+            Faction::Horde
+        );
+        assert!(missing_achievement.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_achievement_media_icon() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/media/achievement/6", ACHIEVEMENT_MEDIA);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let media = client
+            .wow()
+            .get_achievement_media(6)
+            .await
+            .unwrap()
+            .unwrap();
+        let media_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert_eq!(
+            media.get_asset(MediaAssetKey::Icon).unwrap().file_data_id,
+            Some(236206)
+        );
+        assert_eq!(
+            media_request.query,
+            Some("namespace=static-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_achievements_from_search_and_paginator() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/search/achievement", ACHIEVEMENT_SEARCH);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let query = SearchQuery::new()
+            .filter(AchievementSearchField::CategoryId, 92)
+            .order_by(AchievementSearchField::Points, SortOrder::Descending);
+
+        // Act
+        let search = client
+            .wow()
+            .search_achievements(&query, None)
+            .await
+            .unwrap();
+        let achievements: Vec<_> = client
+            .wow()
+            .search_achievements_paginator(&query)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        let search_query = server.received_requests().pop().unwrap().query;
+
+        // Assert
+        assert_eq!(search.results[0].data.id, 6);
+        assert_eq!(achievements.len(), 1);
+        assert_eq!(
+            search_query,
+            Some("namespace=static-us&category.id=92&orderby=points:desc&_page=1".to_string())
+        );
+    }
+}
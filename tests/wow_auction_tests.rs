@@ -0,0 +1,263 @@
+#![cfg(feature = "wow")]
+
+mod wow_auction_tests {
+    use std::time::{Duration, Instant};
+
+    use futures::TryStreamExt;
+    use http::header::LAST_MODIFIED;
+    use http::{HeaderMap, StatusCode};
+
+    use bubblehearth::cassette::{Cassette, CassetteMode};
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+    use bubblehearth::wow::auctions::TimeLeft;
+
+    const AUCTIONS: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/connected-realm/11/auctions?namespace=dynamic-us"
+            }
+          },
+          "connected_realm": {
+            "href": "https://us.api.blizzard.com/data/wow/connected-realm/11?namespace=dynamic-us"
+          },
+          "auctions": [
+            {
+              "id": 1880389245,
+              "item": {
+                "id": 193476,
+                "context": 13,
+                "bonus_lists": [8836, 8840, 8902],
+                "modifiers": [{ "type": 9, "value": 70 }, { "type": 28, "value": 2164 }]
+              },
+              "bid": 900000,
+              "buyout": 1000000,
+              "quantity": 1,
+              "time_left": "VERY_LONG"
+            },
+            {
+              "id": 1880389246,
+              "item": {
+                "id": 82800,
+                "modifiers": [{ "type": 6, "value": 93700 }],
+                "pet_breed_id": 8,
+                "pet_level": 25,
+                "pet_quality_id": 3,
+                "pet_species_id": 1155
+              },
+              "buyout": 4500000,
+              "quantity": 1,
+              "time_left": "SHORT"
+            }
+          ],
+          "commodities": {
+            "href": "https://us.api.blizzard.com/data/wow/auctions/commodities?namespace=dynamic-us"
+          }
+        }
+    "#;
+
+    const COMMODITIES: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/auctions/commodities?namespace=dynamic-us"
+            }
+          },
+          "auctions": [
+            { "id": 1, "item": { "id": 2589 }, "quantity": 200, "unit_price": 1500, "time_left": "LONG" },
+            { "id": 2, "item": { "id": 2589 }, "quantity": 20, "unit_price": 1450, "time_left": "MEDIUM" },
+            { "id": 3, "item": { "id": 190395 }, "quantity": 5, "unit_price": 99000, "time_left": "VERY_LONG" }
+          ]
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_streamed_auctions_with_last_modified() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LAST_MODIFIED,
+            "Tue, 20 Feb 2024 14:05:11 GMT".parse().unwrap(),
+        );
+        server.register_fixture_with_headers(
+            "/data/wow/connected-realm/11/auctions",
+            StatusCode::OK,
+            headers,
+            AUCTIONS,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let auctions = client.wow().get_auctions(11).await.unwrap().unwrap();
+        let last_modified = auctions.get_last_modified();
+        let auctions: Vec<_> = auctions.try_collect().await.unwrap();
+        let auctions_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert_eq!(last_modified.unwrap().unix_timestamp(), 1708437911);
+        assert_eq!(auctions.len(), 2);
+        assert_eq!(auctions[0].item.bonus_lists, vec![8836, 8840, 8902]);
+        assert_eq!(auctions[0].item.modifiers[1].value, 2164);
+        assert_eq!(auctions[0].bid, Some(900000));
+        assert_eq!(auctions[1].item.pet_species_id, Some(1155));
+        assert_eq!(auctions[1].time_left, TimeLeft::Short);
+        assert_eq!(
+            auctions_request.query,
+            Some("namespace=dynamic-us&locale=en_US".to_string())
+        );
+        assert_eq!(
+            auctions_request.headers.get("Battlenet-Namespace").unwrap(),
+            "dynamic-us"
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_connected_realm_auctions_not_found() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        let client = server.client(AccountRegion::EU, Locale::EnglishGB);
+
+        // Act
+        let auctions = client.wow().get_auctions(99999).await;
+        let auctions_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert!(auctions.unwrap().is_none());
+        assert_eq!(
+            auctions_request.query,
+            Some("namespace=dynamic-eu&locale=en_GB".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_streamed_commodities_without_last_modified() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/auctions/commodities", COMMODITIES);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let commodities = client.wow().get_commodities().await.unwrap();
+        let last_modified = commodities.get_last_modified();
+        let commodities: Vec<_> = commodities.try_collect().await.unwrap();
+        let cheapest = commodities
+            .iter()
+            .filter(|commodity| commodity.item.id == 2589)
+            .min_by_key(|commodity| commodity.unit_price)
+            .unwrap();
+
+        // Assert
+        assert!(last_modified.is_none());
+        assert_eq!(commodities.len(), 3);
+        assert_eq!(cheapest.id, 2);
+        assert_eq!(commodities[2].time_left, TimeLeft::VeryLong);
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_commodities_unavailable() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let commodities = client.wow().get_commodities().await;
+
+        // Assert
+        assert!(commodities.is_err());
+    }
+
+    #[tokio::test]
+    async fn returns_streamed_commodities_when_received_slower_than_timeout() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_slow_fixture(
+            "/data/wow/auctions/commodities?namespace=dynamic-us",
+            HeaderMap::new(),
+            COMMODITIES,
+            Duration::from_millis(500),
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let started = Instant::now();
+
+        // Act
+        let commodities: Vec<_> = client
+            .wow()
+            .get_commodities()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(commodities.len(), 3);
+        assert!(started.elapsed() > Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn returns_replayed_auctions_when_recorded_to_cassette() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LAST_MODIFIED,
+            "Tue, 20 Feb 2024 14:05:11 GMT".parse().unwrap(),
+        );
+        server.register_fixture_with_headers(
+            "/data/wow/connected-realm/11/auctions",
+            StatusCode::OK,
+            headers,
+            AUCTIONS,
+        );
+        let path = std::env::temp_dir()
+            .join(format!("bubblehearth-cassettes-{}", std::process::id()))
+            .join("returns_replayed_auctions_when_recorded_to_cassette.json");
+        let recording_client = server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_cassette(CassetteMode::Record(path.clone()))
+            .build()
+            .unwrap();
+        let recorded_auctions: Vec<_> = recording_client
+            .wow()
+            .get_auctions(11)
+            .await
+            .unwrap()
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        recording_client.save_cassette().unwrap();
+        let cassette = Cassette::load(&path).unwrap();
+        let sent_requests = server.received_requests().len();
+        let replaying_client = server
+            .client_builder()
+            .with_region(AccountRegion::US)
+            .with_locale(Locale::EnglishUS)
+            .with_cassette(CassetteMode::Replay(path))
+            .build()
+            .unwrap();
+
+        // Act
+        let replayed_auctions = replaying_client
+            .wow()
+            .get_auctions(11)
+            .await
+            .unwrap()
+            .unwrap();
+        let last_modified = replayed_auctions.get_last_modified();
+        let replayed_auctions: Vec<_> = replayed_auctions.try_collect().await.unwrap();
+
+        // Assert
+        assert_eq!(cassette.interactions.len(), 1);
+        assert_eq!(last_modified.unwrap().unix_timestamp(), 1708437911);
+        assert_eq!(replayed_auctions.len(), recorded_auctions.len());
+        assert_eq!(replayed_auctions[0].id, recorded_auctions[0].id);
+        assert_eq!(replayed_auctions[1].item.pet_species_id, Some(1155));
+        assert_eq!(server.received_requests().len(), sent_requests);
+    }
+}
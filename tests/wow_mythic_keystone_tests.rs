@@ -0,0 +1,336 @@
+#![cfg(feature = "wow")]
+
+mod wow_mythic_keystone_tests {
+    use time::Duration;
+
+    use bubblehearth::factions::Faction;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::media::MediaAssetKey;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+
+    const KEYSTONE_AFFIX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/keystone-affix/10?namespace=static-us"
+            }
+          },
+          "id": 10,
+          "name": "Fortified",
+          "description": "Non-boss enemies have 20% more health and inflict up to 30% increased damage.",
+          "media": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/media/keystone-affix/10?namespace=static-us"
+            },
+            "id": 10
+          }
+        }
+    "#;
+
+    const KEYSTONE_AFFIX_MEDIA: &str = r#"
+        {
+          "assets": [
+            {
+              "key": "icon",
+              "value": "https://render.worldofwarcraft.com/us/icons/56/ability_toughness.jpg",
+              "file_data_id": 463829
+            }
+          ],
+          "id": 10
+        }
+    "#;
+
+    const MYTHIC_KEYSTONE_DUNGEON: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/mythic-keystone/dungeon/399?namespace=dynamic-us"
+            }
+          },
+          "id": 399,
+          "name": "Ruby Life Pools",
+          "map": { "name": "Ruby Life Pools", "id": 2521 },
+          "dungeon": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/journal-instance/1202?namespace=static-us"
+            },
+            "name": "Ruby Life Pools",
+            "id": 1202
+          },
+          "keystone_upgrades": [
+            { "upgrade_level": 1, "qualifying_duration": 1800999 },
+            { "upgrade_level": 2, "qualifying_duration": 1440999 },
+            { "upgrade_level": 3, "qualifying_duration": 1080999 }
+          ],
+          "is_tracked": true
+        }
+    "#;
+
+    const MYTHIC_KEYSTONE_PERIODS_INDEX: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/mythic-keystone/period/?namespace=dynamic-us"
+            }
+          },
+          "periods": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/mythic-keystone/period/940?namespace=dynamic-us"
+              },
+              "id": 940
+            },
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/mythic-keystone/period/941?namespace=dynamic-us"
+              },
+              "id": 941
+            }
+          ],
+          "current_period": {
+            "key": {
+              "href": "https://us.api.blizzard.com/data/wow/mythic-keystone/period/941?namespace=dynamic-us"
+            },
+            "id": 941
+          }
+        }
+    "#;
+
+    const MYTHIC_KEYSTONE_PERIOD: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/mythic-keystone/period/941?namespace=dynamic-us"
+            }
+          },
+          "id": 941,
+          "start_timestamp": 1706626800000,
+          "end_timestamp": 1707231600000
+        }
+    "#;
+
+    const MYTHIC_KEYSTONE_SEASON: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/mythic-keystone/season/11?namespace=dynamic-us"
+            }
+          },
+          "id": 11,
+          "start_timestamp": 1699974000000,
+          "periods": [
+            {
+              "key": {
+                "href": "https://us.api.blizzard.com/data/wow/mythic-keystone/period/930?namespace=dynamic-us"
+              },
+              "id": 930
+            }
+          ],
+          "season_name": "Dragonflight Season 3"
+        }
+    "#;
+
+    const MYTHIC_KEYSTONE_LEADERBOARD: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/connected-realm/11/mythic-leaderboard/399/period/941?namespace=dynamic-us"
+            }
+          },
+          "map": { "name": "Ruby Life Pools", "id": 2521 },
+          "period": 941,
+          "period_start_timestamp": 1706626800000,
+          "period_end_timestamp": 1707231600000,
+          "connected_realm": {
+            "href": "https://us.api.blizzard.com/data/wow/connected-realm/11?namespace=dynamic-us"
+          },
+          "leading_groups": [
+            {
+              "ranking": 1,
+              "duration": 1523456,
+              "completed_timestamp": 1706700000000,
+              "keystone_level": 24,
+              "members": [
+                {
+                  "profile": {
+                    "name": "Thrall",
+                    "id": 1337,
+                    "realm": {
+                      "key": {
+                        "href": "https://us.api.blizzard.com/data/wow/realm/11?namespace=dynamic-us"
+                      },
+                      "id": 11,
+                      "slug": "tichondrius"
+                    }
+                  },
+                  "faction": { "type": "HORDE" },
+                  "specialization": {
+                    "key": {
+                      "href": "https://us.api.blizzard.com/data/wow/playable-specialization/263?namespace=static-us"
+                    },
+                    "id": 263
+                  }
+                }
+              ],
+              "mythic_rating": { "color": { "r": 255, "g": 128, "b": 0, "a": 1.0 }, "rating": 221.5 }
+            },
+            {
+              "ranking": 2,
+              "duration": 1601000,
+              "completed_timestamp": 1706710000000,
+              "keystone_level": 23,
+              "members": []
+            }
+          ],
+          "keystone_affixes": [
+            {
+              "keystone_affix": { "name": "Fortified", "id": 10 },
+              "starting_level": 2
+            }
+          ],
+          "map_challenge_mode_id": 399,
+          "name": "Ruby Life Pools"
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_keystone_affix_and_media_from_static_namespace() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/keystone-affix/10", KEYSTONE_AFFIX);
+        server.register_fixture("/data/wow/media/keystone-affix/10", KEYSTONE_AFFIX_MEDIA);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let wow = client.wow();
+
+        // Act
+        let (affix, media) =
+            tokio::join!(wow.get_keystone_affix(10), wow.get_keystone_affix_media(10));
+        let affix = affix.unwrap().unwrap();
+        let media = media.unwrap().unwrap();
+        let affix_request = server
+            .received_requests()
+            .into_iter()
+            .find(|request| request.path == "/data/wow/keystone-affix/10")
+            .unwrap();
+
+        // Assert
+        assert_eq!(affix.id, 10);
+        assert_eq!(affix.media.unwrap().id, 10);
+        assert_eq!(
+            media.get_asset(MediaAssetKey::Icon).unwrap().file_data_id,
+            Some(463829)
+        );
+        assert_eq!(
+            affix_request.query,
+            Some("namespace=static-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_dungeon_with_keystone_upgrade_timers() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/mythic-keystone/dungeon/399",
+            MYTHIC_KEYSTONE_DUNGEON,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let dungeon = client
+            .wow()
+            .get_mythic_keystone_dungeon(399)
+            .await
+            .unwrap()
+            .unwrap();
+        let dungeon_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert_eq!(dungeon.keystone_upgrades.len(), 3);
+        assert_eq!(
+            dungeon.keystone_upgrades[0].get_qualifying_duration(),
+            Duration::milliseconds(1800999)
+        );
+        assert_eq!(dungeon.is_tracked, Some(true));
+        assert_eq!(
+            dungeon_request.query,
+            Some("namespace=dynamic-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_current_period_and_season() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/mythic-keystone/period/index",
+            MYTHIC_KEYSTONE_PERIODS_INDEX,
+        );
+        server.register_fixture(
+            "/data/wow/mythic-keystone/period/941",
+            MYTHIC_KEYSTONE_PERIOD,
+        );
+        server.register_fixture(
+            "/data/wow/mythic-keystone/season/11",
+            MYTHIC_KEYSTONE_SEASON,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let wow = client.wow();
+
+        // Act
+        let (period, season) = tokio::join!(
+            wow.get_current_mythic_keystone_period(),
+            wow.get_mythic_keystone_season(11)
+        );
+        let period = period.unwrap().unwrap();
+        let season = season.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(period.id, 941);
+        assert_eq!(period.get_start().unwrap().unix_timestamp(), 1706626800);
+        assert_eq!(period.get_end().unwrap().unix_timestamp(), 1707231600);
+        assert_eq!(season.periods[0].id, 930);
+        assert!(season.get_start().is_some());
+        assert!(season.get_end().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_leaderboard_with_typed_entries() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/connected-realm/11/mythic-leaderboard/399/period/941",
+            MYTHIC_KEYSTONE_LEADERBOARD,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let wow = client.wow();
+
+        // Act
+        let (leaderboard, missing_leaderboard) = tokio::join!(
+            wow.get_mythic_keystone_leaderboard(11, 399, 941),
+            wow.get_mythic_keystone_leaderboard(11, 399, 1)
+        );
+        let leaderboard = leaderboard.unwrap().unwrap();
+        let leader = &leaderboard.leading_groups[0];
+        let member = &leader.members[0];
+
+        // Assert
+        assert_eq!(leaderboard.map_challenge_mode_id, Some(399));
+        assert_eq!(leaderboard.keystone_affixes[0].starting_level, 2);
+        assert_eq!(leaderboard.leading_groups.len(), 2);
+        assert_eq!(leader.keystone_level, 24);
+        assert_eq!(leader.get_duration(), Duration::milliseconds(1523456));
+        assert_eq!(leader.completed_timestamp.unix_timestamp(), 1706700000);
+        assert_eq!(leader.mythic_rating.unwrap().rating, 221.5);
+        assert_eq!(member.profile.name, "Thrall");
+        assert_eq!(member.profile.realm.as_ref().unwrap().slug, "tichondrius");
+        assert_eq!(member.specialization.as_ref().unwrap().id, 263);
+        assert_eq!(
+            member.faction.as_ref().unwrap().faction_type,
+            Faction::Horde
+        );
+        assert!(leaderboard.leading_groups[1].mythic_rating.is_none());
+        assert!(missing_leaderboard.unwrap().is_none());
+    }
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "wow")]
+
+mod wow_mythic_raid_tests {
+    use bubblehearth::factions::Faction;
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+    use bubblehearth::wow::mythic_raid::HallOfFameFaction;
+
+    const HALL_OF_FAME: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/leaderboard/hall-of-fame/uldir/horde?namespace=dynamic-us"
+            }
+          },
+          "slug": "uldir",
+          "criteria_type": "hall-of-fame",
+          "entries": [
+            {
+              "faction": { "type": "HORDE" },
+              "guild": {
+                "name": "Method",
+                "id": 1,
+                "realm": { "name": "Tarren Mill", "id": 1084, "slug": "tarren-mill" }
+              },
+              "region": "eu",
+              "rank": 1,
+              "timestamp": 1536661263000
+            },
+            {
+              "faction": { "type": "HORDE" },
+              "guild": {
+                "name": "Limit",
+                "id": 2,
+                "realm": { "name": "Illidan", "id": 57, "slug": "illidan" }
+              },
+              "region": "us",
+              "rank": 2,
+              "timestamp": 1536750000000
+            },
+            {
+              "faction": { "type": "HORDE" },
+              "guild": {
+                "name": "Somewhere Else",
+                "id": 3,
+                "realm": { "id": 9999, "slug": "elsewhere" }
+              },
+              "region": "xx",
+              "rank": 3,
+              "timestamp": 1536800000000
+            }
+          ]
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_hall_of_fame_with_ranked_guilds() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/leaderboard/hall-of-fame/uldir/horde",
+            HALL_OF_FAME,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let leaderboard = client
+            .wow()
+            .get_mythic_raid_leaderboard("uldir", HallOfFameFaction::Horde)
+            .await
+            .unwrap()
+            .unwrap();
+        let leaderboard_request = server.received_requests().pop().unwrap();
+        let world_first = &leaderboard.entries[0];
+
+        // Assert
+        assert_eq!(leaderboard.slug, "uldir");
+        assert_eq!(leaderboard.entries.len(), 3);
+        assert_eq!(world_first.rank, 1);
+        assert_eq!(world_first.guild.name, "Method");
+        assert_eq!(world_first.guild.realm.slug, "tarren-mill");
+        assert_eq!(
+            world_first.faction.as_ref().unwrap().faction_type,
+            Faction::Horde
+        );
+        assert_eq!(world_first.get_region(), Some(AccountRegion::EU));
+        assert_eq!(
+            world_first.get_completed().unwrap().unix_timestamp(),
+            1536661263
+        );
+        assert_eq!(leaderboard.entries[2].get_region(), None);
+        assert_eq!(
+            leaderboard_request.query,
+            Some("namespace=dynamic-us&locale=en_US".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_raid_or_faction_not_found() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture(
+            "/data/wow/leaderboard/hall-of-fame/uldir/horde",
+            HALL_OF_FAME,
+        );
+        let client = server.client(AccountRegion::KR, Locale::Korean);
+
+        // Act
+        let leaderboard = client
+            .wow()
+            .get_mythic_raid_leaderboard("uldir", HallOfFameFaction::Alliance)
+            .await;
+        let leaderboard_request = server.received_requests().pop().unwrap();
+
+        // Assert
+        assert!(leaderboard.unwrap().is_none());
+        assert_eq!(
+            leaderboard_request.path,
+            "/data/wow/leaderboard/hall-of-fame/uldir/alliance"
+        );
+        assert_eq!(
+            leaderboard_request.query,
+            Some("namespace=dynamic-kr&locale=ko_KR".to_string())
+        );
+    }
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "wow")]
+
+mod wow_realm_tests {
+    use bubblehearth::localization::Locale;
+    use bubblehearth::regionality::AccountRegion;
+    use bubblehearth::testing::FakeBlizzardServer;
+    use bubblehearth::timezone::Timezone;
+    use bubblehearth::wow::realms::RealmReference;
+
+    const REALM: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/realm/illidan?namespace=dynamic-us"
+            }
+          },
+          "id": 57,
+          "name": "Illidan",
+          "category": "United States",
+          "locale": "enUS",
+          "timezone": "America/New_York",
+          "is_tournament": false,
+          "slug": "illidan",
+          "connected_realm": {
+            "href": "https://us.api.blizzard.com/data/wow/connected-realm/57?namespace=dynamic-us"
+          }
+        }
+    "#;
+
+    const CONNECTED_REALM: &str = r#"
+        {
+          "_links": {
+            "self": {
+              "href": "https://us.api.blizzard.com/data/wow/connected-realm/57?namespace=dynamic-us"
+            }
+          },
+          "id": 57,
+          "has_queue": false,
+          "status": { "type": "UP", "name": "Up" },
+          "population": { "type": "FULL", "name": "Full" },
+          "realms": [
+            {
+              "id": 57,
+              "name": "Illidan",
+              "category": "United States",
+              "locale": "enUS",
+              "timezone": "America/New_York",
+              "is_tournament": false,
+              "slug": "illidan"
+            }
+          ],
+          "auctions": {
+            "href": "https://us.api.blizzard.com/data/wow/connected-realm/57/auctions?namespace=dynamic-us"
+          }
+        }
+    "#;
+
+    #[tokio::test]
+    async fn returns_realm_from_dynamic_namespace() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/realm/illidan?namespace=dynamic-us", REALM);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+
+        // Act
+        let realm = client.wow().get_realm("illidan").await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(realm.id, 57);
+        assert_eq!(realm.slug, "illidan");
+        assert_eq!(realm.timezone, Some(Timezone::AmericaNewYork));
+        assert!(realm.connected_realm.is_some());
+    }
+
+    #[tokio::test]
+    async fn resolves_realm_reference_key_into_realm() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/realm/57?namespace=dynamic-us", REALM);
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let reference: RealmReference = serde_json::from_str(
+            r#"{
+              "key": { "href": "https://us.api.blizzard.com/data/wow/realm/57?namespace=dynamic-us" },
+              "name": "Illidan",
+              "id": 57,
+              "slug": "illidan"
+            }"#,
+        )
+        .unwrap();
+
+        // Act
+        let realm = client.resolve(&reference.key.unwrap()).await.unwrap();
+
+        // Assert
+        assert_eq!(realm.unwrap().slug, "illidan");
+    }
+
+    #[tokio::test]
+    async fn resolves_realm_connected_realm_key_into_connected_realm() {
+        // Arrange
+        let server = FakeBlizzardServer::start_without_fixtures().await;
+        server.register_fixture("/data/wow/realm/illidan?namespace=dynamic-us", REALM);
+        server.register_fixture(
+            "/data/wow/connected-realm/57?namespace=dynamic-us",
+            CONNECTED_REALM,
+        );
+        let client = server.client(AccountRegion::US, Locale::EnglishUS);
+        let realm = client.wow().get_realm("illidan").await.unwrap().unwrap();
+
+        // Act
+        let connected_realm = client
+            .resolve(&realm.connected_realm.unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(connected_realm.id, 57);
+        assert!(connected_realm.is_up());
+        assert!(connected_realm.is_full());
+        assert_eq!(connected_realm.realms[0].slug, "illidan");
+    }
+}